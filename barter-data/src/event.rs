@@ -3,6 +3,7 @@ use crate::{
     subscription::{
         book::{OrderBook, OrderBookL1},
         candle::Candle,
+        funding::FundingRate,
         liquidation::Liquidation,
         trade::PublicTrade,
     },
@@ -63,6 +64,7 @@ pub enum DataKind {
     OrderBook(OrderBook),
     Candle(Candle),
     Liquidation(Liquidation),
+    FundingRate(FundingRate),
 }
 
 impl<InstrumentId> From<MarketEvent<InstrumentId, PublicTrade>>
@@ -132,3 +134,17 @@ impl<InstrumentId> From<MarketEvent<InstrumentId, Liquidation>>
         }
     }
 }
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, FundingRate>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, FundingRate>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::FundingRate(event.kind),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use super::SubscriptionKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`FundingRate`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Each event must be a realised funding settlement (see [`FundingRate`]), not a streamed
+/// predicted or indicative rate. Note that no exchange implements [`FundingRates`] yet.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct FundingRates;
+
+impl SubscriptionKind for FundingRates {
+    type Event = FundingRate;
+}
+
+/// Normalised Barter perpetual [`FundingRate`] model.
+///
+/// A positive rate is paid by long positions to short positions, and a negative rate by short
+/// positions to long positions, as a proportion of the position notional at the mark price.
+///
+/// A [`FundingRate`] represents a funding settlement that occurred at `time`. Consumers (eg/ a
+/// margined Barter Portfolio) book every [`FundingRate`] they receive as realised funding, so
+/// an exchange integration must only yield one per settlement, rather than every predicted or
+/// indicative rate update it streams between settlements.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FundingRate {
+    pub rate: f64,
+    pub mark_price: f64,
+    pub time: DateTime<Utc>,
+}
@@ -127,6 +127,7 @@ pub mod test_util {
     };
     use barter_data::subscription::trade::PublicTrade;
     use barter_integration::model::{
+        amount::Amount,
         instrument::{kind::InstrumentKind, Instrument},
         Exchange, Side,
     };
@@ -146,9 +147,9 @@ pub mod test_util {
     pub fn order_open(
         cid: ClientOrderId,
         side: Side,
-        price: f64,
-        quantity: f64,
-        filled: f64,
+        price: Amount,
+        quantity: Amount,
+        filled: Amount,
     ) -> Order<Open> {
         Order {
             exchange: Exchange::from("exchange"),
@@ -173,7 +174,13 @@ pub mod test_util {
         }
     }
 
-    pub fn trade(id: TradeId, side: Side, price: f64, quantity: f64, fees: SymbolFees) -> Trade {
+    pub fn trade(
+        id: TradeId,
+        side: Side,
+        price: Amount,
+        quantity: Amount,
+        fees: SymbolFees,
+    ) -> Trade {
         Trade {
             id,
             order_id: OrderId::from("order_id"),
@@ -1,4 +1,4 @@
-use barter_integration::model::instrument::symbol::Symbol;
+use barter_integration::model::{amount::Amount, instrument::symbol::Symbol};
 use serde::{Deserialize, Serialize};
 
 /// [`Balance`] associated with a [`Symbol`].
@@ -24,18 +24,18 @@ impl SymbolBalance {
 /// Total and available balance values.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Balance {
-    pub total: f64,
-    pub available: f64,
+    pub total: Amount,
+    pub available: Amount,
 }
 
 impl Balance {
     /// Construct a new [`Balance`].
-    pub fn new(total: f64, available: f64) -> Self {
+    pub fn new(total: Amount, available: Amount) -> Self {
         Self { total, available }
     }
 
     /// Calculate the used (`total` - `available`) balance.
-    pub fn used(&self) -> f64 {
+    pub fn used(&self) -> Amount {
         self.total - self.available
     }
 
@@ -49,13 +49,13 @@ impl Balance {
 /// Communicates a change to be applied to a [`Balance`];
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct BalanceDelta {
-    pub total: f64,
-    pub available: f64,
+    pub total: Amount,
+    pub available: Amount,
 }
 
 impl BalanceDelta {
     /// Construct a new [`BalanceDelta`].
-    pub fn new(total: f64, available: f64) -> Self {
+    pub fn new(total: Amount, available: Amount) -> Self {
         Self { total, available }
     }
 }
@@ -63,19 +63,20 @@ impl BalanceDelta {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use barter_integration::amount;
 
     #[test]
     fn test_balance_used() {
         // No Balance is used
-        let balance = Balance::new(10.0, 10.0);
-        assert_eq!(balance.used(), 0.0);
+        let balance = Balance::new(amount!(10.0), amount!(10.0));
+        assert_eq!(balance.used(), amount!(0.0));
 
         // All Balance is used
-        let balance = Balance::new(10.0, 0.0);
+        let balance = Balance::new(amount!(10.0), amount!(0.0));
         assert_eq!(balance.used(), balance.total);
 
         // Half Balance is used
-        let balance = Balance::new(10.0, 5.0);
+        let balance = Balance::new(amount!(10.0), amount!(5.0));
         assert_eq!(balance.used(), balance.available);
     }
 
@@ -90,39 +91,39 @@ mod tests {
         let tests = vec![
             TestCase {
                 // TC0: Delta applies a negative total delta only
-                balance: Balance::new(10.0, 0.0),
-                input_delta: BalanceDelta::new(-10.0, 0.0),
-                expected: Balance::new(0.0, 0.0),
+                balance: Balance::new(amount!(10.0), amount!(0.0)),
+                input_delta: BalanceDelta::new(-amount!(10.0), amount!(0.0)),
+                expected: Balance::new(amount!(0.0), amount!(0.0)),
             },
             TestCase {
                 // TC1: Delta applies a negative available delta only
-                balance: Balance::new(10.0, 10.0),
-                input_delta: BalanceDelta::new(0.0, -10.0),
-                expected: Balance::new(10.0, 0.0),
+                balance: Balance::new(amount!(10.0), amount!(10.0)),
+                input_delta: BalanceDelta::new(amount!(0.0), -amount!(10.0)),
+                expected: Balance::new(amount!(10.0), amount!(0.0)),
             },
             TestCase {
                 // TC2: Delta applies a positive available delta only
-                balance: Balance::new(10.0, 10.0),
-                input_delta: BalanceDelta::new(0.0, 10.0),
-                expected: Balance::new(10.0, 20.0),
+                balance: Balance::new(amount!(10.0), amount!(10.0)),
+                input_delta: BalanceDelta::new(amount!(0.0), amount!(10.0)),
+                expected: Balance::new(amount!(10.0), amount!(20.0)),
             },
             TestCase {
                 // TC3: Delta applies a positive available delta only
-                balance: Balance::new(10.0, 10.0),
-                input_delta: BalanceDelta::new(0.0, 10.0),
-                expected: Balance::new(10.0, 20.0),
+                balance: Balance::new(amount!(10.0), amount!(10.0)),
+                input_delta: BalanceDelta::new(amount!(0.0), amount!(10.0)),
+                expected: Balance::new(amount!(10.0), amount!(20.0)),
             },
             TestCase {
                 // TC4: Delta applies a positive total & available delta
-                balance: Balance::new(10.0, 10.0),
-                input_delta: BalanceDelta::new(10.0, 10.0),
-                expected: Balance::new(20.0, 20.0),
+                balance: Balance::new(amount!(10.0), amount!(10.0)),
+                input_delta: BalanceDelta::new(amount!(10.0), amount!(10.0)),
+                expected: Balance::new(amount!(20.0), amount!(20.0)),
             },
             TestCase {
                 // TC5: Delta applies a negative total & available delta
-                balance: Balance::new(10.0, 10.0),
-                input_delta: BalanceDelta::new(-10.0, -10.0),
-                expected: Balance::new(0.0, 0.0),
+                balance: Balance::new(amount!(10.0), amount!(10.0)),
+                input_delta: BalanceDelta::new(-amount!(10.0), -amount!(10.0)),
+                expected: Balance::new(amount!(0.0), amount!(0.0)),
             },
         ];
 
@@ -1,5 +1,6 @@
 use super::ClientOrderId;
 use barter_integration::model::{
+    amount::Amount,
     instrument::{symbol::Symbol, Instrument},
     Exchange, Side,
 };
@@ -48,12 +49,12 @@ pub struct Order<State> {
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct RequestOpen {
     pub kind: OrderKind,
-    pub price: f64,
-    pub quantity: f64,
+    pub price: Amount,
+    pub quantity: Amount,
 }
 
 impl Order<RequestOpen> {
-    pub fn required_available_balance(&self) -> (&Symbol, f64) {
+    pub fn required_available_balance(&self) -> (&Symbol, Amount) {
         match self.side {
             Side::Buy => (
                 &self.instrument.quote,
@@ -88,13 +89,13 @@ where
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
 pub struct Open {
     pub id: OrderId,
-    pub price: f64,
-    pub quantity: f64,
-    pub filled_quantity: f64,
+    pub price: Amount,
+    pub quantity: Amount,
+    pub filled_quantity: Amount,
 }
 
 impl Open {
-    pub fn remaining_quantity(&self) -> f64 {
+    pub fn remaining_quantity(&self) -> Amount {
         self.quantity - self.filled_quantity
     }
 }
@@ -190,7 +191,7 @@ impl From<(OrderId, Order<RequestOpen>)> for Order<Open> {
                 id,
                 price: request.state.price,
                 quantity: request.state.quantity,
-                filled_quantity: 0.0,
+                filled_quantity: Amount::default(),
             },
         }
     }
@@ -212,12 +213,19 @@ impl From<Order<Open>> for Order<Cancelled> {
 mod tests {
     use super::*;
     use crate::test_util::order_open;
+    use barter_integration::amount;
     use uuid::Uuid;
 
     #[test]
     fn test_open_order_remaining_quantity() {
-        let order = order_open(ClientOrderId(Uuid::new_v4()), Side::Buy, 10.0, 10.0, 5.0);
-        assert_eq!(order.state.remaining_quantity(), 5.0)
+        let order = order_open(
+            ClientOrderId(Uuid::new_v4()),
+            Side::Buy,
+            amount!(10.0),
+            amount!(10.0),
+            amount!(5.0),
+        );
+        assert_eq!(order.state.remaining_quantity(), amount!(5.0))
     }
 
     #[test]
@@ -234,118 +242,118 @@ mod tests {
             // -- Side::Buy Order<Open> --
             TestCase {
                 // TC0: Input One has higher price and higher quantity -> Greater
-                input_one: order_open(cid, Side::Buy, 1100.0, 2.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Buy, amount!(1100.0), amount!(2.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Buy, amount!(1000.0), amount!(1.0), amount!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC1: Input One has higher price but same quantity -> Greater
-                input_one: order_open(cid, Side::Buy, 1100.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Buy, amount!(1100.0), amount!(1.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Buy, amount!(1000.0), amount!(1.0), amount!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC2: Input One has higher price but lower quantity -> Greater
-                input_one: order_open(cid, Side::Buy, 1100.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1000.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Buy, amount!(1100.0), amount!(1.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Buy, amount!(1000.0), amount!(2.0), amount!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC3: Input One has same price and higher quantity -> Greater
-                input_one: order_open(cid, Side::Buy, 1000.0, 2.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Buy, amount!(1000.0), amount!(2.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Buy, amount!(1000.0), amount!(1.0), amount!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC4: Input One has same price and same quantity -> Equal
-                input_one: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Buy, amount!(1000.0), amount!(1.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Buy, amount!(1000.0), amount!(1.0), amount!(0.0)),
                 expected: Some(Ordering::Equal),
             },
             TestCase {
                 // TC5: Input One has same price but lower quantity -> Less
-                input_one: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1000.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Buy, amount!(1000.0), amount!(1.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Buy, amount!(1000.0), amount!(2.0), amount!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // TC6: Input One has lower price but higher quantity -> Less
-                input_one: order_open(cid, Side::Buy, 1000.0, 2.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1100.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Buy, amount!(1000.0), amount!(2.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Buy, amount!(1100.0), amount!(1.0), amount!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // TC7: Input One has lower price and same quantity -> Less
-                input_one: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1100.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Buy, amount!(1000.0), amount!(1.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Buy, amount!(1100.0), amount!(1.0), amount!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // TC8: Input One has lower price but lower quantity -> Less
-                input_one: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1100.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Buy, amount!(1000.0), amount!(1.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Buy, amount!(1100.0), amount!(2.0), amount!(0.0)),
                 expected: Some(Ordering::Less),
             },
             // -- Side::Sell Order<Open> --
             TestCase {
                 // TC9: Input One has higher price and higher quantity -> Lesser
-                input_one: order_open(cid, Side::Sell, 1100.0, 2.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Sell, amount!(1100.0), amount!(2.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Sell, amount!(1000.0), amount!(1.0), amount!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // TC10: Input One has higher price but same quantity -> Lesser
-                input_one: order_open(cid, Side::Sell, 1100.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Sell, amount!(1100.0), amount!(1.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Sell, amount!(1000.0), amount!(1.0), amount!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // T11: Input One has higher price but lower quantity -> Lesser
-                input_one: order_open(cid, Side::Sell, 1100.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1000.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Sell, amount!(1100.0), amount!(1.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Sell, amount!(1000.0), amount!(2.0), amount!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // TC12: Input One has same price and higher quantity -> Lesser
-                input_one: order_open(cid, Side::Sell, 1000.0, 2.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Sell, amount!(1000.0), amount!(2.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Sell, amount!(1000.0), amount!(1.0), amount!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // TC13: Input One has same price and same quantity -> Equal
-                input_one: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Sell, amount!(1000.0), amount!(1.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Sell, amount!(1000.0), amount!(1.0), amount!(0.0)),
                 expected: Some(Ordering::Equal),
             },
             TestCase {
                 // TC14: Input One has same price but lower quantity -> Greater
-                input_one: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1000.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Sell, amount!(1000.0), amount!(1.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Sell, amount!(1000.0), amount!(2.0), amount!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC15: Input One has lower price but higher quantity -> Greater
-                input_one: order_open(cid, Side::Sell, 1000.0, 2.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1100.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Sell, amount!(1000.0), amount!(2.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Sell, amount!(1100.0), amount!(1.0), amount!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC16: Input One has lower price and same quantity -> Greater
-                input_one: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1100.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Sell, amount!(1000.0), amount!(1.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Sell, amount!(1100.0), amount!(1.0), amount!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC17: Input One has lower price but lower quantity -> Greater
-                input_one: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1100.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Sell, amount!(1000.0), amount!(1.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Sell, amount!(1100.0), amount!(2.0), amount!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             // -- Inputs Are Not Comparable Due To Different Sides
             TestCase {
                 // TC18: Input One has lower price but lower quantity -> Greater
-                input_one: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1100.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Buy, amount!(1000.0), amount!(1.0), amount!(0.0)),
+                input_two: order_open(cid, Side::Sell, amount!(1100.0), amount!(2.0), amount!(0.0)),
                 expected: None,
             },
         ];
@@ -386,80 +394,80 @@ mod tests {
             TestCase {
                 // TC1: Vector of Side::Buy Order<Open> already sorted
                 input: vec![
-                    order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Buy, amount!(100.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Buy, amount!(200.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Buy, amount!(300.0), amount!(1.0), amount!(0.0)),
                 ],
                 expected: vec![
-                    order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Buy, amount!(100.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Buy, amount!(200.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Buy, amount!(300.0), amount!(1.0), amount!(0.0)),
                 ],
             },
             TestCase {
                 // TC2: Vector of Side::Buy Order<Open> reverse sorted
                 input: vec![
-                    order_open(cid, Side::Buy, 300.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
+                    order_open(cid, Side::Buy, amount!(300.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Buy, amount!(200.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Buy, amount!(100.0), amount!(1.0), amount!(0.0)),
                 ],
                 expected: vec![
-                    order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Buy, amount!(100.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Buy, amount!(200.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Buy, amount!(300.0), amount!(1.0), amount!(0.0)),
                 ],
             },
             TestCase {
                 // TC3: Vector of Side::Buy Order<Open> unsorted sorted
                 input: vec![
-                    order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Buy, amount!(200.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Buy, amount!(100.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Buy, amount!(300.0), amount!(1.0), amount!(0.0)),
                 ],
                 expected: vec![
-                    order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Buy, amount!(100.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Buy, amount!(200.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Buy, amount!(300.0), amount!(1.0), amount!(0.0)),
                 ],
             },
             // -- Vector: Side::Sell Order<Open> --
             TestCase {
                 // TC1: Vector of Side::Sell Order<Open> already sorted
                 input: vec![
-                    order_open(cid, Side::Sell, 300.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                    order_open(cid, Side::Sell, amount!(300.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Sell, amount!(200.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Sell, amount!(100.0), amount!(1.0), amount!(0.0)),
                 ],
                 expected: vec![
-                    order_open(cid, Side::Sell, 300.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                    order_open(cid, Side::Sell, amount!(300.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Sell, amount!(200.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Sell, amount!(100.0), amount!(1.0), amount!(0.0)),
                 ],
             },
             TestCase {
                 // TC2: Vector of Side::Sell Order<Open> reverse sorted
                 input: vec![
-                    order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Sell, amount!(100.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Sell, amount!(200.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Sell, amount!(300.0), amount!(1.0), amount!(0.0)),
                 ],
                 expected: vec![
-                    order_open(cid, Side::Sell, 300.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                    order_open(cid, Side::Sell, amount!(300.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Sell, amount!(200.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Sell, amount!(100.0), amount!(1.0), amount!(0.0)),
                 ],
             },
             TestCase {
                 // TC3: Vector of Side::Sell Order<Open> unsorted sorted
                 input: vec![
-                    order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Sell, amount!(200.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Sell, amount!(100.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Sell, amount!(300.0), amount!(1.0), amount!(0.0)),
                 ],
                 expected: vec![
-                    order_open(cid, Side::Sell, 300.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                    order_open(cid, Side::Sell, amount!(300.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Sell, amount!(200.0), amount!(1.0), amount!(0.0)),
+                    order_open(cid, Side::Sell, amount!(100.0), amount!(1.0), amount!(0.0)),
                 ],
             },
         ];
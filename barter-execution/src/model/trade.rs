@@ -1,5 +1,6 @@
 use super::order::OrderId;
 use barter_integration::model::{
+    amount::Amount,
     instrument::{symbol::Symbol, Instrument},
     Side,
 };
@@ -12,8 +13,8 @@ pub struct Trade {
     pub order_id: OrderId,
     pub instrument: Instrument,
     pub side: Side,
-    pub price: f64,
-    pub quantity: f64,
+    pub price: Amount,
+    pub quantity: Amount,
     pub fees: SymbolFees,
 }
 
@@ -37,12 +38,12 @@ where
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct SymbolFees {
     pub symbol: Symbol,
-    pub fees: f64,
+    pub fees: Amount,
 }
 
 impl SymbolFees {
     /// Construct a new [`SymbolFees`].
-    pub fn new<S>(symbol: S, fees: f64) -> Self
+    pub fn new<S>(symbol: S, fees: Amount) -> Self
     where
         S: Into<Symbol>,
     {
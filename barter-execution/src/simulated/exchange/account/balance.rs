@@ -7,6 +7,7 @@ use crate::{
     ExecutionError, ExecutionId, Open, Order,
 };
 use barter_integration::model::{
+    amount::Amount,
     instrument::{symbol::Symbol, Instrument},
     Exchange, Side,
 };
@@ -51,7 +52,7 @@ impl ClientBalances {
     pub fn has_sufficient_available_balance(
         &self,
         symbol: &Symbol,
-        required_balance: f64,
+        required_balance: Amount,
     ) -> Result<(), ExecutionError> {
         let available = self.balance(symbol)?.available;
         match available >= required_balance {
@@ -63,7 +64,11 @@ impl ClientBalances {
     /// Updates the associated [`Symbol`] [`Balance`] when a client creates an [`Order<Open>`]. The
     /// nature of the [`Balance`] change will depend on if the [`Order<Open>`] is a
     /// [`Side::Buy`] or [`Side::Sell`].
-    pub fn update_from_open(&mut self, open: &Order<Open>, required_balance: f64) -> AccountEvent {
+    pub fn update_from_open(
+        &mut self,
+        open: &Order<Open>,
+        required_balance: Amount,
+    ) -> AccountEvent {
         let updated_balance = match open.side {
             Side::Buy => {
                 let balance = self
@@ -140,7 +145,7 @@ impl ClientBalances {
                 // Note: available was already decreased by the opening of the Side::Buy order
                 let quote_delta = BalanceDelta {
                     total: -trade.quantity * trade.price,
-                    available: 0.0,
+                    available: Amount::default(),
                 };
 
                 (base_delta, quote_delta)
@@ -150,7 +155,7 @@ impl ClientBalances {
                 // Note: available was already decreased by the opening of the Side::Sell order
                 let base_delta = BalanceDelta {
                     total: -trade.quantity,
-                    available: 0.0,
+                    available: Amount::default(),
                 };
 
                 // Quote total & available increase by (trade.quantity * price) minus quote fees
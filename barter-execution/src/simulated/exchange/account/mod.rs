@@ -8,7 +8,7 @@ use crate::{
     Cancelled, ExecutionError, ExecutionId, Open, Order, RequestCancel, RequestOpen,
 };
 use barter_data::subscription::trade::PublicTrade;
-use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use barter_integration::model::{amount::Amount, instrument::Instrument, Exchange, Side};
 use chrono::Utc;
 use std::{fmt::Debug, time::Duration};
 use tokio::sync::{mpsc, oneshot};
@@ -26,7 +26,7 @@ pub mod order;
 #[derive(Clone, Debug)]
 pub struct ClientAccount {
     pub latency: Duration,
-    pub fees_percent: f64,
+    pub fees_percent: Amount,
     pub event_account_tx: mpsc::UnboundedSender<AccountEvent>,
     pub balances: ClientBalances,
     pub orders: ClientOrders,
@@ -303,7 +303,7 @@ pub fn respond_with_latency<Response>(
 #[derive(Debug, Default)]
 pub struct ClientAccountBuilder {
     latency: Option<Duration>,
-    fees_percent: Option<f64>,
+    fees_percent: Option<Amount>,
     event_account_tx: Option<mpsc::UnboundedSender<AccountEvent>>,
     instruments: Option<Vec<Instrument>>,
     balances: Option<ClientBalances>,
@@ -323,7 +323,7 @@ impl ClientAccountBuilder {
         }
     }
 
-    pub fn fees_percent(self, value: f64) -> Self {
+    pub fn fees_percent(self, value: Amount) -> Self {
         Self {
             fees_percent: Some(value),
             ..self
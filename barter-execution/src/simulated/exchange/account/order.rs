@@ -3,7 +3,11 @@ use crate::{
     ExecutionError, Open, Order, OrderId, RequestOpen,
 };
 use barter_data::subscription::trade::PublicTrade;
-use barter_integration::model::{instrument::Instrument, Side};
+use barter_integration::model::{
+    amount::{Amount, AmountExt},
+    instrument::Instrument,
+    Side,
+};
 use serde::{Deserialize, Serialize};
 use std::{cmp::Ordering, collections::HashMap};
 
@@ -97,13 +101,15 @@ impl Orders {
     ///    price, preferentially select the Order<Open> with the larger remaining quantity to
     ///    match on.
     pub fn has_matching_order(&self, trade: &PublicTrade) -> Option<Side> {
+        let trade_price = Amount::from_f64_lossy(trade.price);
+
         match (self.bids.last(), self.asks.last()) {
             // Check the best bid & ask Order<Open> for a match
             (Some(best_bid), Some(best_ask)) => {
                 // Note:
-                // In the unlikely case that: best_bid.price == best_ask.price == trade.price
+                // In the unlikely case that: best_bid.price == best_ask.price == trade_price
                 // Preferentially select the larger remaining quantity Order<Open> to match on
-                if best_bid.state.price == trade.price && best_ask.state.price == trade.price {
+                if best_bid.state.price == trade_price && best_ask.state.price == trade_price {
                     let best_bid_quantity = best_bid.state.remaining_quantity();
                     let best_ask_quantity = best_ask.state.remaining_quantity();
                     match best_bid_quantity.partial_cmp(&best_ask_quantity) {
@@ -112,11 +118,11 @@ impl Orders {
                     }
                 }
                 // Best bid matches
-                else if best_bid.state.price >= trade.price {
+                else if best_bid.state.price >= trade_price {
                     Some(Side::Buy)
                 }
                 // Best ask matches
-                else if best_ask.state.price <= trade.price {
+                else if best_ask.state.price <= trade_price {
                     Some(Side::Sell)
                 }
                 // No matches
@@ -126,10 +132,10 @@ impl Orders {
             }
 
             // Best bid Order<Open> matches the input PublicTrade
-            (Some(best_bid), None) if best_bid.state.price >= trade.price => Some(Side::Buy),
+            (Some(best_bid), None) if best_bid.state.price >= trade_price => Some(Side::Buy),
 
             // Best ask Order<Open> matches the input PublicTrade
-            (None, Some(best_ask)) if best_ask.state.price <= trade.price => Some(Side::Sell),
+            (None, Some(best_ask)) if best_ask.state.price <= trade_price => Some(Side::Sell),
 
             // Either no bid or ask Order<Open>, or no matches
             _ => None,
@@ -138,9 +144,10 @@ impl Orders {
 
     /// Simulates [`Side::Buy`] trades by using the [`PublicTrade`] liquidity to match on open
     /// client bid [`Order<Open>`]s.
-    pub fn match_bids(&mut self, trade: &PublicTrade, fees_percent: f64) -> Vec<Trade> {
+    pub fn match_bids(&mut self, trade: &PublicTrade, fees_percent: Amount) -> Vec<Trade> {
         // Keep track of how much trade liquidity is remaining to match with
-        let mut remaining_liquidity = trade.amount;
+        let mut remaining_liquidity = Amount::from_f64_lossy(trade.amount);
+        let trade_price = Amount::from_f64_lossy(trade.price);
 
         // Collection of execution Trades generated from Order<Open> matches
         let mut trades = vec![];
@@ -153,7 +160,7 @@ impl Orders {
             };
 
             // Break with remaining best bid if it's not a match, or trade liquidity is exhausted
-            if best_bid.state.price < trade.price || remaining_liquidity <= 0.0 {
+            if best_bid.state.price < trade_price || remaining_liquidity <= Amount::ZERO {
                 break Some(best_bid);
             }
 
@@ -170,7 +177,7 @@ impl Orders {
                     trades.push(self.generate_trade(best_bid, trade_quantity, fees_percent));
 
                     // If exact full fill with zero remaining liquidity (highly unlikely), break
-                    if remaining_liquidity == 0.0 {
+                    if remaining_liquidity == Amount::ZERO {
                         break None;
                     }
                 }
@@ -205,8 +212,8 @@ impl Orders {
     pub fn generate_trade(
         &self,
         order: Order<Open>,
-        trade_quantity: f64,
-        fees_percent: f64,
+        trade_quantity: Amount,
+        fees_percent: Amount,
     ) -> Trade {
         // Calculate the trade fees (denominated in base or quote depending on Order Side)
         let fees = calculate_fees(&order, trade_quantity, fees_percent);
@@ -231,9 +238,10 @@ impl Orders {
 
     /// Simulates [`Side::Sell`] trades by using the [`PublicTrade`] liquidity to match on open
     /// client bid [`Order<Open>`]s.
-    pub fn match_asks(&mut self, trade: &PublicTrade, fees_percent: f64) -> Vec<Trade> {
+    pub fn match_asks(&mut self, trade: &PublicTrade, fees_percent: Amount) -> Vec<Trade> {
         // Keep track of how much trade liquidity is remaining to match with
-        let mut remaining_liquidity = trade.amount;
+        let mut remaining_liquidity = Amount::from_f64_lossy(trade.amount);
+        let trade_price = Amount::from_f64_lossy(trade.price);
 
         // Collection of execution Trades generated from Order<Open> matches
         let mut trades = vec![];
@@ -246,7 +254,7 @@ impl Orders {
             };
 
             // Break with remaining best ask if it's not a match, or trade liquidity is exhausted
-            if best_ask.state.price > trade.price || remaining_liquidity <= 0.0 {
+            if best_ask.state.price > trade_price || remaining_liquidity <= Amount::ZERO {
                 break Some(best_ask);
             }
 
@@ -263,7 +271,7 @@ impl Orders {
                     trades.push(self.generate_trade(best_ask, trade_quantity, fees_percent));
 
                     // If exact full fill with zero remaining liquidity (highly unlikely), break
-                    if remaining_liquidity == 0.0 {
+                    if remaining_liquidity == Amount::ZERO {
                         break None;
                     }
                 }
@@ -310,7 +318,7 @@ pub enum OrderFill {
 
 impl OrderFill {
     /// Determine the [`OrderFill`] kind given the [`Order<Open>`] and the available liquidity.
-    pub fn kind(order: &Order<Open>, liquidity: f64) -> Self {
+    pub fn kind(order: &Order<Open>, liquidity: Amount) -> Self {
         match order.state.remaining_quantity() <= liquidity {
             true => Self::Full,
             false => Self::Partial,
@@ -319,7 +327,11 @@ impl OrderFill {
 }
 
 /// Calculate the [`SymbolFees`] of a [`Order<Open>`] match (trade).
-pub fn calculate_fees(order: &Order<Open>, trade_quantity: f64, fees_percent: f64) -> SymbolFees {
+pub fn calculate_fees(
+    order: &Order<Open>,
+    trade_quantity: Amount,
+    fees_percent: Amount,
+) -> SymbolFees {
     match order.side {
         Side::Buy => SymbolFees::new(order.instrument.base.clone(), fees_percent * trade_quantity),
         Side::Sell => SymbolFees::new(
@@ -337,7 +349,7 @@ mod tests {
         simulated::exchange::account::order::Orders,
         test_util::{client_orders, order_open, public_trade, trade},
     };
-    use barter_integration::model::Side;
+    use barter_integration::{amount, model::Side};
     use uuid::Uuid;
 
     #[test]
@@ -359,7 +371,17 @@ mod tests {
             },
             TestCase {
                 // TC1: No matching bid for trade with no asks open
-                orders: client_orders(0, vec![order_open(cid, Side::Buy, 100.0, 1.0, 0.0)], vec![]),
+                orders: client_orders(
+                    0,
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        amount!(100.0),
+                        amount!(1.0),
+                        amount!(0.0),
+                    )],
+                    vec![],
+                ),
                 input_trade: public_trade(Side::Buy, 150.0, 1.0),
                 expected: None,
             },
@@ -368,14 +390,30 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![],
-                    vec![order_open(cid, Side::Sell, 100.0, 1.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        amount!(100.0),
+                        amount!(1.0),
+                        amount!(0.0),
+                    )],
                 ),
                 input_trade: public_trade(Side::Sell, 50.0, 1.0),
                 expected: None,
             },
             TestCase {
                 // TC3: Exact matching bid for trade with no asks open
-                orders: client_orders(0, vec![order_open(cid, Side::Buy, 100.0, 1.0, 0.0)], vec![]),
+                orders: client_orders(
+                    0,
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        amount!(100.0),
+                        amount!(1.0),
+                        amount!(0.0),
+                    )],
+                    vec![],
+                ),
                 input_trade: public_trade(Side::Buy, 100.0, 1.0),
                 expected: Some(Side::Buy),
             },
@@ -384,7 +422,13 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![],
-                    vec![order_open(cid, Side::Sell, 100.0, 1.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        amount!(100.0),
+                        amount!(1.0),
+                        amount!(0.0),
+                    )],
                 ),
                 input_trade: public_trade(Side::Sell, 100.0, 1.0),
                 expected: Some(Side::Sell),
@@ -393,8 +437,20 @@ mod tests {
                 // TC5: No matches for trade with open bids and asks
                 orders: client_orders(
                     0,
-                    vec![order_open(cid, Side::Buy, 50.0, 1.0, 0.0)],
-                    vec![order_open(cid, Side::Sell, 150.0, 1.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        amount!(50.0),
+                        amount!(1.0),
+                        amount!(0.0),
+                    )],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        amount!(150.0),
+                        amount!(1.0),
+                        amount!(0.0),
+                    )],
                 ),
                 input_trade: public_trade(Side::Buy, 100.0, 1.0),
                 expected: None,
@@ -403,8 +459,20 @@ mod tests {
                 // TC6: Trade matches bid & ask (same price), so take larger quantity bid
                 orders: client_orders(
                     0,
-                    vec![order_open(cid, Side::Buy, 100.0, 100.0, 0.0)],
-                    vec![order_open(cid, Side::Sell, 100.0, 1.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        amount!(100.0),
+                        amount!(100.0),
+                        amount!(0.0),
+                    )],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        amount!(100.0),
+                        amount!(1.0),
+                        amount!(0.0),
+                    )],
                 ),
                 input_trade: public_trade(Side::Buy, 100.0, 1.0),
                 expected: Some(Side::Buy),
@@ -413,8 +481,20 @@ mod tests {
                 // TC6: Trade matches bid & ask (same price), so take larger quantity ask
                 orders: client_orders(
                     0,
-                    vec![order_open(cid, Side::Buy, 100.0, 1.0, 0.0)],
-                    vec![order_open(cid, Side::Sell, 100.0, 100.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        amount!(100.0),
+                        amount!(1.0),
+                        amount!(0.0),
+                    )],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        amount!(100.0),
+                        amount!(100.0),
+                        amount!(0.0),
+                    )],
                 ),
                 input_trade: public_trade(Side::Buy, 100.0, 1.0),
                 expected: Some(Side::Sell),
@@ -432,7 +512,7 @@ mod tests {
         struct TestCase {
             orders: Orders,
             input_trade: PublicTrade,
-            input_fees_percent: f64,
+            input_fees_percent: Amount,
             expected_orders: Orders,
             expected_trades: Vec<Trade>,
         }
@@ -445,24 +525,30 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![
-                        order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                        order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
+                        order_open(cid, Side::Buy, amount!(100.0), amount!(1.0), amount!(0.0)),
+                        order_open(cid, Side::Buy, amount!(200.0), amount!(1.0), amount!(0.0)),
                     ],
                     vec![],
                 ),
                 input_trade: public_trade(Side::Buy, 200.0, 1.0),
-                input_fees_percent: 0.1,
+                input_fees_percent: amount!(0.1),
                 expected_orders: client_orders(
                     1,
-                    vec![order_open(cid, Side::Buy, 100.0, 1.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        amount!(100.0),
+                        amount!(1.0),
+                        amount!(0.0),
+                    )],
                     vec![],
                 ),
                 expected_trades: vec![trade(
                     TradeId(1.to_string()),
                     Side::Buy,
-                    200.0,
-                    1.0,
-                    SymbolFees::new("base", 0.1 * 1.0),
+                    amount!(200.0),
+                    amount!(1.0),
+                    SymbolFees::new("base", amount!(0.1) * amount!(1.0)),
                 )],
             },
             TestCase {
@@ -470,28 +556,28 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![
-                        order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                        order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
+                        order_open(cid, Side::Buy, amount!(100.0), amount!(1.0), amount!(0.0)),
+                        order_open(cid, Side::Buy, amount!(200.0), amount!(1.0), amount!(0.0)),
                     ],
                     vec![],
                 ),
                 input_trade: public_trade(Side::Buy, 100.0, 2.0),
-                input_fees_percent: 0.1,
+                input_fees_percent: amount!(0.1),
                 expected_orders: client_orders(2, vec![], vec![]),
                 expected_trades: vec![
                     trade(
                         TradeId(1.to_string()),
                         Side::Buy,
-                        200.0,
-                        1.0,
-                        SymbolFees::new("base", 0.1 * 1.0),
+                        amount!(200.0),
+                        amount!(1.0),
+                        SymbolFees::new("base", amount!(0.1) * amount!(1.0)),
                     ),
                     trade(
                         TradeId(2.to_string()),
                         Side::Buy,
-                        100.0,
-                        1.0,
-                        SymbolFees::new("base", 0.1 * 1.0),
+                        amount!(100.0),
+                        amount!(1.0),
+                        SymbolFees::new("base", amount!(0.1) * amount!(1.0)),
                     ),
                 ],
             },
@@ -500,32 +586,38 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![
-                        order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                        order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
+                        order_open(cid, Side::Buy, amount!(100.0), amount!(1.0), amount!(0.0)),
+                        order_open(cid, Side::Buy, amount!(200.0), amount!(1.0), amount!(0.0)),
                     ],
                     vec![],
                 ),
                 input_trade: public_trade(Side::Sell, 100.0, 1.5),
-                input_fees_percent: 0.1,
+                input_fees_percent: amount!(0.1),
                 expected_orders: client_orders(
                     2,
-                    vec![order_open(cid, Side::Buy, 100.0, 1.0, 0.5)],
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        amount!(100.0),
+                        amount!(1.0),
+                        amount!(0.5),
+                    )],
                     vec![],
                 ),
                 expected_trades: vec![
                     trade(
                         TradeId(1.to_string()),
                         Side::Buy,
-                        200.0,
-                        1.0,
-                        SymbolFees::new("base", 0.1 * 1.0),
+                        amount!(200.0),
+                        amount!(1.0),
+                        SymbolFees::new("base", amount!(0.1) * amount!(1.0)),
                     ),
                     trade(
                         TradeId(2.to_string()),
                         Side::Buy,
-                        100.0,
-                        0.5,
-                        SymbolFees::new("base", 0.1 * 0.5),
+                        amount!(100.0),
+                        amount!(0.5),
+                        SymbolFees::new("base", amount!(0.1) * amount!(0.5)),
                     ),
                 ],
             },
@@ -534,18 +626,18 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![
-                        order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                        order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
+                        order_open(cid, Side::Buy, amount!(100.0), amount!(1.0), amount!(0.0)),
+                        order_open(cid, Side::Buy, amount!(200.0), amount!(1.0), amount!(0.0)),
                     ],
                     vec![],
                 ),
                 input_trade: public_trade(Side::Sell, 1_000_000_000.0, 1.0),
-                input_fees_percent: 0.1,
+                input_fees_percent: amount!(0.1),
                 expected_orders: client_orders(
                     0,
                     vec![
-                        order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                        order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
+                        order_open(cid, Side::Buy, amount!(100.0), amount!(1.0), amount!(0.0)),
+                        order_open(cid, Side::Buy, amount!(200.0), amount!(1.0), amount!(0.0)),
                     ],
                     vec![],
                 ),
@@ -569,7 +661,7 @@ mod tests {
         struct TestCase {
             orders: Orders,
             input_trade: PublicTrade,
-            input_fees_percent: f64,
+            input_fees_percent: Amount,
             expected_orders: Orders,
             expected_trades: Vec<Trade>,
         }
@@ -583,23 +675,29 @@ mod tests {
                     0,
                     vec![],
                     vec![
-                        order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, amount!(200.0), amount!(1.0), amount!(0.0)),
+                        order_open(cid, Side::Sell, amount!(100.0), amount!(1.0), amount!(0.0)),
                     ],
                 ),
                 input_trade: public_trade(Side::Buy, 100.0, 1.0),
-                input_fees_percent: 0.1,
+                input_fees_percent: amount!(0.1),
                 expected_orders: client_orders(
                     1,
                     vec![],
-                    vec![order_open(cid, Side::Sell, 200.0, 1.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        amount!(200.0),
+                        amount!(1.0),
+                        amount!(0.0),
+                    )],
                 ),
                 expected_trades: vec![trade(
                     TradeId(1.to_string()),
                     Side::Sell,
-                    100.0,
-                    1.0,
-                    SymbolFees::new("quote", 0.1 * 100.0 * 1.0),
+                    amount!(100.0),
+                    amount!(1.0),
+                    SymbolFees::new("quote", amount!(0.1) * amount!(100.0) * amount!(1.0)),
                 )],
             },
             TestCase {
@@ -608,27 +706,27 @@ mod tests {
                     0,
                     vec![],
                     vec![
-                        order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, amount!(200.0), amount!(1.0), amount!(0.0)),
+                        order_open(cid, Side::Sell, amount!(100.0), amount!(1.0), amount!(0.0)),
                     ],
                 ),
                 input_trade: public_trade(Side::Buy, 200.0, 2.0),
-                input_fees_percent: 0.1,
+                input_fees_percent: amount!(0.1),
                 expected_orders: client_orders(2, vec![], vec![]),
                 expected_trades: vec![
                     trade(
                         TradeId(1.to_string()),
                         Side::Sell,
-                        100.0,
-                        1.0,
-                        SymbolFees::new("quote", 0.1 * 100.0 * 1.0),
+                        amount!(100.0),
+                        amount!(1.0),
+                        SymbolFees::new("quote", amount!(0.1) * amount!(100.0) * amount!(1.0)),
                     ),
                     trade(
                         TradeId(2.to_string()),
                         Side::Sell,
-                        200.0,
-                        1.0,
-                        SymbolFees::new("quote", 0.1 * 200.0 * 1.0),
+                        amount!(200.0),
+                        amount!(1.0),
+                        SymbolFees::new("quote", amount!(0.1) * amount!(200.0) * amount!(1.0)),
                     ),
                 ],
             },
@@ -638,31 +736,37 @@ mod tests {
                     0,
                     vec![],
                     vec![
-                        order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, amount!(200.0), amount!(1.0), amount!(0.0)),
+                        order_open(cid, Side::Sell, amount!(100.0), amount!(1.0), amount!(0.0)),
                     ],
                 ),
                 input_trade: public_trade(Side::Sell, 200.0, 1.5),
-                input_fees_percent: 0.1,
+                input_fees_percent: amount!(0.1),
                 expected_orders: client_orders(
                     2,
                     vec![],
-                    vec![order_open(cid, Side::Sell, 200.0, 1.0, 0.5)],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        amount!(200.0),
+                        amount!(1.0),
+                        amount!(0.5),
+                    )],
                 ),
                 expected_trades: vec![
                     trade(
                         TradeId(1.to_string()),
                         Side::Sell,
-                        100.0,
-                        1.0,
-                        SymbolFees::new("quote", 0.1 * 100.0 * 1.0),
+                        amount!(100.0),
+                        amount!(1.0),
+                        SymbolFees::new("quote", amount!(0.1) * amount!(100.0) * amount!(1.0)),
                     ),
                     trade(
                         TradeId(2.to_string()),
                         Side::Sell,
-                        200.0,
-                        0.5,
-                        SymbolFees::new("quote", 0.1 * 200.0 * 0.5),
+                        amount!(200.0),
+                        amount!(0.5),
+                        SymbolFees::new("quote", amount!(0.1) * amount!(200.0) * amount!(0.5)),
                     ),
                 ],
             },
@@ -672,18 +776,18 @@ mod tests {
                     0,
                     vec![],
                     vec![
-                        order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, amount!(200.0), amount!(1.0), amount!(0.0)),
+                        order_open(cid, Side::Sell, amount!(100.0), amount!(1.0), amount!(0.0)),
                     ],
                 ),
                 input_trade: public_trade(Side::Sell, 1.0, 1.0),
-                input_fees_percent: 0.1,
+                input_fees_percent: amount!(0.1),
                 expected_orders: client_orders(
                     0,
                     vec![],
                     vec![
-                        order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, amount!(200.0), amount!(1.0), amount!(0.0)),
+                        order_open(cid, Side::Sell, amount!(100.0), amount!(1.0), amount!(0.0)),
                     ],
                 ),
                 expected_trades: vec![],
@@ -718,7 +822,17 @@ mod tests {
             },
             TestCase {
                 // TC1: one bid, empty ask
-                orders: client_orders(0, vec![order_open(cid, Side::Buy, 150.0, 1.0, 0.0)], vec![]),
+                orders: client_orders(
+                    0,
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        amount!(150.0),
+                        amount!(1.0),
+                        amount!(0.0),
+                    )],
+                    vec![],
+                ),
                 expected_num: 1,
             },
             TestCase {
@@ -726,7 +840,13 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![],
-                    vec![order_open(cid, Side::Sell, 150.0, 1.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        amount!(150.0),
+                        amount!(1.0),
+                        amount!(0.0),
+                    )],
                 ),
                 expected_num: 1,
             },
@@ -735,12 +855,12 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![
-                        order_open(cid, Side::Sell, 150.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 150.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, amount!(150.0), amount!(1.0), amount!(0.0)),
+                        order_open(cid, Side::Sell, amount!(150.0), amount!(1.0), amount!(0.0)),
                     ],
                     vec![
-                        order_open(cid, Side::Sell, 150.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 150.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, amount!(150.0), amount!(1.0), amount!(0.0)),
+                        order_open(cid, Side::Sell, amount!(150.0), amount!(1.0), amount!(0.0)),
                     ],
                 ),
                 expected_num: 4,
@@ -757,7 +877,7 @@ mod tests {
     fn test_order_fill_kind() {
         struct TestCase {
             input_order: Order<Open>,
-            input_liquidity: f64,
+            input_liquidity: Amount,
             expected: OrderFill,
         }
 
@@ -766,26 +886,26 @@ mod tests {
         let tests = vec![
             TestCase {
                 // TC0: Zero filled bid is fully filled by remaining liquidity
-                input_order: order_open(cid, Side::Buy, 10.0, 10.0, 0.0),
-                input_liquidity: 10.0,
+                input_order: order_open(cid, Side::Buy, amount!(10.0), amount!(10.0), amount!(0.0)),
+                input_liquidity: amount!(10.0),
                 expected: OrderFill::Full,
             },
             TestCase {
                 // TC1: Partially filled bid is fully filled by remaining liquidity
-                input_order: order_open(cid, Side::Buy, 10.0, 10.0, 5.0),
-                input_liquidity: 10.0,
+                input_order: order_open(cid, Side::Buy, amount!(10.0), amount!(10.0), amount!(5.0)),
+                input_liquidity: amount!(10.0),
                 expected: OrderFill::Full,
             },
             TestCase {
                 // TC2: Zero filled bid is partially filled by remaining liquidity
-                input_order: order_open(cid, Side::Buy, 10.0, 10.0, 0.0),
-                input_liquidity: 5.0,
+                input_order: order_open(cid, Side::Buy, amount!(10.0), amount!(10.0), amount!(0.0)),
+                input_liquidity: amount!(5.0),
                 expected: OrderFill::Partial,
             },
             TestCase {
                 // TC3: Partially filled bid is partially filled by remaining liquidity
-                input_order: order_open(cid, Side::Buy, 10.0, 10.0, 1.0),
-                input_liquidity: 5.0,
+                input_order: order_open(cid, Side::Buy, amount!(10.0), amount!(10.0), amount!(1.0)),
+                input_liquidity: amount!(5.0),
                 expected: OrderFill::Partial,
             },
         ];
@@ -800,8 +920,8 @@ mod tests {
     fn test_calculate_fees() {
         struct TestCase {
             order: Order<Open>,
-            trade_quantity: f64,
-            fees_percent: f64,
+            trade_quantity: Amount,
+            fees_percent: Amount,
             expected: SymbolFees,
         }
 
@@ -810,17 +930,17 @@ mod tests {
         let tests = vec![
             TestCase {
                 // TC0: 10% trade fees from matched Side::Buy order
-                order: order_open(cid, Side::Buy, 100.0, 10.0, 0.0),
-                trade_quantity: 10.0,
-                fees_percent: 0.1,
-                expected: SymbolFees::new("base", 0.1 * 10.0),
+                order: order_open(cid, Side::Buy, amount!(100.0), amount!(10.0), amount!(0.0)),
+                trade_quantity: amount!(10.0),
+                fees_percent: amount!(0.1),
+                expected: SymbolFees::new("base", amount!(0.1) * amount!(10.0)),
             },
             TestCase {
                 // TC1: 50% trade fees from matched Side::Sell order
-                order: order_open(cid, Side::Sell, 100.0, 10.0, 0.0),
-                trade_quantity: 10.0,
-                fees_percent: 0.5,
-                expected: SymbolFees::new("quote", 0.5 * 100.0 * 10.0),
+                order: order_open(cid, Side::Sell, amount!(100.0), amount!(10.0), amount!(0.0)),
+                trade_quantity: amount!(10.0),
+                fees_percent: amount!(0.5),
+                expected: SymbolFees::new("quote", amount!(0.5) * amount!(100.0) * amount!(10.0)),
             },
         ];
 
@@ -14,9 +14,12 @@ use barter_execution::{
     simulated::{execution::SimulatedExecution, SimulatedEvent},
     ExecutionClient,
 };
-use barter_integration::model::{
-    instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
-    Side,
+use barter_integration::{
+    amount,
+    model::{
+        instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
+        Side,
+    },
 };
 use tokio::sync::mpsc;
 use uuid::Uuid;
@@ -166,8 +169,8 @@ async fn test_3_open_limit_buy_order(
             Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
             test_3_ids.cid,
             Side::Buy,
-            100.0,
-            1.0,
+            amount!(100.0),
+            amount!(1.0),
         )])
         .await;
 
@@ -176,9 +179,9 @@ async fn test_3_open_limit_buy_order(
         test_3_ids.cid,
         test_3_ids.id,
         Side::Buy,
-        100.0,
-        1.0,
-        0.0,
+        amount!(100.0),
+        amount!(1.0),
+        amount!(0.0),
     );
 
     assert_eq!(new_orders.len(), 1);
@@ -191,7 +194,8 @@ async fn test_3_open_limit_buy_order(
             ..
         }) => {
             // Expected usdt Balance.available = 10_000 - (100.0 * 1.0)
-            let expected = SymbolBalance::new("usdt", Balance::new(10_000.0, 9_900.0));
+            let expected =
+                SymbolBalance::new("usdt", Balance::new(amount!(10_000.0), amount!(9_900.0)));
             assert_eq!(usdt_balance, expected);
         }
         other => {
@@ -294,7 +298,8 @@ async fn test_5_cancel_buy_order(
             ..
         }) => {
             // Expected usdt Balance.available = 9_900 + (100.0 * 1.0)
-            let expected = SymbolBalance::new("usdt", Balance::new(10_000.0, 10_000.0));
+            let expected =
+                SymbolBalance::new("usdt", Balance::new(amount!(10_000.0), amount!(10_000.0)));
             assert_eq!(usdt_balance, expected);
         }
         other => {
@@ -324,15 +329,15 @@ async fn test_6_open_2x_limit_buy_orders(
                 Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 test_6_ids_1.cid,
                 Side::Buy,
-                100.0,
-                1.0,
+                amount!(100.0),
+                amount!(1.0),
             ),
             order_request_limit(
                 Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 test_6_ids_2.cid,
                 Side::Buy,
-                200.0,
-                1.0,
+                amount!(200.0),
+                amount!(1.0),
             ),
         ])
         .await;
@@ -342,9 +347,9 @@ async fn test_6_open_2x_limit_buy_orders(
         test_6_ids_1.cid,
         test_6_ids_1.id.clone(),
         Side::Buy,
-        100.0,
-        1.0,
-        0.0,
+        amount!(100.0),
+        amount!(1.0),
+        amount!(0.0),
     );
 
     let expected_order_new_2 = open_order(
@@ -352,9 +357,9 @@ async fn test_6_open_2x_limit_buy_orders(
         test_6_ids_2.cid,
         test_6_ids_2.id,
         Side::Buy,
-        200.0,
-        1.0,
-        0.0,
+        amount!(200.0),
+        amount!(1.0),
+        amount!(0.0),
     );
 
     assert_eq!(opened_orders.len(), 2);
@@ -368,7 +373,8 @@ async fn test_6_open_2x_limit_buy_orders(
             ..
         }) => {
             // Expected usdt Balance.available = 10_000 - (100.0 * 1.0)
-            let expected = SymbolBalance::new("usdt", Balance::new(10_000.0, 9_900.0));
+            let expected =
+                SymbolBalance::new("usdt", Balance::new(amount!(10_000.0), amount!(9_900.0)));
             assert_eq!(usdt_balance, expected);
         }
         other => {
@@ -397,7 +403,8 @@ async fn test_6_open_2x_limit_buy_orders(
             ..
         }) => {
             // Expected usdt Balance.available = 9_900 - (200.0 * 1.0)
-            let expected = SymbolBalance::new("usdt", Balance::new(10_000.0, 9_700.0));
+            let expected =
+                SymbolBalance::new("usdt", Balance::new(amount!(10_000.0), amount!(9_700.0)));
             assert_eq!(usdt_balance, expected);
         }
         other => {
@@ -459,15 +466,19 @@ async fn test_7_send_market_event_that_exact_full_matches_order(
             assert_eq!(balances.len(), 2);
 
             // Base Balance first: expected btc { total: 10.0 + 1.0 - fees, available: 10.0 + 1.0 - fees }
-            let btc_fees = 1.0 * fees_50_percent();
+            let btc_fees = amount!(1.0) * fees_50_percent();
             let expected_btc = SymbolBalance::new(
                 "btc",
-                Balance::new(10.0 + 1.0 - btc_fees, 10.0 + 1.0 - btc_fees),
+                Balance::new(
+                    amount!(10.0) + amount!(1.0) - btc_fees,
+                    amount!(10.0) + amount!(1.0) - btc_fees,
+                ),
             );
             assert_eq!(balances[0], expected_btc);
 
             // Quote Balance second: expected usdt Balance { total: 10_000 - 200, available: 9_700 }
-            let expected_usdt = SymbolBalance::new("usdt", Balance::new(9_800.0, 9_700.0));
+            let expected_usdt =
+                SymbolBalance::new("usdt", Balance::new(amount!(9_800.0), amount!(9_700.0)));
             assert_eq!(balances[1], expected_usdt);
         }
         other => {
@@ -486,9 +497,9 @@ async fn test_7_send_market_event_that_exact_full_matches_order(
                 order_id: OrderId(3.to_string()),
                 instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 side: Side::Buy,
-                price: 200.0,
-                quantity: 1.0,
-                fees: SymbolFees::new("btc", 1.0 * fees_50_percent()),
+                price: amount!(200.0),
+                quantity: amount!(1.0),
+                fees: SymbolFees::new("btc", amount!(1.0) * fees_50_percent()),
             };
             assert_eq!(trade, expected);
         }
@@ -520,9 +531,9 @@ async fn test_8_fetch_open_orders_and_check_test_6_order_cid_1_only(
             test_6_ids_1.cid,
             test_6_ids_1.id,
             Side::Buy,
-            100.0,
-            1.0,
-            0.0
+            amount!(100.0),
+            amount!(1.0),
+            amount!(0.0)
         )
     );
 }
@@ -540,15 +551,15 @@ async fn test_9_open_2x_limit_sell_orders(
                 Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 test_9_ids_1.cid,
                 Side::Sell,
-                500.0,
-                1.0,
+                amount!(500.0),
+                amount!(1.0),
             ),
             order_request_limit(
                 Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 test_9_ids_2.cid,
                 Side::Sell,
-                1000.0,
-                1.0,
+                amount!(1000.0),
+                amount!(1.0),
             ),
         ])
         .await;
@@ -558,9 +569,9 @@ async fn test_9_open_2x_limit_sell_orders(
         test_9_ids_1.cid,
         test_9_ids_1.id,
         Side::Sell,
-        500.0,
-        1.0,
-        0.0,
+        amount!(500.0),
+        amount!(1.0),
+        amount!(0.0),
     );
 
     let expected_order_new_2 = open_order(
@@ -568,9 +579,9 @@ async fn test_9_open_2x_limit_sell_orders(
         test_9_ids_2.cid,
         test_9_ids_2.id,
         Side::Sell,
-        1000.0,
-        1.0,
-        0.0,
+        amount!(1000.0),
+        amount!(1.0),
+        amount!(0.0),
     );
 
     assert_eq!(opened_orders.len(), 2);
@@ -584,7 +595,10 @@ async fn test_9_open_2x_limit_sell_orders(
             ..
         }) => {
             // Expected btc Balance.available = 10.5 - 1.0
-            let expected = SymbolBalance::new("btc", Balance::new(10.5, 10.5 - 1.0));
+            let expected = SymbolBalance::new(
+                "btc",
+                Balance::new(amount!(10.5), amount!(10.5) - amount!(1.0)),
+            );
             assert_eq!(btc_balance, expected);
         }
         other => {
@@ -619,7 +633,10 @@ async fn test_9_open_2x_limit_sell_orders(
             ..
         }) => {
             // Expected btc Balance.available = 9.5 - 1.0
-            let expected = SymbolBalance::new("btc", Balance::new(10.5, 9.5 - 1.0));
+            let expected = SymbolBalance::new(
+                "btc",
+                Balance::new(amount!(10.5), amount!(9.5) - amount!(1.0)),
+            );
             assert_eq!(btc_balance, expected);
         }
         other => {
@@ -681,7 +698,7 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
     tokio::time::sleep(latency_50ms()).await;
 
     // a) First full match fill:
-    let first_full_fill_fees = (500.0 * 1.0) * fees_50_percent();
+    let first_full_fill_fees = (amount!(500.0) * amount!(1.0)) * fees_50_percent();
     // Check AccountEvent Balances for base & quote currencies related to the trade
     match event_account_rx.try_recv() {
         Ok(AccountEvent {
@@ -692,13 +709,17 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
             assert_eq!(balances.len(), 2);
 
             // Base Balance first: expected btc Balance { total: 10.5 - 1.0, available: 8.5 }
-            let expected_btc = SymbolBalance::new("btc", Balance::new(10.5 - 1.0, 8.5));
+            let expected_btc = SymbolBalance::new(
+                "btc",
+                Balance::new(amount!(10.5) - amount!(1.0), amount!(8.5)),
+            );
             assert_eq!(balances[0], expected_btc);
 
             // Quote Balance second:
             // Expected usdt increase = (500 * 1.0) - (500 * 1.0 * 0.5) = 500 - 250 = 250
             // expected usdt Balance { total: 9_800 + 250, available: 9_700 + 250 }
-            let expected_usdt = SymbolBalance::new("usdt", Balance::new(10_050.0, 9_950.0));
+            let expected_usdt =
+                SymbolBalance::new("usdt", Balance::new(amount!(10_050.0), amount!(9_950.0)));
             assert_eq!(balances[1], expected_usdt);
         }
         other => {
@@ -719,8 +740,8 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
                 order_id: OrderId(4.to_string()),
                 instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 side: Side::Sell,
-                price: 500.0,
-                quantity: 1.0,
+                price: amount!(500.0),
+                quantity: amount!(1.0),
                 fees: SymbolFees::new("usdt", first_full_fill_fees),
             };
             assert_eq!(trade, expected);
@@ -734,7 +755,7 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
     }
 
     // b) Second partial match fill
-    let second_partial_fill_fees = (1000.0 * 0.5) * fees_50_percent();
+    let second_partial_fill_fees = (amount!(1000.0) * amount!(0.5)) * fees_50_percent();
 
     // Check AccountEvent Balances for base & quote currencies related to the trade
     match event_account_rx.try_recv() {
@@ -748,14 +769,22 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
             // btc { total: 9.0, available: 8.5 } 0.5 left in partially filled trade
 
             // Base Balance first: expected btc Balance { total: 9.5 - 0.5, available: 8.5 }
-            let expected_btc = SymbolBalance::new("btc", Balance::new(9.5 - 0.5, 8.5));
+            let expected_btc = SymbolBalance::new(
+                "btc",
+                Balance::new(amount!(9.5) - amount!(0.5), amount!(8.5)),
+            );
             assert_eq!(balances[0], expected_btc);
 
             // Quote Balance second:
             // Expected usdt increase = (1000 * 0.5) - (1000 * 0.5 * 0.5) = 500 - 250 = 250
             // expected usdt Balance { total: 10_050 + 250, available: 9_950 + 250 }
-            let expected_usdt =
-                SymbolBalance::new("usdt", Balance::new(10_050.0 + 250.0, 9_950.0 + 250.0));
+            let expected_usdt = SymbolBalance::new(
+                "usdt",
+                Balance::new(
+                    amount!(10_050.0) + amount!(250.0),
+                    amount!(9_950.0) + amount!(250.0),
+                ),
+            );
             assert_eq!(balances[1], expected_usdt);
         }
         other => {
@@ -776,8 +805,8 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
                 order_id: OrderId(5.to_string()),
                 instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 side: Side::Sell,
-                price: 1000.0,
-                quantity: 0.5,
+                price: amount!(1000.0),
+                quantity: amount!(0.5),
                 fees: SymbolFees::new("usdt", second_partial_fill_fees),
             };
             assert_eq!(trade, expected);
@@ -863,14 +892,19 @@ async fn test_11_cancel_all_orders(
             // Bids are cancelled first, so balance is updated first
             // test_6_order_cid_1, Side::Buy, price=100.0, quantity=1.0
             // Therefore, usdt Balance { total: 10_300, available: 10_200 + (100 * 1)
-            let expected_usdt =
-                SymbolBalance::new("usdt", Balance::new(10_300.0, 10_200.0 + 100.0));
+            let expected_usdt = SymbolBalance::new(
+                "usdt",
+                Balance::new(amount!(10_300.0), amount!(10_200.0) + amount!(100.0)),
+            );
             assert_eq!(balances[0], expected_usdt);
 
             // Asks are cancelled second, so balance is updated first
             // test_9_order_cid_2, Side::Sell, price=1000.0, quantity=1.0, filled=0.5
             // Therefore, btc Balance { total: 9.0, available: 8.5 + 0.5 }
-            let expected_btc = SymbolBalance::new("btc", Balance::new(9.0, 8.5 + 0.5));
+            let expected_btc = SymbolBalance::new(
+                "btc",
+                Balance::new(amount!(9.0), amount!(8.5) + amount!(0.5)),
+            );
             assert_eq!(balances[1], expected_btc);
         }
         other => {
@@ -912,15 +946,15 @@ async fn test_13_fail_to_open_one_of_two_limits_with_insufficient_funds(
                 Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 test_13_ids_1.cid,
                 Side::Buy,
-                1_000_000_000.0,
-                1.0,
+                amount!(1_000_000_000.0),
+                amount!(1.0),
             ),
             order_request_limit(
                 Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 test_13_ids_2.cid,
                 Side::Sell,
-                1000.0,
-                1.0,
+                amount!(1000.0),
+                amount!(1.0),
             ),
         ])
         .await;
@@ -931,9 +965,9 @@ async fn test_13_fail_to_open_one_of_two_limits_with_insufficient_funds(
         test_13_ids_2.cid,
         test_13_ids_2.id,
         Side::Sell,
-        1000.0,
-        1.0,
-        0.0,
+        amount!(1000.0),
+        amount!(1.0),
+        amount!(0.0),
     );
 
     assert_eq!(opened_orders.len(), 2);
@@ -949,7 +983,10 @@ async fn test_13_fail_to_open_one_of_two_limits_with_insufficient_funds(
             ..
         }) => {
             // Expected btc Balance.available = 9.0 - 1.0
-            let expected = SymbolBalance::new("btc", Balance::new(9.0, 9.0 - 1.0));
+            let expected = SymbolBalance::new(
+                "btc",
+                Balance::new(amount!(9.0), amount!(9.0) - amount!(1.0)),
+            );
             assert_eq!(btc_balance, expected);
         }
         other => {
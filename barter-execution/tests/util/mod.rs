@@ -13,9 +13,13 @@ use barter_execution::{
     },
     ExecutionId,
 };
-use barter_integration::model::{
-    instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
-    Exchange, Side,
+use barter_integration::{
+    amount,
+    model::{
+        amount::Amount,
+        instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
+        Exchange, Side,
+    },
 };
 use std::{collections::HashMap, time::Duration};
 use tokio::sync::mpsc;
@@ -53,8 +57,8 @@ pub(super) fn latency_50ms() -> Duration {
     Duration::from_millis(50)
 }
 
-pub(super) fn fees_50_percent() -> f64 {
-    0.5
+pub(super) fn fees_50_percent() -> Amount {
+    amount!(0.5)
 }
 
 // Instruments that the SimulatedExchange supports
@@ -65,8 +69,14 @@ pub(super) fn instruments() -> Vec<Instrument> {
 // Initial SimulatedExchange ClientAccount balances for each Symbol
 pub(super) fn initial_balances() -> ClientBalances {
     ClientBalances(HashMap::from([
-        (Symbol::from("btc"), Balance::new(10.0, 10.0)),
-        (Symbol::from("usdt"), Balance::new(10_000.0, 10_000.0)),
+        (
+            Symbol::from("btc"),
+            Balance::new(amount!(10.0), amount!(10.0)),
+        ),
+        (
+            Symbol::from("usdt"),
+            Balance::new(amount!(10_000.0), amount!(10_000.0)),
+        ),
     ]))
 }
 
@@ -75,8 +85,8 @@ pub(super) fn order_request_limit<I>(
     instrument: I,
     cid: ClientOrderId,
     side: Side,
-    price: f64,
-    quantity: f64,
+    price: Amount,
+    quantity: Amount,
 ) -> Order<RequestOpen>
 where
     I: Into<Instrument>,
@@ -100,9 +110,9 @@ pub(super) fn open_order<I>(
     cid: ClientOrderId,
     id: OrderId,
     side: Side,
-    price: f64,
-    quantity: f64,
-    filled: f64,
+    price: Amount,
+    quantity: Amount,
+    filled: Amount,
 ) -> Order<Open>
 where
     I: Into<Instrument>,
@@ -0,0 +1,111 @@
+#[cfg(feature = "decimal")]
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+
+/// Monetary amount, such as a price, quantity, fee or balance.
+///
+/// An `f64` by default, or an exact [`Decimal`](rust_decimal::Decimal) with the `decimal`
+/// feature enabled so that accumulated P&L, fees & balances reconcile exactly with exchange
+/// statements.
+#[cfg(not(feature = "decimal"))]
+pub type Amount = f64;
+
+/// Monetary amount, such as a price, quantity, fee or balance.
+///
+/// An `f64` by default, or an exact [`Decimal`](rust_decimal::Decimal) with the `decimal`
+/// feature enabled so that accumulated P&L, fees & balances reconcile exactly with exchange
+/// statements.
+#[cfg(feature = "decimal")]
+pub type Amount = Decimal;
+
+/// Constants & conversions available for every [`Amount`] representation.
+pub trait AmountExt: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Convert from an `f64` (eg/ a market data price or an analytical result). Rounds if the
+    /// [`Amount`] is a `Decimal`, in which case non-finite values convert to zero.
+    fn from_f64_lossy(value: f64) -> Self;
+
+    /// Convert to an `f64` (eg/ to compute statistics). Rounds if the [`Amount`] is a
+    /// `Decimal`.
+    fn to_f64_lossy(self) -> f64;
+
+    /// Parse an [`Amount`] from the text of a numeric literal. See [`amount!`](crate::amount).
+    fn from_literal(literal: &str) -> Self;
+}
+
+impl AmountExt for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    fn from_f64_lossy(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64_lossy(self) -> f64 {
+        self
+    }
+
+    fn from_literal(literal: &str) -> Self {
+        literal
+            .replace('_', "")
+            .parse()
+            .unwrap_or_else(|_| panic!("invalid Amount literal: {literal}"))
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl AmountExt for Decimal {
+    const ZERO: Self = Decimal::ZERO;
+    const ONE: Self = Decimal::ONE;
+
+    fn from_f64_lossy(value: f64) -> Self {
+        Decimal::from_f64(value).unwrap_or(Decimal::ZERO)
+    }
+
+    fn to_f64_lossy(self) -> f64 {
+        self.to_f64().unwrap_or(0.0)
+    }
+
+    fn from_literal(literal: &str) -> Self {
+        Decimal::from_str_exact(&literal.replace('_', ""))
+            .unwrap_or_else(|_| panic!("invalid Amount literal: {literal}"))
+    }
+}
+
+/// Construct an [`Amount`] from a numeric literal, exactly if the [`Amount`] is a `Decimal`.
+///
+/// ### Examples
+/// ```
+/// use barter_integration::{amount, model::amount::{Amount, AmountExt}};
+///
+/// let fees: Amount = amount!(1.5) + amount!(2.5);
+/// assert_eq!(fees, amount!(4.0));
+/// assert_eq!(fees.to_f64_lossy(), 4.0);
+/// assert_eq!(-amount!(1.5), Amount::ZERO - amount!(1.5));
+/// ```
+#[macro_export]
+macro_rules! amount {
+    ($value:literal) => {
+        <$crate::model::amount::Amount as $crate::model::amount::AmountExt>::from_literal(
+            stringify!($value),
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amount_literal_and_f64_conversions() {
+        assert_eq!(amount!(100.5), Amount::from_f64_lossy(100.5));
+        assert_eq!(amount!(0), Amount::ZERO);
+        assert_eq!(amount!(1.0), Amount::ONE);
+        assert_eq!(amount!(2.25).to_f64_lossy(), 2.25);
+        assert_eq!(amount!(10_000.5), Amount::from_f64_lossy(10_000.5));
+    }
+}
@@ -5,6 +5,9 @@ use std::{
     fmt::{Debug, Display, Formatter},
 };
 
+/// Monetary [`Amount`](amount::Amount) type, an `f64` or a `Decimal` with the `decimal` feature.
+pub mod amount;
+
 /// [`Instrument`] related data structures.
 ///
 /// eg/ `Instrument`, `InstrumentKind`, `OptionContract`, `Symbol`, etc.
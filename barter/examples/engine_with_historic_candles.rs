@@ -1,6 +1,10 @@
 use barter::{
     data::historical,
-    engine::{trader::Trader, Engine},
+    engine::{
+        clock::{EngineClock, HistoricalClock},
+        trader::Trader,
+        Engine,
+    },
     event::{Event, EventTx},
     execution::{
         simulated::{Config as ExecutionConfig, SimulatedExecution},
@@ -20,11 +24,13 @@ use barter_data::{
     event::{DataKind, MarketEvent},
     subscription::candle::Candle,
 };
-use barter_integration::model::{
-    instrument::{kind::InstrumentKind, Instrument},
-    Exchange, Market,
+use barter_integration::{
+    amount,
+    model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange, Market,
+    },
 };
-use chrono::Utc;
 use parking_lot::Mutex;
 use std::{collections::HashMap, fs, sync::Arc};
 use tokio::sync::mpsc;
@@ -47,15 +53,26 @@ async fn main() {
     // Create the Market(s) to be traded on (1-to-1 relationship with a Trader)
     let market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
 
+    // Load historical MarketEvents & create a HistoricalClock driven by their exchange_time
+    let market_events = load_json_market_event_candles();
+    let clock = HistoricalClock::new(
+        market_events
+            .first()
+            .map(|market| market.exchange_time)
+            .expect("historical candles are empty"),
+    );
+    let start_time = clock.time();
+
     // Build global shared-state MetaPortfolio (1-to-1 relationship with an Engine)
     let portfolio = Arc::new(Mutex::new(
         MetaPortfolio::builder()
             .engine_id(engine_id)
             .markets(vec![market.clone()])
-            .starting_cash(10_000.0)
+            .starting_cash(amount!(10_000.0))
             .repository(InMemoryRepository::new())
             .allocation_manager(DefaultAllocator {
                 default_order_value: 100.0,
+                ..Default::default()
             })
             .risk_manager(DefaultRisk {})
             .statistic_config(StatisticConfig {
@@ -63,6 +80,7 @@ async fn main() {
                 trading_days_per_year: 365,
                 risk_free_return: 0.0,
             })
+            .clock(clock.clone())
             .build_and_init()
             .expect("failed to build & initialise MetaPortfolio"),
     ));
@@ -80,17 +98,19 @@ async fn main() {
             .command_rx(trader_command_rx)
             .event_tx(event_tx.clone())
             .portfolio(Arc::clone(&portfolio))
-            .data(historical::MarketFeed::new(
-                load_json_market_event_candles().into_iter(),
-            ))
+            .data(historical::MarketFeed::new(market_events.into_iter()))
             .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
-            .execution(SimulatedExecution::new(ExecutionConfig {
-                simulated_fees_pct: Fees {
-                    exchange: 0.1,
-                    slippage: 0.05,
-                    network: 0.0,
+            .execution(SimulatedExecution::new(
+                ExecutionConfig {
+                    simulated_fees_pct: Fees {
+                        exchange: amount!(0.1),
+                        slippage: amount!(0.05),
+                        network: amount!(0.0),
+                    },
                 },
-            }))
+                clock.clone(),
+            ))
+            .clock(clock)
             .build()
             .expect("failed to build trader"),
     );
@@ -105,11 +125,14 @@ async fn main() {
         .portfolio(portfolio)
         .traders(traders)
         .trader_command_txs(trader_command_txs)
-        .statistics_summary(TradingSummary::init(StatisticConfig {
-            starting_equity: 1000.0,
-            trading_days_per_year: 365,
-            risk_free_return: 0.0,
-        }))
+        .statistics_summary(TradingSummary::init(
+            StatisticConfig {
+                starting_equity: 1000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            },
+            start_time,
+        ))
         .build()
         .expect("failed to build engine");
 
@@ -128,7 +151,7 @@ fn load_json_market_event_candles() -> Vec<MarketEvent<Instrument, DataKind>> {
         .into_iter()
         .map(|candle| MarketEvent {
             exchange_time: candle.close_time,
-            received_time: Utc::now(),
+            received_time: candle.close_time,
             exchange: Exchange::from("binance"),
             instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
             kind: DataKind::Candle(candle),
@@ -155,7 +178,7 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // OrderNew Event occurred in Engine
                 println!("{new_order:?}");
             }
-            Event::OrderUpdate => {
+            Event::OrderUpdate(_) => {
                 // OrderUpdate Event occurred in Engine
             }
             Event::Fill(fill_event) => {
@@ -178,6 +201,10 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // Balance update Event occurred in Engine
                 println!("{balance_update:?}");
             }
+            Event::Error(trader_error) => {
+                // Trader failed to recover from an error
+                println!("{trader_error:?}");
+            }
         }
     }
 }
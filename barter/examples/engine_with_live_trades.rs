@@ -1,6 +1,10 @@
 use barter::{
     data::live,
-    engine::{trader::Trader, Engine},
+    engine::{
+        clock::{EngineClock, LiveClock},
+        trader::Trader,
+        Engine,
+    },
     event::{Event, EventTx},
     execution::{
         simulated::{Config as ExecutionConfig, SimulatedExecution},
@@ -22,9 +26,12 @@ use barter_data::{
     streams::Streams,
     subscription::trade::PublicTrades,
 };
-use barter_integration::model::{
-    instrument::{kind::InstrumentKind, Instrument},
-    Market,
+use barter_integration::{
+    amount,
+    model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Market,
+    },
 };
 use parking_lot::Mutex;
 use std::{collections::HashMap, sync::Arc, time::Duration};
@@ -53,10 +60,11 @@ async fn main() {
         MetaPortfolio::builder()
             .engine_id(engine_id)
             .markets(vec![market.clone()])
-            .starting_cash(10_000.0)
+            .starting_cash(amount!(10_000.0))
             .repository(InMemoryRepository::new())
             .allocation_manager(DefaultAllocator {
                 default_order_value: 100.0,
+                ..Default::default()
             })
             .risk_manager(DefaultRisk {})
             .statistic_config(StatisticConfig {
@@ -64,6 +72,7 @@ async fn main() {
                 trading_days_per_year: 365,
                 risk_free_return: 0.0,
             })
+            .clock(LiveClock)
             .build_and_init()
             .expect("failed to build & initialise MetaPortfolio"),
     ));
@@ -83,13 +92,17 @@ async fn main() {
             .portfolio(Arc::clone(&portfolio))
             .data(live::MarketFeed::new(stream_market_event_trades().await))
             .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
-            .execution(SimulatedExecution::new(ExecutionConfig {
-                simulated_fees_pct: Fees {
-                    exchange: 0.1,
-                    slippage: 0.05,
-                    network: 0.0,
+            .execution(SimulatedExecution::new(
+                ExecutionConfig {
+                    simulated_fees_pct: Fees {
+                        exchange: amount!(0.1),
+                        slippage: amount!(0.05),
+                        network: amount!(0.0),
+                    },
                 },
-            }))
+                LiveClock,
+            ))
+            .clock(LiveClock)
             .build()
             .expect("failed to build trader"),
     );
@@ -104,11 +117,14 @@ async fn main() {
         .portfolio(portfolio)
         .traders(traders)
         .trader_command_txs(trader_command_txs)
-        .statistics_summary(TradingSummary::init(StatisticConfig {
-            starting_equity: 1000.0,
-            trading_days_per_year: 365,
-            risk_free_return: 0.0,
-        }))
+        .statistics_summary(TradingSummary::init(
+            StatisticConfig {
+                starting_equity: 1000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            },
+            LiveClock.time(),
+        ))
         .build()
         .expect("failed to build engine");
 
@@ -180,7 +196,7 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // OrderNew Event occurred in Engine
                 println!("{new_order:?}");
             }
-            Event::OrderUpdate => {
+            Event::OrderUpdate(_) => {
                 // OrderUpdate Event occurred in Engine
             }
             Event::Fill(fill_event) => {
@@ -203,6 +219,10 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // Balance update Event occurred in Engine
                 println!("{balance_update:?}");
             }
+            Event::Error(trader_error) => {
+                // Trader failed to recover from an error
+                println!("{trader_error:?}");
+            }
         }
     }
 }
@@ -11,8 +11,26 @@ pub enum DataError {
     BuilderIncomplete(&'static str),
 
     #[error("Socket: {0}")]
-    Socket(#[from] SocketError),
+    Socket(Box<SocketError>),
 
     #[error("Barter-Data: {0}")]
-    Data(#[from] barter_data::error::DataError),
+    Data(Box<barter_data::error::DataError>),
+
+    #[error("Failed to read historical data due to: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to deserialize historical data due to: {0}")]
+    JsonSerDe(#[from] serde_json::Error),
+}
+
+impl From<SocketError> for DataError {
+    fn from(error: SocketError) -> Self {
+        Self::Socket(Box::new(error))
+    }
+}
+
+impl From<barter_data::error::DataError> for DataError {
+    fn from(error: barter_data::error::DataError) -> Self {
+        Self::Data(Box::new(error))
+    }
 }
@@ -1,19 +1,48 @@
 use super::{Feed, MarketGenerator};
+use std::{
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+    time::{Duration, Instant},
+};
 use tokio::sync::mpsc;
 
 /// Live [`Feed`] of market events.
+///
+/// [`MarketGenerator::next`] parks the calling thread until a market event arrives, rather than
+/// spinning on the receiver. If a heartbeat timeout is configured & no market event arrives within
+/// it, [`Feed::Unhealthy`] is yielded so the caller (eg/ a `Trader`) can action remote commands
+/// during quiet periods.
 #[derive(Debug)]
 pub struct MarketFeed<Event> {
     pub market_rx: mpsc::UnboundedReceiver<Event>,
+    heartbeat_timeout: Option<Duration>,
 }
 
 impl<Event> MarketGenerator<Event> for MarketFeed<Event> {
     fn next(&mut self) -> Feed<Event> {
+        // Waker that unparks this thread when the market_rx receives a new event
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut context = Context::from_waker(&waker);
+
+        let deadline = self
+            .heartbeat_timeout
+            .map(|heartbeat_timeout| Instant::now() + heartbeat_timeout);
+
         loop {
-            match self.market_rx.try_recv() {
-                Ok(event) => break Feed::Next(event),
-                Err(mpsc::error::TryRecvError::Empty) => continue,
-                Err(mpsc::error::TryRecvError::Disconnected) => break Feed::Finished,
+            match self.market_rx.poll_recv(&mut context) {
+                Poll::Ready(Some(event)) => break Feed::Next(event),
+                Poll::Ready(None) => break Feed::Finished,
+                Poll::Pending => match deadline {
+                    None => thread::park(),
+                    Some(deadline) => {
+                        let now = Instant::now();
+                        if now >= deadline {
+                            break Feed::Unhealthy;
+                        }
+                        thread::park_timeout(deadline - now);
+                    }
+                },
             }
         }
     }
@@ -21,7 +50,7 @@ impl<Event> MarketGenerator<Event> for MarketFeed<Event> {
 
 impl<Event> MarketFeed<Event> {
     /// Initialises a live [`MarketFeed`] that yields market `Event`s from the provided
-    /// [`mpsc::UnboundedReceiver`].
+    /// [`mpsc::UnboundedReceiver`], waiting indefinitely for the next market `Event`.
     ///
     /// Recommended use with the `Barter-Data` [`Streams`](barter_data::streams::Streams):
     ///  1. Initialise a [`Streams`](barter_data::streams::Streams) using the
@@ -31,6 +60,67 @@ impl<Event> MarketFeed<Event> {
     ///     [`mpsc::UnboundedReceiver`] streams into a unified [`mpsc::UnboundedReceiver`].
     ///  3. Construct [`Self`] with the unified [`mpsc::UnboundedReceiver`].
     pub fn new(market_rx: mpsc::UnboundedReceiver<Event>) -> Self {
-        Self { market_rx }
+        Self {
+            market_rx,
+            heartbeat_timeout: None,
+        }
+    }
+
+    /// Initialises a live [`MarketFeed`] that yields [`Feed::Unhealthy`] if no market `Event` is
+    /// received within the provided heartbeat timeout.
+    pub fn new_with_heartbeat(
+        market_rx: mpsc::UnboundedReceiver<Event>,
+        heartbeat_timeout: Duration,
+    ) -> Self {
+        Self {
+            market_rx,
+            heartbeat_timeout: Some(heartbeat_timeout),
+        }
+    }
+}
+
+/// [`Wake`] implementation that unparks the [`Thread`] waiting on a live [`MarketFeed`].
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn live_market_feed_wakes_when_event_sent_from_another_thread() {
+        let (market_tx, market_rx) = mpsc::unbounded_channel();
+        let mut feed = MarketFeed::new(market_rx);
+
+        let sender = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            market_tx.send(1).unwrap();
+        });
+
+        assert_eq!(feed.next(), Feed::Next(1));
+        sender.join().unwrap();
+        assert_eq!(feed.next(), Feed::Finished);
+    }
+
+    #[test]
+    fn live_market_feed_yields_unhealthy_after_heartbeat_timeout() {
+        let (market_tx, market_rx) = mpsc::unbounded_channel::<u32>();
+        let mut feed = MarketFeed::new_with_heartbeat(market_rx, Duration::from_millis(10));
+
+        let start = Instant::now();
+        assert_eq!(feed.next(), Feed::Unhealthy);
+        assert!(start.elapsed() >= Duration::from_millis(10));
+
+        market_tx.send(1).unwrap();
+        assert_eq!(feed.next(), Feed::Next(1));
     }
 }
@@ -10,6 +10,9 @@ pub mod live;
 /// Historical market event feed for backtesting.
 pub mod historical;
 
+/// Historical market event windows used to warm up a strategy before it begins trading.
+pub mod warm_up;
+
 /// Generates the next `Event`. Acts as the system heartbeat.
 pub trait MarketGenerator<Event> {
     /// Return the next market `Event`.
@@ -0,0 +1,152 @@
+use super::error::DataError;
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::{instrument::Instrument, Market};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Window of historical [`MarketEvent`]s used to warm up a strategy.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum WarmUpWindow {
+    /// The most recent number of [`MarketEvent`]s.
+    Events(usize),
+    /// The [`MarketEvent`]s with an exchange time within the lookback duration.
+    Lookback(Duration),
+}
+
+impl WarmUpWindow {
+    /// Selects the [`MarketEvent`]s of the window ending at the provided time, sorted by
+    /// exchange time.
+    pub fn select(
+        &self,
+        mut markets: Vec<MarketEvent<Instrument, DataKind>>,
+        end: DateTime<Utc>,
+    ) -> Vec<MarketEvent<Instrument, DataKind>> {
+        markets.retain(|market| market.exchange_time <= end);
+        markets.sort_by_key(|market| market.exchange_time);
+
+        match self {
+            WarmUpWindow::Events(events) => {
+                markets.split_off(markets.len().saturating_sub(*events))
+            }
+            WarmUpWindow::Lookback(lookback) => {
+                let start = chrono::Duration::from_std(*lookback)
+                    .ok()
+                    .and_then(|lookback| end.checked_sub_signed(lookback))
+                    .unwrap_or(DateTime::<Utc>::MIN_UTC);
+                markets.retain(|market| market.exchange_time >= start);
+                markets
+            }
+        }
+    }
+}
+
+/// Source of the historical [`MarketEvent`]s used to warm up a strategy before it begins
+/// trading, provided to a [`Trader`](crate::engine::trader::Trader) via it's builder.
+///
+/// Implemented for any closure with a matching signature, so any historical data provider can
+/// be used as a [`WarmUpSource`]. Only the [`JsonFileWarmUp`] source is provided, since
+/// `barter-data` has no exchange REST history integrations to build one on (eg/ a REST candle
+/// endpoint must be wrapped in a closure by the user).
+pub trait WarmUpSource {
+    /// Return the historical [`MarketEvent`]s of the provided [`Market`] within the
+    /// [`WarmUpWindow`] ending at the provided time, sorted by exchange time. The end time
+    /// should be the [`EngineClock`](crate::engine::clock::EngineClock) time trading begins at
+    /// (eg/ the start of a backtest, or now when trading live).
+    fn fetch(
+        &mut self,
+        market: &Market,
+        window: WarmUpWindow,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<MarketEvent<Instrument, DataKind>>, DataError>;
+}
+
+impl<F> WarmUpSource for F
+where
+    F: FnMut(
+        &Market,
+        WarmUpWindow,
+        DateTime<Utc>,
+    ) -> Result<Vec<MarketEvent<Instrument, DataKind>>, DataError>,
+{
+    fn fetch(
+        &mut self,
+        market: &Market,
+        window: WarmUpWindow,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<MarketEvent<Instrument, DataKind>>, DataError> {
+        self(market, window, end)
+    }
+}
+
+/// [`WarmUpSource`] that reads the [`MarketEvent`]s of a JSON file containing an array of
+/// [`MarketEvent`]s (eg/ recorded during a previous trading session).
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct JsonFileWarmUp {
+    path: PathBuf,
+}
+
+impl WarmUpSource for JsonFileWarmUp {
+    fn fetch(
+        &mut self,
+        market: &Market,
+        window: WarmUpWindow,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<MarketEvent<Instrument, DataKind>>, DataError> {
+        let mut markets = serde_json::from_str::<Vec<MarketEvent<Instrument, DataKind>>>(
+            &fs::read_to_string(&self.path)?,
+        )?;
+
+        markets.retain(|event| {
+            event.exchange == market.exchange && event.instrument == market.instrument
+        });
+
+        Ok(window.select(markets, end))
+    }
+}
+
+impl JsonFileWarmUp {
+    /// Constructs a new [`JsonFileWarmUp`] reading the JSON file at the provided path.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_candle;
+
+    #[test]
+    fn warm_up_window_selects_most_recent_market_events() {
+        let end = market_event_candle().exchange_time;
+        let markets = [-3, 0, -1, -2, 1]
+            .into_iter()
+            .map(|minutes| MarketEvent {
+                exchange_time: end + chrono::Duration::minutes(minutes),
+                ..market_event_candle()
+            })
+            .collect::<Vec<_>>();
+
+        let minutes = |window: WarmUpWindow| {
+            window
+                .select(markets.clone(), end)
+                .into_iter()
+                .map(|market| (market.exchange_time - end).num_minutes())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(minutes(WarmUpWindow::Events(2)), vec![-1, 0]);
+        assert_eq!(minutes(WarmUpWindow::Events(10)), vec![-3, -2, -1, 0]);
+        assert_eq!(
+            minutes(WarmUpWindow::Lookback(Duration::from_secs(150))),
+            vec![-2, -1, 0]
+        );
+    }
+}
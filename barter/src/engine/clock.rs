@@ -0,0 +1,101 @@
+use barter_data::event::MarketEvent;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Defines how the components of an [`Engine`](super::Engine) determine the current time.
+///
+/// Every timestamp the [`Engine`](super::Engine) generates (eg/ `OrderEvent::time`,
+/// `FillEvent::time`) is sourced from an [`EngineClock`], enabling deterministic backtests that
+/// are driven by [`MarketEvent`] time rather than the system time.
+pub trait EngineClock {
+    /// Return the current time according to this [`EngineClock`].
+    fn time(&self) -> DateTime<Utc>;
+
+    /// Process the latest [`MarketEvent`] observed by the [`Engine`](super::Engine). Event-time
+    /// clocks advance to the [`MarketEvent::exchange_time`], whereas system clocks ignore it.
+    fn process_market<InstrumentId, Kind>(&self, market: &MarketEvent<InstrumentId, Kind>);
+}
+
+/// Live [`EngineClock`] that uses the system time. Used for dry-trading & live-trading.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct LiveClock;
+
+impl EngineClock for LiveClock {
+    fn time(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn process_market<InstrumentId, Kind>(&self, _: &MarketEvent<InstrumentId, Kind>) {}
+}
+
+/// Historical [`EngineClock`] driven by the [`MarketEvent::exchange_time`] of the market data
+/// being processed. Used for backtesting.
+///
+/// Cloned [`HistoricalClock`]s share the same underlying time, so a single instance can be
+/// distributed to every component of an [`Engine`](super::Engine). Time only ever moves forward,
+/// so an out-of-order [`MarketEvent`] does not rewind the clock.
+#[derive(Clone, Debug)]
+pub struct HistoricalClock {
+    time: Arc<RwLock<DateTime<Utc>>>,
+}
+
+impl EngineClock for HistoricalClock {
+    fn time(&self) -> DateTime<Utc> {
+        *self.time.read()
+    }
+
+    fn process_market<InstrumentId, Kind>(&self, market: &MarketEvent<InstrumentId, Kind>) {
+        let mut time = self.time.write();
+        if market.exchange_time > *time {
+            *time = market.exchange_time;
+        }
+    }
+}
+
+impl HistoricalClock {
+    /// Constructs a new [`HistoricalClock`] starting at the provided time. This is usually the
+    /// time of the first [`MarketEvent`] in the backtest.
+    pub fn new(start_time: DateTime<Utc>) -> Self {
+        Self {
+            time: Arc::new(RwLock::new(start_time)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::model::Side;
+    use chrono::Duration;
+
+    #[test]
+    fn historical_clock_advances_with_market_exchange_time() {
+        let start_time = Utc::now();
+        let clock = HistoricalClock::new(start_time);
+        let shared = clock.clone();
+
+        let mut market = market_event_trade(Side::Buy);
+        market.exchange_time = start_time + Duration::minutes(1);
+        clock.process_market(&market);
+
+        assert_eq!(clock.time(), start_time + Duration::minutes(1));
+        assert_eq!(shared.time(), start_time + Duration::minutes(1));
+    }
+
+    #[test]
+    fn historical_clock_does_not_rewind_with_out_of_order_market() {
+        let start_time = Utc::now();
+        let clock = HistoricalClock::new(start_time);
+
+        let mut market = market_event_trade(Side::Buy);
+        market.exchange_time = start_time - Duration::minutes(1);
+        clock.process_market(&market);
+
+        assert_eq!(clock.time(), start_time);
+    }
+}
@@ -9,4 +9,7 @@ pub enum EngineError {
 
     #[error("Failed to interact with repository")]
     RepositoryInteractionError(#[from] RepositoryError),
+
+    #[error("Failed to serialize to JSON due to: {0}")]
+    JsonSerDeError(#[from] serde_json::Error),
 }
@@ -1,32 +1,61 @@
 use crate::{
     data::MarketGenerator,
-    engine::{error::EngineError, trader::Trader},
+    engine::{
+        clock::EngineClock,
+        error::EngineError,
+        policy::{ErrorAction, TraderError},
+        trader::Trader,
+    },
     event::{Event, MessageTransmitter},
     execution::ExecutionClient,
     portfolio::{
         position::Position,
-        repository::{PositionHandler, StatisticHandler},
-        FillUpdater, MarketUpdater, OrderGenerator,
+        repository::{
+            BalanceHandler, EquityHandler, PositionHandler, StatisticHandler,
+            StrategySnapshotHandler,
+        },
+        Balance, FillUpdater, MarketUpdater, OrderGenerator,
+    },
+    statistic::{
+        metric::EquityPoint,
+        summary::{PositionSummariser, TableBuilder},
     },
-    statistic::summary::{PositionSummariser, TableBuilder},
-    strategy::SignalGenerator,
+    strategy::{Signal, SignalGenerator},
 };
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Market, MarketId};
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 use prettytable::Table;
 use serde::Serialize;
-use std::{collections::HashMap, fmt::Debug, sync::Arc, thread};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    fmt::Debug,
+    sync::Arc,
+    thread,
+    time::Duration,
+};
 use tokio::sync::{mpsc, oneshot};
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
+/// Clocks that determine the current time for an [`Engine`] and it's components. Enables
+/// deterministic backtests driven by [`MarketEvent`] time.
+pub mod clock;
+
 /// Barter Engine module specific errors.
 pub mod error;
 
+/// Configurable policy determining how a Trader responds to the Portfolio & Execution errors it
+/// encounters.
+pub mod policy;
+
 /// Contains the trading event loop for a Trader capable of trading a single market pair. A Trader
 /// has it's own Data handler, Strategy & Execution handler, as well as shared access to a global
 /// Portfolio instance.
+// The Trader propagates PortfolioErrors, which are large with the `decimal` feature.
+#[cfg_attr(feature = "decimal", allow(clippy::result_large_err))]
 pub mod trader;
 
 /// Commands that can be actioned by an [`Engine`] and it's associated [`Trader`]s.
@@ -36,6 +65,27 @@ pub enum Command {
     /// `oneshot::Sender`. Involves the [`Engine`] only.
     FetchOpenPositions(oneshot::Sender<Result<Vec<Position>, EngineError>>),
 
+    /// Fetches the Portfolio's current [`Balance`] and sends it on the provided
+    /// `oneshot::Sender`. Involves the [`Engine`] only.
+    FetchBalance(oneshot::Sender<Result<Balance, EngineError>>),
+
+    /// Fetches the Portfolio's statistics for every [`Market`] traded, serialised as JSON, and
+    /// sends them on the provided `oneshot::Sender`. Involves the [`Engine`] only.
+    FetchStatistics(oneshot::Sender<Result<HashMap<Market, serde_json::Value>, EngineError>>),
+
+    /// Fetches all the [`Engine`]'s exited [`Position`]s and sends them on the provided
+    /// `oneshot::Sender`. Involves the [`Engine`] only.
+    FetchExitedPositions(oneshot::Sender<Result<Vec<Position>, EngineError>>),
+
+    /// Fetches the Portfolio's mark-to-market equity curve and sends it on the provided
+    /// `oneshot::Sender`. Involves the [`Engine`] only.
+    FetchEquityCurve(oneshot::Sender<Result<Vec<EquityPoint>, EngineError>>),
+
+    /// Fetches the exchange time of the last [`MarketEvent`] processed by every [`Trader`]
+    /// (`None` if it is yet to process one), and sends them on the provided `oneshot::Sender`.
+    /// Involves the [`Engine`] only.
+    FetchMarketTimes(oneshot::Sender<HashMap<Market, Option<DateTime<Utc>>>>),
+
     /// Terminate every running [`Trader`] associated with this [`Engine`]. Involves all [`Trader`]s.
     Terminate(String),
 
@@ -45,11 +95,58 @@ pub enum Command {
     /// Exit a [`Position`]. Uses the [`Market`] provided to route this [`Command`] to the relevant
     /// [`Trader`] instance. Involves one [`Trader`].
     ExitPosition(Market),
+
+    /// Pause a [`Trader`] so it stops entering new [`Position`]s, while continuing to manage the
+    /// exit of any open [`Position`]. Uses the [`Market`] provided to route this [`Command`] to
+    /// the relevant [`Trader`] instance. Involves one [`Trader`].
+    PauseTrader(Market),
+
+    /// Resume a paused [`Trader`] so it can enter new [`Position`]s again. Uses the [`Market`]
+    /// provided to route this [`Command`] to the relevant [`Trader`] instance. Involves one
+    /// [`Trader`].
+    ResumeTrader(Market),
+
+    /// Exit the open [`Position`] of a [`Trader`] and terminate it, removing it from the
+    /// [`Engine`]. Uses the [`Market`] provided to route this [`Command`] to the relevant
+    /// [`Trader`] instance. Involves one [`Trader`].
+    RemoveTrader(Market),
+
+    /// Route a [`Signal`] generated for a [`Market`] by another [`Trader`] (eg/ via a
+    /// [`CrossMarketStrategy`](crate::strategy::cross_market::CrossMarketStrategy)) to the
+    /// [`Trader`] of the [`Signal`]'s [`Market`], which actions it before it's next
+    /// [`MarketEvent`]. Involves one [`Trader`].
+    RouteSignal(Signal),
+
+    /// Persist a snapshot of every [`Trader`]'s Strategy state via the Portfolio repository, for
+    /// Strategies that support it. See [`StrategySnapshot`](crate::strategy::StrategySnapshot).
+    /// Involves all [`Trader`]s.
+    SnapshotStrategies,
+}
+
+/// [`Trader`] to hot-add to a running [`Engine`], along with the transmitter the [`Engine`] uses
+/// to route [`Command`]s to it. Sent to the [`Engine`] via the `trader_rx` provided to the
+/// [`EngineBuilder`].
+///
+/// The Portfolio must be tracking the [`Trader`]'s [`Market`] before it is added (eg/ via
+/// [`MetaPortfolio::add_market`](crate::portfolio::portfolio::MetaPortfolio::add_market)).
+#[derive(Debug)]
+pub struct AddTrader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
+    Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
+    Strategy: SignalGenerator + Send,
+    Execution: ExecutionClient + Send,
+    Clock: EngineClock + Send,
+{
+    pub trader: Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>,
+    pub command_tx: mpsc::Sender<Command>,
 }
 
 /// Lego components for constructing an [`Engine`] via the new() constructor method.
 #[derive(Debug)]
-pub struct EngineLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+pub struct EngineLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
 where
     EventTx: MessageTransmitter<Event> + Send,
     Statistic: Serialize + Send,
@@ -57,6 +154,7 @@ where
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
+    Clock: EngineClock + Send,
 {
     /// Unique identifier for an [`Engine`] in Uuid v4 format. Used as a unique identifier seed for
     /// the Portfolio, Trader & Positions associated with this [`Engine`].
@@ -66,13 +164,20 @@ where
     /// Shared-access to a global Portfolio instance.
     pub portfolio: Arc<Mutex<Portfolio>>,
     /// Collection of [`Trader`] instances that can concurrently trade a market pair on it's own thread.
-    pub traders: Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>,
+    pub traders: Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>>,
     /// `HashMap` containing a [`Command`] transmitter for every [`Trader`] associated with this
     /// [`Engine`].
     pub trader_command_txs: HashMap<Market, mpsc::Sender<Command>>,
+    /// Optional mpsc::Receiver for receiving [`AddTrader`]s to hot-add to the running [`Engine`].
+    pub trader_rx: Option<
+        mpsc::Receiver<AddTrader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>>,
+    >,
     /// Uses trading session's exited [`Position`]s to calculate an average statistical summary
     /// across all [`Market`]s traded.
     pub statistics_summary: Statistic,
+    /// Optional interval at which the running [`Engine`] persists a snapshot of every
+    /// [`Trader`]'s Strategy state (see [`Command::SnapshotStrategies`]).
+    pub snapshot_interval: Option<Duration>,
 }
 
 /// Multi-threaded Trading Engine capable of trading with an arbitrary number of [`Trader`]s, one
@@ -82,13 +187,19 @@ where
 /// Execution Handler, as well as shared access to a global Portfolio instance. A graceful remote
 /// shutdown is made possible by sending a [`Command::Terminate`] to the Engine's broadcast::Receiver
 /// termination_rx.
+///
+/// For reproducible multi-market backtests, [`Engine::run_backtest`] instead drives every
+/// [`Trader`] from a single thread using a time-ordered merge of their [`MarketEvent`] feeds.
 #[derive(Debug)]
-pub struct Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+pub struct Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: PositionSummariser + Serialize + Send,
     Portfolio: PositionHandler
+        + BalanceHandler
         + StatisticHandler<Statistic>
+        + StrategySnapshotHandler
+        + EquityHandler
         + MarketUpdater
         + OrderGenerator
         + FillUpdater
@@ -97,6 +208,7 @@ where
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send + 'static,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
+    Clock: EngineClock + Send,
 {
     /// Unique identifier for an [`Engine`] in Uuid v4 format. Used as a unique identifier seed for
     /// the Portfolio, Trader & Positions associated with this [`Engine`].
@@ -107,22 +219,38 @@ where
     /// [`OrderGenerator`] & [`FillUpdater`].
     portfolio: Arc<Mutex<Portfolio>>,
     /// Collection of [`Trader`] instances that can concurrently trade a market pair on it's own thread.
-    traders: Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>,
+    traders: Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>>,
     /// `HashMap` containing a [`Command`] transmitter for every [`Trader`] associated with this
     /// [`Engine`].
     trader_command_txs: HashMap<Market, mpsc::Sender<Command>>,
+    /// Optional mpsc::Receiver for receiving [`AddTrader`]s to hot-add to the running [`Engine`].
+    trader_rx: Option<
+        mpsc::Receiver<AddTrader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>>,
+    >,
+    /// [`Market`]s of the [`Trader`]s removed via [`Command::RemoveTrader`], which are still
+    /// included in the trading session summary.
+    removed_markets: Vec<Market>,
+    /// Shared-access to the exchange time of the last [`MarketEvent`] processed by every
+    /// [`Trader`].
+    market_times: HashMap<Market, Arc<Mutex<Option<DateTime<Utc>>>>>,
     /// Uses trading session's exited [`Position`]s to calculate an average statistical summary
     /// across all [`Market`]s traded.
     statistics_summary: Statistic,
+    /// Optional interval at which the running [`Engine`] persists a snapshot of every
+    /// [`Trader`]'s Strategy state.
+    snapshot_interval: Option<Duration>,
 }
 
-impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
-    Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
+    Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
 where
     EventTx: MessageTransmitter<Event> + Send + 'static,
     Statistic: PositionSummariser + TableBuilder + Serialize + Send + 'static,
     Portfolio: PositionHandler
+        + BalanceHandler
         + StatisticHandler<Statistic>
+        + StrategySnapshotHandler
+        + EquityHandler
         + MarketUpdater
         + OrderGenerator
         + FillUpdater
@@ -131,9 +259,12 @@ where
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
     Strategy: SignalGenerator + Send + 'static,
     Execution: ExecutionClient + Send + 'static,
+    Clock: EngineClock + Send + 'static,
 {
     /// Constructs a new trading [`Engine`] instance using the provided [`EngineLego`].
-    pub fn new(lego: EngineLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution>) -> Self {
+    pub fn new(
+        lego: EngineLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>,
+    ) -> Self {
         info!(
             engine_id = &*format!("{}", lego.engine_id),
             "constructed new Engine instance"
@@ -142,38 +273,100 @@ where
             engine_id: lego.engine_id,
             command_rx: lego.command_rx,
             portfolio: lego.portfolio,
+            market_times: lego
+                .traders
+                .iter()
+                .map(|trader| (trader.market().clone(), trader.last_market_time()))
+                .collect(),
             traders: lego.traders,
             trader_command_txs: lego.trader_command_txs,
+            trader_rx: lego.trader_rx,
+            removed_markets: Vec::new(),
             statistics_summary: lego.statistics_summary,
+            snapshot_interval: lego.snapshot_interval,
         }
     }
 
     /// Builder to construct [`Engine`] instances.
-    pub fn builder() -> EngineBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution> {
+    pub fn builder(
+    ) -> EngineBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock> {
         EngineBuilder::new()
     }
 
     /// Run the trading [`Engine`]. Spawns a thread for each [`Trader`] to run on. Asynchronously
     /// receives [`Command`]s via the `command_rx` and actions them
-    /// (eg/ terminate_traders, fetch_open_positions). If all of the [`Trader`]s stop organically
-    /// (eg/ due to a finished [`MarketGenerator`]), the [`Engine`] terminates & prints a summary
-    /// for the trading session.
+    /// (eg/ terminate_traders, fetch_open_positions), and spawns a thread for any [`AddTrader`]
+    /// received via the `trader_rx`. If all of the [`Trader`]s stop organically (eg/ due to a
+    /// finished [`MarketGenerator`]), or a [`Trader`] is halted by an error configured to
+    /// [`ErrorAction::HaltEngine`], the [`Engine`] terminates & prints a summary for the trading
+    /// session.
     pub async fn run(mut self) {
-        // Run Traders on threads & send notification when they have stopped organically
-        let mut notify_traders_stopped = self.run_traders().await;
+        // Run Traders on threads & send notification on trader_stopped_tx when each one stops
+        let (trader_stopped_tx, mut trader_stopped_rx) = mpsc::unbounded_channel();
+        let traders = std::mem::take(&mut self.traders);
+        let mut running_traders = traders.len();
+        for trader in traders {
+            Self::spawn_trader(trader, trader_stopped_tx.clone());
+        }
+
+        // Periodically snapshot the Strategy state of every Trader, if configured
+        let mut snapshot_interval = self.snapshot_interval.map(|period| {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            interval
+        });
 
-        loop {
-            // Action received commands from remote, or wait for all Traders to stop organically
+        while running_traders > 0 {
+            // Action received commands from remote, add Traders, or wait for all Traders to stop
             tokio::select! {
-                _ = notify_traders_stopped.recv() => {
-                    break;
+                stopped = trader_stopped_rx.recv() => {
+                    running_traders -= 1;
+
+                    if let Some(Some(error)) = stopped {
+                        if error.action == ErrorAction::HaltEngine {
+                            let message = format!("Trader halted the Engine due to: {}", error.error);
+                            self.terminate_traders(message).await;
+                            break;
+                        }
+                    }
+                },
+
+                _ = tick_snapshot_interval(&mut snapshot_interval) => {
+                    self.snapshot_strategies().await;
+                },
+
+                add_trader = recv_add_trader(&mut self.trader_rx) => {
+                    match add_trader {
+                        Some(AddTrader { trader, command_tx }) => {
+                            if self.register_trader(&trader, command_tx) {
+                                Self::spawn_trader(trader, trader_stopped_tx.clone());
+                                running_traders += 1;
+                            }
+                        }
+                        None => self.trader_rx = None,
+                    }
                 },
 
                 command = self.command_rx.recv() => {
                     if let Some(command) = command {
                         match command {
                             Command::FetchOpenPositions(positions_tx) => {
-                                self.fetch_open_positions(positions_tx).await;
+                                self.fetch_open_positions(positions_tx);
+                            },
+                            Command::FetchBalance(balance_tx) => {
+                                self.fetch_balance(balance_tx);
+                            },
+                            Command::FetchStatistics(statistics_tx) => {
+                                self.fetch_statistics(statistics_tx);
+                            },
+                            Command::FetchExitedPositions(positions_tx) => {
+                                self.fetch_exited_positions(positions_tx);
+                            },
+                            Command::FetchEquityCurve(equity_curve_tx) => {
+                                self.fetch_equity_curve(equity_curve_tx);
+                            },
+                            Command::FetchMarketTimes(market_times_tx) => {
+                                self.fetch_market_times(market_times_tx);
                             },
                             Command::Terminate(message) => {
                                 self.terminate_traders(message).await;
@@ -185,6 +378,24 @@ where
                             Command::ExitAllPositions => {
                                 self.exit_all_positions().await;
                             },
+                            Command::PauseTrader(market) => {
+                                let command = Command::PauseTrader(market.clone());
+                                self.send_trader_command(&market, command).await;
+                            },
+                            Command::ResumeTrader(market) => {
+                                let command = Command::ResumeTrader(market.clone());
+                                self.send_trader_command(&market, command).await;
+                            },
+                            Command::RemoveTrader(market) => {
+                                self.remove_trader(market).await;
+                            },
+                            Command::RouteSignal(signal) => {
+                                let market = Market::new(signal.exchange.clone(), signal.instrument.clone());
+                                self.send_trader_command(&market, Command::RouteSignal(signal)).await;
+                            },
+                            Command::SnapshotStrategies => {
+                                self.snapshot_strategies().await;
+                            },
                         }
                     } else {
                         // Terminate traders due to dropped receiver
@@ -198,49 +409,245 @@ where
         self.generate_session_summary().printstd();
     }
 
-    /// Runs each [`Trader`] it's own thread. Sends a message on the returned `mpsc::Receiver<bool>`
-    /// if all the [`Trader`]s have stopped organically (eg/ due to a finished [`MarketEvent`] feed).
-    async fn run_traders(&mut self) -> mpsc::Receiver<bool> {
-        // Extract Traders out of the Engine so we can move them into threads
-        let traders = std::mem::take(&mut self.traders);
+    /// Run the [`Engine`] in single-threaded backtest mode. Rather than spawning a thread for each
+    /// [`Trader`], the historical [`MarketEvent`] feeds of every [`Trader`] are merged into a
+    /// single stream ordered by [`MarketEvent::exchange_time`] (ties are broken by [`Trader`]
+    /// index), and each [`Trader`]'s trading sequence is driven from this one loop. This makes the
+    /// order of cross-market Portfolio updates, and therefore the trading session statistics,
+    /// reproducible.
+    ///
+    /// [`Command`]s received via the `command_rx` are actioned between [`MarketEvent`]s. Once
+    /// every [`MarketGenerator`] has finished, the [`Engine`] terminates & prints a summary for
+    /// the trading session.
+    pub fn run_backtest(mut self) {
+        // Extract Traders out of the Engine so they can be driven in lock-step
+        let mut traders = std::mem::take(&mut self.traders);
 
-        // Run each Trader instance on it's own thread
-        let mut thread_handles = Vec::with_capacity(traders.len());
-        for trader in traders.into_iter() {
-            let handle = thread::spawn(move || trader.run());
-            thread_handles.push(handle);
+        // Seed the k-way merge with the first MarketEvent yielded by each Trader
+        let mut next_markets = Vec::with_capacity(traders.len());
+        let mut merge_heap = BinaryHeap::with_capacity(traders.len());
+        for (index, trader) in traders.iter_mut().enumerate() {
+            trader.restore_strategy();
+            let market = trader.next_market();
+            if let Some(market) = &market {
+                merge_heap.push(Reverse((market.exchange_time, index)));
+            }
+            next_markets.push(market);
         }
 
-        // Create channel to notify the Engine when the Traders have stopped organically
-        let (notify_tx, notify_rx) = mpsc::channel(1);
+        'backtest: loop {
+            // Action received commands from remote before processing the next MarketEvent
+            loop {
+                match self.command_rx.try_recv() {
+                    Ok(Command::FetchOpenPositions(positions_tx)) => {
+                        self.fetch_open_positions(positions_tx);
+                    }
+                    Ok(Command::FetchBalance(balance_tx)) => {
+                        self.fetch_balance(balance_tx);
+                    }
+                    Ok(Command::FetchStatistics(statistics_tx)) => {
+                        self.fetch_statistics(statistics_tx);
+                    }
+                    Ok(Command::FetchExitedPositions(positions_tx)) => {
+                        self.fetch_exited_positions(positions_tx);
+                    }
+                    Ok(Command::FetchEquityCurve(equity_curve_tx)) => {
+                        self.fetch_equity_curve(equity_curve_tx);
+                    }
+                    Ok(Command::FetchMarketTimes(market_times_tx)) => {
+                        self.fetch_market_times(market_times_tx);
+                    }
+                    Ok(Command::Terminate(message)) => {
+                        self.stop_backtest(&mut traders);
+                        info!(
+                            engine_id = %self.engine_id,
+                            %message,
+                            "backtest terminated via remote Command::Terminate"
+                        );
+                        break 'backtest;
+                    }
+                    Ok(Command::ExitPosition(market)) => {
+                        self.exit_position_backtest(market);
+                    }
+                    Ok(Command::ExitAllPositions) => {
+                        self.exit_all_positions_backtest();
+                    }
+                    Ok(Command::PauseTrader(market)) => {
+                        let command = Command::PauseTrader(market.clone());
+                        self.send_trader_command_backtest(&market, command);
+                    }
+                    Ok(Command::ResumeTrader(market)) => {
+                        let command = Command::ResumeTrader(market.clone());
+                        self.send_trader_command_backtest(&market, command);
+                    }
+                    Ok(Command::RemoveTrader(market)) => {
+                        self.remove_trader_backtest(market);
+                    }
+                    Ok(Command::RouteSignal(signal)) => {
+                        let market =
+                            Market::new(signal.exchange.clone(), signal.instrument.clone());
+                        self.send_trader_command_backtest(&market, Command::RouteSignal(signal));
+                    }
+                    Ok(Command::SnapshotStrategies) => {
+                        for market in self.trader_command_txs.keys() {
+                            self.send_trader_command_backtest(market, Command::SnapshotStrategies);
+                        }
+                    }
+                    Err(mpsc::error::TryRecvError::Empty) => break,
+                    Err(mpsc::error::TryRecvError::Disconnected) => {
+                        // Terminate backtest due to dropped receiver
+                        break 'backtest;
+                    }
+                }
+            }
 
-        // Create Task that notifies Engine when the Traders have stopped organically
-        tokio::spawn(async move {
-            for handle in thread_handles {
-                if let Err(err) = handle.join() {
-                    error!(
-                        error = &*format!("{:?}", err),
-                        "Trader thread has panicked during execution",
-                    )
+            // Seed the k-way merge with the first MarketEvent of any hot-added Traders
+            while let Some(AddTrader {
+                mut trader,
+                command_tx,
+            }) = self
+                .trader_rx
+                .as_mut()
+                .and_then(|trader_rx| trader_rx.try_recv().ok())
+            {
+                if self.register_trader(&trader, command_tx) {
+                    trader.restore_strategy();
+                    let market = trader.next_market();
+                    if let Some(market) = &market {
+                        merge_heap.push(Reverse((market.exchange_time, traders.len())));
+                    }
+                    next_markets.push(market);
+                    traders.push(trader);
+                }
+            }
+
+            let Some(Reverse((_, index))) = merge_heap.pop() else {
+                break 'backtest;
+            };
+
+            // Drive the Trader associated with the earliest MarketEvent
+            let trader = &mut traders[index];
+            if trader.action_remote_commands() {
+                // Action any forced exits received alongside the Command::Terminate
+                trader.process_event_q();
+                continue 'backtest;
+            }
+
+            let market = next_markets[index]
+                .take()
+                .expect("merge heap entry always has a pending MarketEvent");
+            trader.process_market(market);
+
+            // Stop driving a Trader halted by an error, or every Trader if it halts the Engine
+            if let Some(error) = trader.halted() {
+                if error.action == ErrorAction::HaltEngine {
+                    let message = error.error.clone();
+                    self.stop_backtest(&mut traders);
+                    warn!(
+                        engine_id = %self.engine_id,
+                        error = %message,
+                        "backtest terminated via Trader ErrorAction::HaltEngine"
+                    );
+                    break 'backtest;
                 }
+                continue 'backtest;
             }
 
-            let _ = notify_tx.send(true).await;
+            // Replenish the merge heap with the Trader's next MarketEvent
+            if let Some(market) = trader.next_market() {
+                merge_heap.push(Reverse((market.exchange_time, index)));
+                next_markets[index] = Some(market);
+            }
+        }
+
+        // Snapshot the Strategy state of every Trader now trading has stopped
+        for trader in traders.iter_mut() {
+            trader.snapshot_strategy();
+        }
+
+        // Print Trading Session Summary
+        self.generate_session_summary().printstd();
+    }
+
+    /// Runs the [`Trader`] on it's own thread. Sends the outcome of [`Trader::run`] on the
+    /// `trader_stopped_tx` once it has stopped (eg/ due to a finished [`MarketEvent`] feed).
+    fn spawn_trader(
+        trader: Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>,
+        trader_stopped_tx: mpsc::UnboundedSender<Option<TraderError>>,
+    ) {
+        let market = trader.market().clone();
+        let handle = thread::spawn(move || trader.run());
+
+        // Create Task that notifies Engine when the Trader has stopped
+        tokio::task::spawn_blocking(move || {
+            let stopped = handle.join().unwrap_or_else(|err| {
+                error!(
+                    error = &*format!("{:?}", err),
+                    market = &*format!("{:?}", market),
+                    "Trader thread has panicked during execution",
+                );
+                None
+            });
+
+            let _ = trader_stopped_tx.send(stopped);
         });
+    }
 
-        notify_rx
+    /// Exit every open [`Position`] in backtest mode, actioning the resulting forced exits of
+    /// every [`Trader`] before the backtest stops.
+    fn stop_backtest(
+        &self,
+        traders: &mut [Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>],
+    ) {
+        self.exit_all_positions_backtest();
+        for trader in traders.iter_mut() {
+            trader.action_remote_commands();
+            trader.process_event_q();
+        }
+    }
+
+    /// Registers the `command_tx` of a hot-added [`Trader`] so [`Command`]s can be routed to it.
+    /// Returns `false` if the [`Engine`] already has a [`Trader`] for the same [`Market`].
+    fn register_trader(
+        &mut self,
+        trader: &Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>,
+        command_tx: mpsc::Sender<Command>,
+    ) -> bool {
+        let market = trader.market().clone();
+        if self.trader_command_txs.contains_key(&market) {
+            warn!(
+                market = &*format!("{:?}", market),
+                why = "Engine already has a Trader associated with provided Market",
+                "failed to add Trader"
+            );
+            return false;
+        }
+
+        info!(
+            engine_id = %self.engine_id,
+            market = &*format!("{:?}", market),
+            "added Trader to Engine"
+        );
+        self.removed_markets.retain(|removed| removed != &market);
+        self.market_times
+            .insert(market.clone(), trader.last_market_time());
+        self.trader_command_txs.insert(market, command_tx);
+        true
     }
 
     /// Fetches all the [`Engine`]'s open [`Position`]s and sends them on the provided
     /// `oneshot::Sender`.
-    async fn fetch_open_positions(
+    fn fetch_open_positions(
         &self,
         positions_tx: oneshot::Sender<Result<Vec<Position>, EngineError>>,
     ) {
         let open_positions = self
             .portfolio
             .lock()
-            .get_open_positions(self.engine_id, self.trader_command_txs.keys())
+            .get_open_positions(
+                self.engine_id,
+                self.trader_command_txs.keys().chain(&self.removed_markets),
+            )
             .map_err(EngineError::RepositoryInteractionError);
 
         if positions_tx.send(open_positions).is_err() {
@@ -251,6 +658,110 @@ where
         }
     }
 
+    /// Fetches the Portfolio's current [`Balance`] and sends it on the provided
+    /// `oneshot::Sender`.
+    fn fetch_balance(&self, balance_tx: oneshot::Sender<Result<Balance, EngineError>>) {
+        let balance = self
+            .portfolio
+            .lock()
+            .get_balance(self.engine_id)
+            .map_err(EngineError::RepositoryInteractionError);
+
+        if balance_tx.send(balance).is_err() {
+            warn!(
+                why = "oneshot receiver dropped",
+                "cannot action Command::FetchBalance"
+            );
+        }
+    }
+
+    /// Fetches the Portfolio's statistics for every [`Market`] traded, serialised as JSON, and
+    /// sends them on the provided `oneshot::Sender`.
+    fn fetch_statistics(
+        &self,
+        statistics_tx: oneshot::Sender<Result<HashMap<Market, serde_json::Value>, EngineError>>,
+    ) {
+        let statistics = self
+            .trader_command_txs
+            .keys()
+            .chain(&self.removed_markets)
+            .map(|market| {
+                let statistics = self
+                    .portfolio
+                    .lock()
+                    .get_statistics(&MarketId::from(market))?;
+                Ok((market.clone(), serde_json::to_value(statistics)?))
+            })
+            .collect();
+
+        if statistics_tx.send(statistics).is_err() {
+            warn!(
+                why = "oneshot receiver dropped",
+                "cannot action Command::FetchStatistics"
+            );
+        }
+    }
+
+    /// Fetches all the [`Engine`]'s exited [`Position`]s and sends them on the provided
+    /// `oneshot::Sender`.
+    fn fetch_exited_positions(
+        &self,
+        positions_tx: oneshot::Sender<Result<Vec<Position>, EngineError>>,
+    ) {
+        let exited_positions = self
+            .portfolio
+            .lock()
+            .get_exited_positions(self.engine_id)
+            .map_err(EngineError::RepositoryInteractionError);
+
+        if positions_tx.send(exited_positions).is_err() {
+            warn!(
+                why = "oneshot receiver dropped",
+                "cannot action Command::FetchExitedPositions"
+            );
+        }
+    }
+
+    /// Fetches the Portfolio's mark-to-market equity curve and sends it on the provided
+    /// `oneshot::Sender`.
+    fn fetch_equity_curve(
+        &self,
+        equity_curve_tx: oneshot::Sender<Result<Vec<EquityPoint>, EngineError>>,
+    ) {
+        let equity_curve = self
+            .portfolio
+            .lock()
+            .get_equity_curve(self.engine_id)
+            .map_err(EngineError::RepositoryInteractionError);
+
+        if equity_curve_tx.send(equity_curve).is_err() {
+            warn!(
+                why = "oneshot receiver dropped",
+                "cannot action Command::FetchEquityCurve"
+            );
+        }
+    }
+
+    /// Fetches the exchange time of the last [`MarketEvent`] processed by every [`Trader`], and
+    /// sends them on the provided `oneshot::Sender`.
+    fn fetch_market_times(
+        &self,
+        market_times_tx: oneshot::Sender<HashMap<Market, Option<DateTime<Utc>>>>,
+    ) {
+        let market_times = self
+            .market_times
+            .iter()
+            .map(|(market, time)| (market.clone(), *time.lock()))
+            .collect();
+
+        if market_times_tx.send(market_times).is_err() {
+            warn!(
+                why = "oneshot receiver dropped",
+                "cannot action Command::FetchMarketTimes"
+            );
+        }
+    }
+
     /// Terminate every running [`Trader`] associated with this [`Engine`].
     async fn terminate_traders(&self, message: String) {
         // Firstly, exit all Positions
@@ -273,6 +784,20 @@ where
         }
     }
 
+    /// Distribute a [`Command::SnapshotStrategies`] to every [`Trader`] associated with this
+    /// [`Engine`].
+    async fn snapshot_strategies(&self) {
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            if command_tx.send(Command::SnapshotStrategies).await.is_err() {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::SnapshotStrategies to Trader command_rx"
+                );
+            }
+        }
+    }
+
     /// Exit every open [`Position`] associated with this [`Engine`].
     async fn exit_all_positions(&self) {
         for (market, command_tx) in self.trader_command_txs.iter() {
@@ -314,11 +839,120 @@ where
         }
     }
 
+    /// Exit every open [`Position`] associated with this [`Engine`] without awaiting [`Trader`]
+    /// command_rx capacity. Used by [`Engine::run_backtest`].
+    fn exit_all_positions_backtest(&self) {
+        for market in self.trader_command_txs.keys() {
+            self.exit_position_backtest(market.clone());
+        }
+    }
+
+    /// Exit a [`Position`] without awaiting [`Trader`] command_rx capacity. Uses the [`Market`]
+    /// provided to route this [`Command`] to the relevant [`Trader`] instance. Used by
+    /// [`Engine::run_backtest`].
+    fn exit_position_backtest(&self, market: Market) {
+        if let Some((market_ref, command_tx)) = self.trader_command_txs.get_key_value(&market) {
+            if let Err(error) = command_tx.try_send(Command::ExitPosition(market)) {
+                error!(
+                    market = &*format!("{:?}", market_ref),
+                    %error,
+                    "failed to send Command::ExitPosition to Trader command_rx"
+                );
+            }
+        } else {
+            warn!(
+                market = &*format!("{:?}", market),
+                why = "Engine has no trader_command_tx associated with provided Market",
+                "failed to exit Position"
+            );
+        }
+    }
+
+    /// Send a [`Command`] to the [`Trader`] associated with the provided [`Market`].
+    async fn send_trader_command(&self, market: &Market, command: Command) {
+        if let Some(command_tx) = self.trader_command_txs.get(market) {
+            if command_tx.send(command).await.is_err() {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command to Trader command_rx"
+                );
+            }
+        } else {
+            warn!(
+                market = &*format!("{:?}", market),
+                why = "Engine has no trader_command_tx associated with provided Market",
+                "failed to send Command to Trader"
+            );
+        }
+    }
+
+    /// Send a [`Command`] to the [`Trader`] associated with the provided [`Market`] without
+    /// awaiting [`Trader`] command_rx capacity. Used by [`Engine::run_backtest`].
+    fn send_trader_command_backtest(&self, market: &Market, command: Command) {
+        if let Some(command_tx) = self.trader_command_txs.get(market) {
+            if let Err(error) = command_tx.try_send(command) {
+                error!(
+                    market = &*format!("{:?}", market),
+                    %error,
+                    "failed to send Command to Trader command_rx"
+                );
+            }
+        } else {
+            warn!(
+                market = &*format!("{:?}", market),
+                why = "Engine has no trader_command_tx associated with provided Market",
+                "failed to send Command to Trader"
+            );
+        }
+    }
+
+    /// Exit the open [`Position`] of the [`Trader`] associated with the provided [`Market`] and
+    /// terminate it, removing it from the [`Engine`].
+    async fn remove_trader(&mut self, market: Market) {
+        let exit_position = Command::ExitPosition(market.clone());
+        self.send_trader_command(&market, exit_position).await;
+
+        let terminate = Command::Terminate("Trader removed from Engine".to_owned());
+        self.send_trader_command(&market, terminate).await;
+
+        self.deregister_trader(market);
+    }
+
+    /// Exit the open [`Position`] of the [`Trader`] associated with the provided [`Market`] and
+    /// terminate it, removing it from the [`Engine`]. Used by [`Engine::run_backtest`].
+    fn remove_trader_backtest(&mut self, market: Market) {
+        let exit_position = Command::ExitPosition(market.clone());
+        self.send_trader_command_backtest(&market, exit_position);
+
+        let terminate = Command::Terminate("Trader removed from Engine".to_owned());
+        self.send_trader_command_backtest(&market, terminate);
+
+        self.deregister_trader(market);
+    }
+
+    /// Removes the `command_tx` of a removed [`Trader`], retaining it's [`Market`] for the
+    /// trading session summary.
+    fn deregister_trader(&mut self, market: Market) {
+        if self.trader_command_txs.remove(&market).is_some() {
+            info!(
+                engine_id = %self.engine_id,
+                market = &*format!("{:?}", market),
+                "removed Trader from Engine"
+            );
+            self.removed_markets.push(market);
+        }
+    }
+
     /// Generate a trading session summary. Uses the Portfolio's statistics per [`Market`] in
     /// combination with the average statistics across all [`Market`]s traded.
     fn generate_session_summary(mut self) -> Table {
         // Fetch statistics for each Market
-        let stats_per_market = self.trader_command_txs.into_keys().filter_map(|market| {
+        let markets = self
+            .trader_command_txs
+            .into_keys()
+            .chain(self.removed_markets);
+        let stats_per_market = markets.filter_map(|market| {
             let market_id = MarketId::from(&market);
 
             match self.portfolio.lock().get_statistics(&market_id) {
@@ -334,6 +968,23 @@ where
             }
         });
 
+        // Generate drawdown statistics across all markets using the mark-to-market equity curve,
+        // which supersedes the exited Position balances if any snapshots were taken
+        self.portfolio
+            .lock()
+            .get_equity_curve(self.engine_id)
+            .map(|equity_curve| {
+                self.statistics_summary
+                    .generate_equity_summary(&equity_curve);
+            })
+            .unwrap_or_else(|error| {
+                warn!(
+                    ?error,
+                    why = "failed to get equity curve from Portfolio's repository",
+                    "failed to generate mark-to-market Statistics summary for trading session"
+                );
+            });
+
         // Generate average statistics across all markets using session's exited Positions
         self.portfolio
             .lock()
@@ -358,7 +1009,7 @@ where
 
 /// Builder to construct [`Engine`] instances.
 #[derive(Debug, Default)]
-pub struct EngineBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+pub struct EngineBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: Serialize + Send,
@@ -366,22 +1017,30 @@ where
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
+    Clock: EngineClock + Send,
 {
     engine_id: Option<Uuid>,
     command_rx: Option<mpsc::Receiver<Command>>,
     portfolio: Option<Arc<Mutex<Portfolio>>>,
-    traders: Option<Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>>,
+    traders: Option<Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>>>,
     trader_command_txs: Option<HashMap<Market, mpsc::Sender<Command>>>,
+    trader_rx: Option<
+        mpsc::Receiver<AddTrader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>>,
+    >,
     statistics_summary: Option<Statistic>,
+    snapshot_interval: Option<Duration>,
 }
 
-impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
-    EngineBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
+    EngineBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: PositionSummariser + Serialize + Send,
     Portfolio: PositionHandler
+        + BalanceHandler
         + StatisticHandler<Statistic>
+        + StrategySnapshotHandler
+        + EquityHandler
         + MarketUpdater
         + OrderGenerator
         + FillUpdater
@@ -389,6 +1048,7 @@ where
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
+    Clock: EngineClock + Send,
 {
     fn new() -> Self {
         Self {
@@ -397,7 +1057,9 @@ where
             portfolio: None,
             traders: None,
             trader_command_txs: None,
+            trader_rx: None,
             statistics_summary: None,
+            snapshot_interval: None,
         }
     }
 
@@ -424,7 +1086,7 @@ where
 
     pub fn traders(
         self,
-        value: Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>,
+        value: Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>>,
     ) -> Self {
         Self {
             traders: Some(value),
@@ -439,6 +1101,19 @@ where
         }
     }
 
+    /// Optional mpsc::Receiver for receiving [`AddTrader`]s to hot-add to the running [`Engine`].
+    pub fn trader_rx(
+        self,
+        value: mpsc::Receiver<
+            AddTrader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>,
+        >,
+    ) -> Self {
+        Self {
+            trader_rx: Some(value),
+            ..self
+        }
+    }
+
     pub fn statistics_summary(self, value: Statistic) -> Self {
         Self {
             statistics_summary: Some(value),
@@ -446,9 +1121,23 @@ where
         }
     }
 
+    /// Optional interval at which the running [`Engine`] persists a snapshot of every
+    /// [`Trader`]'s Strategy state.
+    pub fn snapshot_interval(self, value: Duration) -> Self {
+        Self {
+            snapshot_interval: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(
         self,
-    ) -> Result<Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
+    ) -> Result<Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>, EngineError>
+    {
+        let traders = self
+            .traders
+            .ok_or(EngineError::BuilderIncomplete("traders"))?;
+
         Ok(Engine {
             engine_id: self
                 .engine_id
@@ -459,15 +1148,42 @@ where
             portfolio: self
                 .portfolio
                 .ok_or(EngineError::BuilderIncomplete("portfolio"))?,
-            traders: self
-                .traders
-                .ok_or(EngineError::BuilderIncomplete("traders"))?,
+            market_times: traders
+                .iter()
+                .map(|trader| (trader.market().clone(), trader.last_market_time()))
+                .collect(),
+            traders,
             trader_command_txs: self
                 .trader_command_txs
                 .ok_or(EngineError::BuilderIncomplete("trader_command_txs"))?,
+            trader_rx: self.trader_rx,
+            removed_markets: Vec::new(),
             statistics_summary: self
                 .statistics_summary
                 .ok_or(EngineError::BuilderIncomplete("statistics_summary"))?,
+            snapshot_interval: self.snapshot_interval,
         })
     }
 }
+
+/// Resolves at the next tick of the Strategy snapshot interval, or never resolves if there is no
+/// snapshot interval.
+async fn tick_snapshot_interval(interval: &mut Option<tokio::time::Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Receives the next [`AddTrader`] from the `trader_rx`, or never resolves if there is no
+/// `trader_rx`. Returns `None` if the `trader_rx` transmitter has been dropped.
+async fn recv_add_trader<AddTrader>(
+    trader_rx: &mut Option<mpsc::Receiver<AddTrader>>,
+) -> Option<AddTrader> {
+    match trader_rx {
+        Some(trader_rx) => trader_rx.recv().await,
+        None => std::future::pending().await,
+    }
+}
@@ -0,0 +1,128 @@
+use barter_integration::model::Market;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, time::Duration};
+
+/// Class of error a [`Trader`](super::trader::Trader) can encounter when driving the Portfolio &
+/// Execution components through the trading sequence.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum ErrorClass {
+    /// Portfolio failed to update from a [`MarketEvent`](barter_data::event::MarketEvent).
+    MarketUpdate,
+    /// Portfolio failed to generate an [`OrderEvent`](crate::portfolio::OrderEvent) from a
+    /// [`Signal`](crate::strategy::Signal) or [`SignalForceExit`](crate::strategy::SignalForceExit).
+    OrderGeneration,
+    /// Execution handler failed to execute an [`OrderEvent`](crate::portfolio::OrderEvent).
+    OrderExecution,
+    /// Portfolio failed to update from a [`FillEvent`](crate::execution::FillEvent).
+    FillUpdate,
+    /// Execution handler failed to report the state of in-flight orders.
+    ExecutionPoll,
+}
+
+/// Action taken once an error has exhausted it's retries.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum ErrorAction {
+    /// Skip the [`Event`](crate::event::Event) that caused the error & continue trading.
+    Skip,
+    /// Stop the [`Trader`](super::trader::Trader) that encountered the error.
+    HaltTrader,
+    /// Stop the [`Trader`](super::trader::Trader) that encountered the error, and have the
+    /// [`Engine`](super::Engine) terminate every other [`Trader`](super::trader::Trader).
+    HaltEngine,
+}
+
+/// Response to an error of a given [`ErrorClass`]. The failed operation is retried up to
+/// `max_retries` times, waiting `backoff` before the first retry & doubling it before each
+/// subsequent retry, before the [`ErrorAction`] is taken.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct ErrorResponse {
+    pub max_retries: u32,
+    pub backoff: Duration,
+    pub action: ErrorAction,
+}
+
+impl Default for ErrorResponse {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            backoff: Duration::ZERO,
+            action: ErrorAction::HaltTrader,
+        }
+    }
+}
+
+impl ErrorResponse {
+    /// Returns the duration to wait before the provided retry attempt (starting at 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.backoff
+            .saturating_mul(2_u32.saturating_pow(attempt.saturating_sub(1)))
+    }
+}
+
+/// Configures the [`ErrorResponse`] of a [`Trader`](super::trader::Trader) to each
+/// [`ErrorClass`]. By default every error halts the [`Trader`](super::trader::Trader) without
+/// retrying.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct ErrorPolicy {
+    pub market_update: ErrorResponse,
+    pub order_generation: ErrorResponse,
+    pub order_execution: ErrorResponse,
+    pub fill_update: ErrorResponse,
+    pub execution_poll: ErrorResponse,
+}
+
+impl ErrorPolicy {
+    /// Returns the [`ErrorResponse`] to an error of the provided [`ErrorClass`].
+    pub fn response(&self, class: ErrorClass) -> ErrorResponse {
+        match class {
+            ErrorClass::MarketUpdate => self.market_update,
+            ErrorClass::OrderGeneration => self.order_generation,
+            ErrorClass::OrderExecution => self.order_execution,
+            ErrorClass::FillUpdate => self.fill_update,
+            ErrorClass::ExecutionPoll => self.execution_poll,
+        }
+    }
+}
+
+/// Error a [`Trader`](super::trader::Trader) can encounter, identifiable by it's kind (ie/ the
+/// name of it's variant) without parsing it's message.
+pub trait ErrorKind: Display {
+    /// Returns the name of the kind of error.
+    fn kind(&self) -> &'static str;
+}
+
+/// Error encountered by a [`Trader`](super::trader::Trader) that could not be recovered by
+/// retrying, sent as an [`Event::Error`](crate::event::Event::Error).
+///
+/// The [`PortfolioError`](crate::portfolio::error::PortfolioError) or
+/// [`ExecutionError`](crate::execution::error::ExecutionError) is carried as it's [`ErrorKind`]
+/// & message since neither is serialisable.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct TraderError {
+    pub time: DateTime<Utc>,
+    pub market: Market,
+    pub class: ErrorClass,
+    pub kind: String,
+    pub error: String,
+    pub retries: u32,
+    pub action: ErrorAction,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_response_backoff_doubles_with_each_retry() {
+        let response = ErrorResponse {
+            max_retries: 3,
+            backoff: Duration::from_millis(100),
+            action: ErrorAction::Skip,
+        };
+
+        assert_eq!(response.backoff(1), Duration::from_millis(100));
+        assert_eq!(response.backoff(2), Duration::from_millis(200));
+        assert_eq!(response.backoff(3), Duration::from_millis(400));
+    }
+}
@@ -1,23 +1,31 @@
-use super::{error::EngineError, Command};
+use super::{
+    clock::EngineClock,
+    error::EngineError,
+    policy::{ErrorAction, ErrorClass, ErrorKind, ErrorPolicy, TraderError},
+    Command,
+};
 use crate::{
     data::{Feed, MarketGenerator},
     event::{Event, MessageTransmitter},
-    execution::ExecutionClient,
-    portfolio::{FillUpdater, MarketUpdater, OrderGenerator},
-    strategy::{SignalForceExit, SignalGenerator},
+    execution::{ExecutionClient, OrderStatus, OrderUpdate},
+    portfolio::{
+        repository::StrategySnapshotHandler, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator,
+    },
+    strategy::{Signal, SignalForceExit, SignalGenerator},
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{instrument::Instrument, Market};
+use barter_integration::model::{instrument::Instrument, Market, MarketId};
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 use serde::Serialize;
 use std::{collections::VecDeque, fmt::Debug, marker::PhantomData, sync::Arc};
 use tokio::sync::mpsc;
-use tracing::{debug, info, warn};
+use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 /// Lego components for constructing a [`Trader`] via the new() constructor method.
 #[derive(Debug)]
-pub struct TraderLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+pub struct TraderLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: Serialize + Send,
@@ -25,6 +33,7 @@ where
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>>,
     Strategy: SignalGenerator,
     Execution: ExecutionClient,
+    Clock: EngineClock,
 {
     /// Identifier for the [`Engine`](super::Engine) this [`Trader`] is associated with
     /// (1-to-many relationship).
@@ -44,6 +53,18 @@ where
     pub strategy: Strategy,
     /// Execution handler that implements [`ExecutionClient`].
     pub execution: Execution,
+    /// [`EngineClock`] that is advanced by every [`MarketEvent`] the [`Trader`] processes.
+    pub clock: Clock,
+    /// [`ErrorPolicy`] determining how the [`Trader`] responds to Portfolio & Execution errors.
+    pub error_policy: ErrorPolicy,
+    /// Historical [`MarketEvent`]s fed to the Strategy on construction to warm it up, with
+    /// [`Signal`](crate::strategy::Signal) generation suppressed (eg/ fetched via a
+    /// [`WarmUpSource`](crate::data::warm_up::WarmUpSource)).
+    pub warm_up: Vec<MarketEvent<Instrument, DataKind>>,
+    /// Optional [`Engine`](super::Engine) [`Command`] transmitter used to route
+    /// [`Signal`](crate::strategy::Signal)s the Strategy generates for other markets (see
+    /// [`SignalGenerator::take_routed_signals`]).
+    pub engine_command_tx: Option<mpsc::Sender<Command>>,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -53,7 +74,7 @@ where
 /// a [`Command::Terminate`] to the Trader's
 /// mpsc::Receiver command_rx.
 #[derive(Debug)]
-pub struct Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+pub struct Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: Serialize + Send,
@@ -61,6 +82,7 @@ where
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
+    Clock: EngineClock + Send,
 {
     /// Identifier for the [`Engine`](super::Engine) this [`Trader`] is associated with
     /// (1-to-many relationship).
@@ -83,21 +105,38 @@ where
     strategy: Strategy,
     /// Execution handler that implements [`ExecutionClient`].
     execution: Execution,
+    /// [`EngineClock`] that is advanced by every [`MarketEvent`] the [`Trader`] processes.
+    clock: Clock,
+    /// Paused [`Trader`]s do not enter new Positions, but continue to manage the exit of any open
+    /// Position.
+    paused: bool,
+    /// Shared-access to the exchange time of the last [`MarketEvent`] this [`Trader`] processed.
+    last_market_time: Arc<Mutex<Option<DateTime<Utc>>>>,
+    /// [`ErrorPolicy`] determining how the [`Trader`] responds to Portfolio & Execution errors.
+    error_policy: ErrorPolicy,
+    /// [`TraderError`] that halted this [`Trader`], if any.
+    halted: Option<TraderError>,
+    /// Optional [`Engine`](super::Engine) [`Command`] transmitter used to route
+    /// [`Signal`](crate::strategy::Signal)s the Strategy generates for other markets.
+    engine_command_tx: Option<mpsc::Sender<Command>>,
     _statistic_marker: PhantomData<Statistic>,
 }
 
-impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
-    Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
+    Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: Serialize + Send,
-    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + StrategySnapshotHandler,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
+    Clock: EngineClock + Send,
 {
     /// Constructs a new [`Trader`] instance using the provided [`TraderLego`].
-    pub fn new(lego: TraderLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution>) -> Self {
+    pub fn new(
+        lego: TraderLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>,
+    ) -> Self {
         info!(
             engine_id = %lego.engine_id,
             market = ?lego.market,
@@ -114,39 +153,171 @@ where
             data: lego.data,
             strategy: lego.strategy,
             execution: lego.execution,
+            clock: lego.clock,
+            paused: false,
+            last_market_time: Arc::new(Mutex::new(None)),
+            error_policy: lego.error_policy,
+            halted: None,
+            engine_command_tx: lego.engine_command_tx,
             _statistic_marker: PhantomData,
         }
+        .warm_up(lego.warm_up)
     }
 
     /// Builder to construct [`Trader`] instances.
-    pub fn builder() -> TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution> {
+    pub fn builder(
+    ) -> TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock> {
         TraderBuilder::new()
     }
 
+    /// Feeds the historical [`MarketEvent`]s to the Strategy so it is ready to generate
+    /// meaningful [`Signal`](crate::strategy::Signal)s once trading begins. Any
+    /// [`Signal`](crate::strategy::Signal)s generated (including those for other markets) are
+    /// discarded, and the Portfolio, Execution & [`EngineClock`] are left untouched.
+    fn warm_up(mut self, markets: Vec<MarketEvent<Instrument, DataKind>>) -> Self {
+        if markets.is_empty() {
+            return self;
+        }
+
+        let warm_up_events = markets.len();
+        for market in markets {
+            let _ = self.strategy.generate_signal(&market);
+            let _ = self.strategy.take_routed_signals();
+        }
+
+        if self.strategy.is_warmed_up() {
+            info!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                warm_up_events,
+                "Trader Strategy warmed up"
+            );
+        } else {
+            warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                warm_up_events,
+                action = "suppressing Signals until Strategy is warmed up by live MarketEvents",
+                "Trader Strategy not warmed up by the warm up MarketEvents provided"
+            );
+        }
+
+        self
+    }
+
+    /// Persists a snapshot of the Strategy state via the Portfolio repository, if the Strategy
+    /// supports it. Failures are logged since they do not affect trading.
+    pub(crate) fn snapshot_strategy(&mut self) {
+        let Some(snapshotter) = self.strategy.snapshotter() else {
+            return;
+        };
+
+        let persisted = snapshotter
+            .snapshot()
+            .map_err(|error| error.to_string())
+            .and_then(|snapshot| {
+                self.portfolio
+                    .lock()
+                    .set_strategy_snapshot(self.engine_id, &MarketId::from(&self.market), snapshot)
+                    .map_err(|error| error.to_string())
+            });
+
+        match persisted {
+            Ok(()) => debug!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                "persisted Strategy snapshot"
+            ),
+            Err(error) => warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                %error,
+                "failed to persist Strategy snapshot"
+            ),
+        }
+    }
+
+    /// Restores the Strategy state from the last snapshot persisted via the Portfolio repository,
+    /// if the Strategy supports it & a snapshot exists. Failures are logged, leaving the Strategy
+    /// in it's (possibly warmed up) initial state.
+    pub(crate) fn restore_strategy(&mut self) {
+        let Some(snapshotter) = self.strategy.snapshotter() else {
+            return;
+        };
+
+        let snapshot = self
+            .portfolio
+            .lock()
+            .get_strategy_snapshot(self.engine_id, &MarketId::from(&self.market));
+
+        let restored = match snapshot {
+            Ok(Some(snapshot)) => snapshotter
+                .restore(snapshot)
+                .map_err(|error| error.to_string()),
+            Ok(None) => return,
+            Err(error) => Err(error.to_string()),
+        };
+
+        match restored {
+            Ok(()) => info!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                "restored Strategy from snapshot"
+            ),
+            Err(error) => warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                %error,
+                action = "continuing with initial Strategy state",
+                "failed to restore Strategy from snapshot"
+            ),
+        }
+    }
+
+    /// Returns the [`Market`] this [`Trader`] is bartering on.
+    pub fn market(&self) -> &Market {
+        &self.market
+    }
+
+    /// Returns shared-access to the exchange time of the last [`MarketEvent`] this [`Trader`]
+    /// processed, enabling it to be observed while the [`Trader`] runs on it's own thread.
+    pub fn last_market_time(&self) -> Arc<Mutex<Option<DateTime<Utc>>>> {
+        Arc::clone(&self.last_market_time)
+    }
+
+    /// Returns the [`TraderError`] that halted this [`Trader`], if any.
+    pub fn halted(&self) -> Option<&TraderError> {
+        self.halted.as_ref()
+    }
+
     /// Run the trading event-loop for this [`Trader`] instance. Loop will run until [`Trader`]
-    /// receives a [`Command::Terminate`] via the mpsc::Receiver command_rx, or the
-    /// [`MarketGenerator`] yields [`Feed::Finished`].
-    pub fn run(mut self) {
+    /// receives a [`Command::Terminate`] via the mpsc::Receiver command_rx, the
+    /// [`MarketGenerator`] yields [`Feed::Finished`], or an error halts the [`Trader`] as
+    /// configured by it's [`ErrorPolicy`]. Returns the [`TraderError`] in the latter case.
+    ///
+    /// The Strategy state is restored from it's last snapshot (if any) before trading, and
+    /// snapshotted once trading stops. See [`StrategySnapshot`](crate::strategy::StrategySnapshot).
+    pub fn run(mut self) -> Option<TraderError> {
+        // Restore the Strategy state persisted by a previous run of this Trader
+        self.restore_strategy();
+
         // Run trading loop for this Trader instance
         'trading: loop {
+            // Stop trading if an error halted this Trader
+            if self.halted.is_some() {
+                break 'trading;
+            }
+
             // Check for new remote Commands before continuing to generate another MarketEvent
-            while let Some(command) = self.receive_remote_command() {
-                match command {
-                    Command::Terminate(_) => break 'trading,
-                    Command::ExitPosition(market) => {
-                        self.event_q
-                            .push_back(Event::SignalForceExit(SignalForceExit::from(market)));
-                    }
-                    _ => continue,
-                }
+            // '--> action any forced exits received alongside a Command::Terminate before stopping
+            if self.action_remote_commands() {
+                self.process_event_q();
+                break 'trading;
             }
 
-            // If the Feed<MarketEvent> yields, populate event_q with the next MarketEvent
+            // If the Feed<MarketEvent> yields, process the next MarketEvent
             match self.data.next() {
-                Feed::Next(market) => {
-                    self.event_tx.send(Event::Market(market.clone()));
-                    self.event_q.push_back(Event::Market(market));
-                }
+                Feed::Next(market) => self.process_market(market),
                 Feed::Unhealthy => {
                     warn!(
                         engine_id = %self.engine_id,
@@ -154,84 +325,373 @@ where
                         action = "continuing while waiting for healthy Feed",
                         "MarketFeed unhealthy"
                     );
+
+                    // In-flight orders may still be filled while the MarketFeed is quiet
+                    self.poll_execution();
+                    self.process_event_q();
                     continue 'trading;
                 }
                 Feed::Finished => break 'trading,
             }
+        }
+
+        debug!(
+            engine_id = &*self.engine_id.to_string(),
+            market = &*format!("{:?}", self.market),
+            "Trader trading loop stopped"
+        );
+
+        self.snapshot_strategy();
+
+        self.halted
+    }
+
+    /// Returns the next [`MarketEvent`] yielded by the [`MarketGenerator`], or `None` if the
+    /// [`Feed`] has finished. Used by drivers that pull [`MarketEvent`]s from many [`Trader`]s,
+    /// such as the [`Engine`](super::Engine) backtest mode.
+    pub(crate) fn next_market(&mut self) -> Option<MarketEvent<Instrument, DataKind>> {
+        loop {
+            match self.data.next() {
+                Feed::Next(market) => break Some(market),
+                Feed::Unhealthy => {
+                    warn!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        action = "continuing while waiting for healthy Feed",
+                        "MarketFeed unhealthy"
+                    );
+                }
+                Feed::Finished => break None,
+            }
+        }
+    }
+
+    /// Actions every remote [`Command`] received since the last check. Returns `true` if a
+    /// [`Command::Terminate`] was received and the [`Trader`] should stop trading.
+    pub(crate) fn action_remote_commands(&mut self) -> bool {
+        while let Some(command) = self.receive_remote_command() {
+            match command {
+                Command::Terminate(_) => return true,
+                Command::ExitPosition(market) => {
+                    self.event_q
+                        .push_back(Event::SignalForceExit(SignalForceExit::from_market(
+                            self.clock.time(),
+                            market,
+                        )));
+                }
+                Command::PauseTrader(_) => {
+                    info!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        "Trader paused, no new Positions will be entered"
+                    );
+                    self.paused = true;
+                }
+                Command::ResumeTrader(_) => {
+                    info!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        "Trader resumed"
+                    );
+                    self.paused = false;
+                }
+                Command::RouteSignal(signal) => {
+                    self.event_tx.send(Event::Signal(signal.clone()));
+                    self.event_q.push_back(Event::Signal(signal));
+                }
+                Command::SnapshotStrategies => self.snapshot_strategy(),
+                _ => continue,
+            }
+        }
+
+        false
+    }
+
+    /// Process the input [`MarketEvent`] by driving the Strategy, Portfolio & Execution
+    /// components through the trading sequence it triggers.
+    pub(crate) fn process_market(&mut self, market: MarketEvent<Instrument, DataKind>) {
+        if self.halted.is_some() {
+            return;
+        }
+
+        // Advance the EngineClock, match resting orders & populate event_q with any in-flight
+        // order FillEvents
+        self.clock.process_market(&market);
+        *self.last_market_time.lock() = Some(market.exchange_time);
+        self.execution.update_from_market(&market);
+        self.poll_execution();
+        if self.halted.is_some() {
+            return;
+        }
+
+        // Populate event_q with the MarketEvent
+        self.event_tx.send(Event::Market(market.clone()));
+        self.event_q.push_back(Event::Market(market));
+
+        self.process_event_q();
+    }
+
+    /// Populate the event_q with the [`FillEvent`](crate::execution::FillEvent)s of any in-flight
+    /// orders that have been filled since the last poll, and send the [`OrderUpdate`]s of any
+    /// in-flight orders that have changed state.
+    fn poll_execution(&mut self) {
+        let Ok(fills) = self.attempt(ErrorClass::ExecutionPoll, |trader| {
+            trader.execution.poll_fills()
+        }) else {
+            return;
+        };
+
+        let Ok(order_updates) = self.attempt(ErrorClass::ExecutionPoll, |trader| {
+            trader.execution.poll_order_updates()
+        }) else {
+            return;
+        };
+
+        // In-flight orders that failed may have been exits triggered by protective exits
+        if order_updates.iter().any(|update| {
+            matches!(
+                update.status,
+                OrderStatus::Rejected { .. }
+                    | OrderStatus::Cancelled { .. }
+                    | OrderStatus::Expired { .. }
+            )
+        }) {
+            self.rearm_protective_exits();
+        }
 
-            // Handle Events in the event_q
-            // '--> While loop will break when event_q is empty and requires another MarketEvent
-            while let Some(event) = self.event_q.pop_front() {
-                match event {
-                    Event::Market(market) => {
-                        if let Some(signal) = self.strategy.generate_signal(&market) {
+        self.event_tx
+            .send_many(order_updates.into_iter().map(Event::OrderUpdate).collect());
+
+        for fill in fills {
+            self.event_tx.send(Event::Fill(fill.clone()));
+            self.event_q.push_back(Event::Fill(fill));
+        }
+    }
+
+    /// Handle every [`Event`] in the event_q until it is empty and another [`MarketEvent`] is
+    /// required.
+    pub(crate) fn process_event_q(&mut self) {
+        if self.halted.is_some() {
+            return;
+        }
+
+        // Handle Events in the event_q
+        // '--> While loop will break when event_q is empty and requires another MarketEvent
+        while let Some(event) = self.event_q.pop_front() {
+            match event {
+                Event::Market(market) => {
+                    // Signals generated before the Strategy is warmed up are discarded
+                    let signal = self.strategy.generate_signal(&market);
+                    let routed_signals = self.strategy.take_routed_signals();
+                    if self.strategy.is_warmed_up() {
+                        if let Some(signal) = signal {
                             self.event_tx.send(Event::Signal(signal.clone()));
                             self.event_q.push_back(Event::Signal(signal));
                         }
+                        self.route_signals(routed_signals);
+                    }
 
-                        if let Some(position_update) = self
-                            .portfolio
-                            .lock()
-                            .update_from_market(&market)
-                            .expect("failed to update Portfolio from market")
-                        {
-                            self.event_tx.send(Event::PositionUpdate(position_update));
+                    if let Ok(Some(position_update)) = self
+                        .attempt(ErrorClass::MarketUpdate, |trader| {
+                            trader.portfolio.lock().update_from_market(&market)
+                        })
+                    {
+                        // Exit the Position if one of it's protective exits was triggered
+                        if let Some(exit_trigger) = position_update.exit_trigger {
+                            info!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                ?exit_trigger,
+                                "Position protective exit triggered"
+                            );
+                            self.event_q
+                                .push_back(Event::SignalForceExit(SignalForceExit::new(
+                                    self.clock.time(),
+                                    market.exchange.clone(),
+                                    market.instrument.clone(),
+                                )));
                         }
+
+                        self.event_tx.send(Event::PositionUpdate(position_update));
                     }
+                }
 
-                    Event::Signal(signal) => {
-                        if let Some(order) = self
-                            .portfolio
-                            .lock()
-                            .generate_order(&signal)
-                            .expect("failed to generate order")
-                        {
-                            self.event_tx.send(Event::OrderNew(order.clone()));
-                            self.event_q.push_back(Event::OrderNew(order));
-                        }
+                Event::Signal(signal) => {
+                    // Paused Traders only action Signals that reduce or exit a Position
+                    if let Ok(Some(order)) =
+                        self.attempt(ErrorClass::OrderGeneration, |trader| match trader.paused {
+                            true => trader.portfolio.lock().generate_reduce_only_order(&signal),
+                            false => trader.portfolio.lock().generate_order(&signal),
+                        })
+                    {
+                        self.event_tx.send(Event::OrderNew(order.clone()));
+                        self.event_q.push_back(Event::OrderNew(order));
                     }
+                }
 
-                    Event::SignalForceExit(signal_force_exit) => {
-                        if let Some(order) = self
+                Event::SignalForceExit(signal_force_exit) => {
+                    match self.attempt(ErrorClass::OrderGeneration, |trader| {
+                        trader
                             .portfolio
                             .lock()
-                            .generate_exit_order(signal_force_exit)
-                            .expect("failed to generate forced exit order")
-                        {
+                            .generate_exit_order(signal_force_exit.clone())
+                    }) {
+                        Ok(Some(order)) => {
                             self.event_tx.send(Event::OrderNew(order.clone()));
                             self.event_q.push_back(Event::OrderNew(order));
                         }
+                        Ok(None) => {}
+                        Err(_) => self.rearm_protective_exits(),
                     }
+                }
 
-                    Event::OrderNew(order) => {
-                        let fill = self
-                            .execution
-                            .generate_fill(&order)
-                            .expect("failed to generate Fill");
+                Event::OrderNew(order) => {
+                    // Orders that are not filled immediately are in-flight until polled as filled
+                    let fill = self.attempt(ErrorClass::OrderExecution, |trader| {
+                        trader.execution.generate_fill(&order)
+                    });
 
-                        self.event_tx.send(Event::Fill(fill.clone()));
-                        self.event_q.push_back(Event::Fill(fill));
+                    match fill {
+                        Ok(Some(fill)) => {
+                            self.send_order_update(&order, OrderStatus::Filled);
+                            self.event_tx.send(Event::Fill(fill.clone()));
+                            self.event_q.push_back(Event::Fill(fill));
+                        }
+                        Ok(None) => self.send_order_update(&order, OrderStatus::Acknowledged),
+                        Err(error) => {
+                            self.send_order_update(
+                                &order,
+                                OrderStatus::Rejected {
+                                    reason: error.error,
+                                },
+                            );
+                            if order.decision.is_exit() {
+                                self.rearm_protective_exits();
+                            }
+                        }
                     }
+                }
 
-                    Event::Fill(fill) => {
-                        let fill_side_effect_events = self
-                            .portfolio
-                            .lock()
-                            .update_from_fill(&fill)
-                            .expect("failed to update Portfolio from fill");
-
+                Event::Fill(fill) => {
+                    if let Ok(fill_side_effect_events) = self
+                        .attempt(ErrorClass::FillUpdate, |trader| {
+                            trader.portfolio.lock().update_from_fill(&fill)
+                        })
+                    {
                         self.event_tx.send_many(fill_side_effect_events);
                     }
-                    _ => {}
                 }
+                _ => {}
             }
+        }
+    }
 
-            debug!(
-                engine_id = &*self.engine_id.to_string(),
-                market = &*format!("{:?}", self.market),
-                "Trader trading loop stopped"
-            );
+    /// Attempts the provided operation, retrying it as configured by the [`ErrorPolicy`] if it
+    /// fails. If the error persists, a [`TraderError`] is sent as an [`Event::Error`], the
+    /// [`ErrorAction`] is taken, and the [`TraderError`] is returned.
+    fn attempt<Output, Error, Operation>(
+        &mut self,
+        class: ErrorClass,
+        mut operation: Operation,
+    ) -> Result<Output, Box<TraderError>>
+    where
+        Error: ErrorKind,
+        Operation: FnMut(&mut Self) -> Result<Output, Error>,
+    {
+        let response = self.error_policy.response(class);
+        let mut retries = 0;
+
+        let error = loop {
+            match operation(self) {
+                Ok(output) => return Ok(output),
+                Err(error) if retries < response.max_retries => {
+                    retries += 1;
+                    warn!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        ?class,
+                        %error,
+                        retries,
+                        "retrying failed operation"
+                    );
+                    std::thread::sleep(response.backoff(retries));
+                }
+                Err(error) => break error,
+            }
+        };
+
+        let error = Box::new(TraderError {
+            time: self.clock.time(),
+            market: self.market.clone(),
+            class,
+            kind: error.kind().to_owned(),
+            error: error.to_string(),
+            retries,
+            action: response.action,
+        });
+
+        error!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            ?class,
+            kind = %error.kind,
+            error = %error.error,
+            action = ?error.action,
+            "Trader failed to recover from error"
+        );
+        self.event_tx.send(Event::Error(error.clone()));
+
+        // Halted Traders discard any remaining Events in the event_q
+        if error.action != ErrorAction::Skip {
+            self.event_q.clear();
+            self.halted = Some(TraderError::clone(&error));
         }
+
+        Err(error)
+    }
+
+    /// Send the [`Signal`](crate::strategy::Signal)s generated for other markets to the
+    /// [`Engine`](super::Engine) as [`Command::RouteSignal`]s, to be actioned by the [`Trader`]
+    /// of their [`Market`].
+    fn route_signals(&mut self, signals: Vec<Signal>) {
+        for signal in signals {
+            let routed = match &self.engine_command_tx {
+                Some(engine_command_tx) => engine_command_tx
+                    .try_send(Command::RouteSignal(signal))
+                    .map_err(|error| error.to_string()),
+                None => Err("Trader has no engine_command_tx".to_owned()),
+            };
+
+            if let Err(error) = routed {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    %error,
+                    action = "dropping Signal",
+                    "failed to route cross-market Signal to the Engine"
+                );
+            }
+        }
+    }
+
+    /// Re-arm the protective exits of the open Position of this [`Trader`]'s [`Market`] after
+    /// an exit failed, so a protective exit that is still breached triggers another
+    /// [`SignalForceExit`] on the next [`MarketEvent`].
+    fn rearm_protective_exits(&mut self) {
+        let market = self.market.clone();
+        let _ = self.attempt(ErrorClass::MarketUpdate, |trader| {
+            trader.portfolio.lock().rearm_protective_exits(&market)
+        });
+    }
+
+    /// Send an [`OrderUpdate`] communicating the [`OrderStatus`] of the provided
+    /// [`OrderEvent`].
+    fn send_order_update(&mut self, order: &OrderEvent, status: OrderStatus) {
+        self.event_tx.send(Event::OrderUpdate(OrderUpdate::new(
+            self.clock.time(),
+            order,
+            status,
+        )));
     }
 
     /// Returns a [`Command`] if one has been received.
@@ -264,7 +724,7 @@ where
 
 /// Builder to construct [`Trader`] instances.
 #[derive(Debug, Default)]
-pub struct TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+pub struct TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: Serialize + Send,
@@ -272,6 +732,7 @@ where
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>>,
     Strategy: SignalGenerator,
     Execution: ExecutionClient,
+    Clock: EngineClock,
 {
     engine_id: Option<Uuid>,
     market: Option<Market>,
@@ -281,18 +742,23 @@ where
     data: Option<Data>,
     strategy: Option<Strategy>,
     execution: Option<Execution>,
+    clock: Option<Clock>,
+    error_policy: Option<ErrorPolicy>,
+    warm_up: Option<Vec<MarketEvent<Instrument, DataKind>>>,
+    engine_command_tx: Option<mpsc::Sender<Command>>,
     _statistic_marker: Option<PhantomData<Statistic>>,
 }
 
-impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
-    TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
+    TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: Serialize + Send,
-    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + StrategySnapshotHandler,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
+    Clock: EngineClock + Send,
 {
     fn new() -> Self {
         Self {
@@ -304,6 +770,10 @@ where
             data: None,
             strategy: None,
             execution: None,
+            clock: None,
+            error_policy: None,
+            warm_up: None,
+            engine_command_tx: None,
             _statistic_marker: None,
         }
     }
@@ -364,9 +834,38 @@ where
         }
     }
 
+    pub fn clock(self, value: Clock) -> Self {
+        Self {
+            clock: Some(value),
+            ..self
+        }
+    }
+
+    pub fn error_policy(self, value: ErrorPolicy) -> Self {
+        Self {
+            error_policy: Some(value),
+            ..self
+        }
+    }
+
+    pub fn warm_up(self, value: Vec<MarketEvent<Instrument, DataKind>>) -> Self {
+        Self {
+            warm_up: Some(value),
+            ..self
+        }
+    }
+
+    pub fn engine_command_tx(self, value: mpsc::Sender<Command>) -> Self {
+        Self {
+            engine_command_tx: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(
         self,
-    ) -> Result<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
+    ) -> Result<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution, Clock>, EngineError>
+    {
         Ok(Trader {
             engine_id: self
                 .engine_id
@@ -391,7 +890,14 @@ where
             execution: self
                 .execution
                 .ok_or(EngineError::BuilderIncomplete("execution"))?,
+            clock: self.clock.ok_or(EngineError::BuilderIncomplete("clock"))?,
+            paused: false,
+            last_market_time: Arc::new(Mutex::new(None)),
+            error_policy: self.error_policy.unwrap_or_default(),
+            halted: None,
+            engine_command_tx: self.engine_command_tx,
             _statistic_marker: PhantomData,
-        })
+        }
+        .warm_up(self.warm_up.unwrap_or_default()))
     }
 }
@@ -0,0 +1,24 @@
+use crate::portfolio::error::PortfolioError;
+use thiserror::Error;
+
+/// All errors generated in the barter::event module.
+#[derive(Error, Debug)]
+pub enum JournalError {
+    #[error("Failed to read or write the journal due to: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to serialize/deserialize JSON due to: {0}")]
+    JsonSerDe(#[from] serde_json::Error),
+
+    #[error("Failed to serialize binary Event due to: {0}")]
+    BinarySerialize(#[from] rmp_serde::encode::Error),
+
+    #[error("Failed to deserialize binary Event due to: {0}")]
+    BinaryDeserialize(#[from] rmp_serde::decode::Error),
+
+    #[error("Binary Event of {0} bytes exceeds the maximum journal record size")]
+    RecordTooLarge(usize),
+
+    #[error("Failed to replay Event into the Portfolio due to: {0}")]
+    Replay(#[from] PortfolioError),
+}
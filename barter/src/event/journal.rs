@@ -0,0 +1,314 @@
+use super::{error::JournalError, Event, EventTx, MessageTransmitter};
+use crate::{
+    engine::clock::EngineClock,
+    portfolio::{Balance, FillUpdater, MarketUpdater},
+};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, BufWriter, ErrorKind, Read, Write},
+    path::Path,
+    sync::Arc,
+};
+use tracing::warn;
+
+/// On-disk encoding of the [`Event`]s in a journal.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum JournalFormat {
+    /// Newline-delimited JSON, one [`Event`] per line.
+    Json,
+    /// Compact MessagePack encoded [`Event`]s, each prefixed by it's little-endian `u32` length
+    /// in bytes.
+    Binary,
+}
+
+impl JournalFormat {
+    /// Appends the encoded [`Event`] to the provided writer.
+    fn write<W: Write>(&self, writer: &mut W, event: &Event) -> Result<(), JournalError> {
+        match self {
+            JournalFormat::Json => {
+                serde_json::to_writer(&mut *writer, event)?;
+                writer.write_all(b"\n")?;
+            }
+            JournalFormat::Binary => {
+                let record = rmp_serde::to_vec(event)?;
+                let length = u32::try_from(record.len())
+                    .map_err(|_| JournalError::RecordTooLarge(record.len()))?;
+                writer.write_all(&length.to_le_bytes())?;
+                writer.write_all(&record)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// [`MessageTransmitter`] that appends every [`Event`] to an on-disk journal, flushing after
+/// each send so the journal survives a crash. Cloned [`JournalTx`]s append to the same journal,
+/// so one can be provided to every [`Trader`](crate::engine::trader::Trader) of an
+/// [`Engine`](crate::engine::Engine).
+///
+/// Journaled [`Event`]s can optionally be forwarded to an [`EventTx`] to continue listening to
+/// them in real-time.
+#[derive(Debug, Clone)]
+pub struct JournalTx {
+    format: JournalFormat,
+    writer: Arc<Mutex<BufWriter<File>>>,
+    event_tx: Option<EventTx>,
+}
+
+impl MessageTransmitter<Event> for JournalTx {
+    fn send(&mut self, message: Event) {
+        self.append(std::slice::from_ref(&message));
+
+        if let Some(event_tx) = &mut self.event_tx {
+            event_tx.send(message);
+        }
+    }
+
+    fn send_many(&mut self, messages: Vec<Event>) {
+        self.append(&messages);
+
+        if let Some(event_tx) = &mut self.event_tx {
+            event_tx.send_many(messages);
+        }
+    }
+}
+
+impl JournalTx {
+    /// Opens the journal at the provided path for appending [`Event`]s in the provided
+    /// [`JournalFormat`], creating it if it does not exist.
+    pub fn new<P: AsRef<Path>>(path: P, format: JournalFormat) -> Result<Self, JournalError> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        Ok(Self {
+            format,
+            writer: Arc::new(Mutex::new(BufWriter::new(file))),
+            event_tx: None,
+        })
+    }
+
+    /// Forward every journaled [`Event`] to the provided [`EventTx`].
+    pub fn forward(self, event_tx: EventTx) -> Self {
+        Self {
+            event_tx: Some(event_tx),
+            ..self
+        }
+    }
+
+    /// Appends the [`Event`]s to the journal & flushes it. Failures are logged since sending
+    /// [`Event`]s is infallible.
+    fn append(&self, events: &[Event]) {
+        let mut writer = self.writer.lock();
+
+        let appended = events
+            .iter()
+            .try_for_each(|event| self.format.write(&mut *writer, event))
+            .and_then(|_| writer.flush().map_err(JournalError::from));
+
+        if let Err(error) = appended {
+            warn!(
+                %error,
+                format = ?self.format,
+                "failed to append Events to journal"
+            );
+        }
+    }
+}
+
+/// Iterator over the [`Event`]s of an on-disk journal written by a [`JournalTx`].
+///
+/// A partially written final record (eg/ due to a crash mid-append) is logged & treated as the
+/// end of the journal.
+#[derive(Debug)]
+pub struct JournalReader {
+    format: JournalFormat,
+    reader: BufReader<File>,
+}
+
+impl Iterator for JournalReader {
+    type Item = Result<Event, JournalError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.format {
+            JournalFormat::Json => self.next_json(),
+            JournalFormat::Binary => self.next_binary(),
+        }
+    }
+}
+
+impl JournalReader {
+    /// Opens the journal at the provided path for reading [`Event`]s encoded in the provided
+    /// [`JournalFormat`].
+    pub fn open<P: AsRef<Path>>(path: P, format: JournalFormat) -> Result<Self, JournalError> {
+        Ok(Self {
+            format,
+            reader: BufReader::new(File::open(path)?),
+        })
+    }
+
+    fn next_json(&mut self) -> Option<Result<Event, JournalError>> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => return None,
+                Ok(_) if !line.ends_with('\n') => {
+                    Self::warn_truncated();
+                    return None;
+                }
+                Ok(_) if line.trim().is_empty() => continue,
+                Ok(_) => return Some(serde_json::from_str(&line).map_err(JournalError::from)),
+                Err(error) => return Some(Err(JournalError::from(error))),
+            }
+        }
+    }
+
+    fn next_binary(&mut self) -> Option<Result<Event, JournalError>> {
+        // Distinguish the end of the journal from a truncated record length
+        match self.reader.fill_buf() {
+            Ok([]) => return None,
+            Ok(_) => {}
+            Err(error) => return Some(Err(JournalError::from(error))),
+        }
+
+        let mut length = [0; 4];
+        let mut record = Vec::new();
+        let read = self.reader.read_exact(&mut length).and_then(|_| {
+            record.resize(u32::from_le_bytes(length) as usize, 0);
+            self.reader.read_exact(&mut record)
+        });
+
+        match read {
+            Ok(()) => Some(rmp_serde::from_slice(&record).map_err(JournalError::from)),
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => {
+                Self::warn_truncated();
+                None
+            }
+            Err(error) => Some(Err(JournalError::from(error))),
+        }
+    }
+
+    fn warn_truncated() {
+        warn!(
+            action = "ignoring partially written record",
+            "journal ends with a truncated Event"
+        );
+    }
+}
+
+/// Outcome of [`replay`]ing a journal.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct Replay {
+    /// Number of [`Event`]s replayed.
+    pub events: usize,
+    /// Last [`Balance`] recorded in the journal, useful for reconciling against the [`Balance`] of
+    /// the Portfolio the journal was replayed into.
+    pub journal_balance: Option<Balance>,
+}
+
+/// Reconstructs Portfolio state (balance, open & exited Positions, and statistics) by replaying
+/// the [`MarketEvent`](barter_data::event::MarketEvent)s & [`FillEvent`](crate::execution::FillEvent)s
+/// of a journal into a freshly initialised Portfolio, advancing the [`EngineClock`] with every
+/// [`MarketEvent`](barter_data::event::MarketEvent) as a [`Trader`](crate::engine::trader::Trader)
+/// would. The Portfolio must be configured identically to the one that produced the journal
+/// (eg/ starting cash, markets & statistic config).
+///
+/// Useful for auditing a trading session, or recovering Portfolio state after a crash.
+pub fn replay<Events, Portfolio, Clock>(
+    events: Events,
+    portfolio: &mut Portfolio,
+    clock: &Clock,
+) -> Result<Replay, JournalError>
+where
+    Events: IntoIterator<Item = Result<Event, JournalError>>,
+    Portfolio: MarketUpdater + FillUpdater,
+    Clock: EngineClock,
+{
+    let mut replay = Replay::default();
+
+    for event in events {
+        match event? {
+            Event::Market(market) => {
+                clock.process_market(&market);
+                portfolio.update_from_market(&market)?;
+            }
+            Event::Fill(fill) => {
+                portfolio.update_from_fill(&fill)?;
+            }
+            Event::Balance(balance) => replay.journal_balance = Some(balance),
+            _ => {}
+        }
+        replay.events += 1;
+    }
+
+    Ok(replay)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{fill_event, market_event_trade, position, signal};
+    use barter_integration::model::Side;
+    use uuid::Uuid;
+
+    fn journal_events() -> Vec<Event> {
+        vec![
+            Event::Market(market_event_trade(Side::Buy)),
+            Event::Signal(signal()),
+            Event::Fill(fill_event()),
+            Event::PositionNew(Box::new(position())),
+            Event::Balance(Balance::default()),
+        ]
+    }
+
+    #[test]
+    fn journal_tx_appends_events_readable_by_journal_reader() {
+        for format in [JournalFormat::Json, JournalFormat::Binary] {
+            let path = std::env::temp_dir().join(format!("barter_journal_{}", Uuid::new_v4()));
+
+            let events = journal_events();
+            let mut journal_tx = JournalTx::new(&path, format).unwrap();
+            journal_tx.send(events[0].clone());
+            journal_tx.send_many(events[1..].to_vec());
+
+            let actual = JournalReader::open(&path, format)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            std::fs::remove_file(&path).unwrap();
+
+            assert_eq!(actual, events, "{:?} journal failed", format);
+        }
+    }
+
+    #[test]
+    fn journal_reader_ignores_truncated_final_record() {
+        for format in [JournalFormat::Json, JournalFormat::Binary] {
+            let path = std::env::temp_dir().join(format!("barter_journal_{}", Uuid::new_v4()));
+
+            let mut events = journal_events();
+            let mut journal_tx = JournalTx::new(&path, format).unwrap();
+            journal_tx.send_many(events.clone());
+
+            // Simulate a crash mid-append by truncating the final record
+            let length = std::fs::metadata(&path).unwrap().len();
+            OpenOptions::new()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_len(length - 3)
+                .unwrap();
+
+            let actual = JournalReader::open(&path, format)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            std::fs::remove_file(&path).unwrap();
+
+            events.pop();
+            assert_eq!(actual, events, "{:?} journal failed", format);
+        }
+    }
+}
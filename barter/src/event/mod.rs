@@ -1,5 +1,6 @@
 use crate::{
-    execution::FillEvent,
+    engine::policy::TraderError,
+    execution::{FillEvent, OrderUpdate},
     portfolio::{
         position::{Position, PositionExit, PositionUpdate},
         Balance, OrderEvent,
@@ -13,22 +14,33 @@ use std::fmt::Debug;
 use tokio::sync::mpsc;
 use tracing::warn;
 
+/// Barter event module specific errors.
+pub mod error;
+
+/// On-disk journal of [`Event`]s, and the tooling to replay it into a Portfolio.
+// JournalError::Replay wraps a PortfolioError, which is large with the `decimal` feature.
+#[cfg_attr(feature = "decimal", allow(clippy::result_large_err))]
+pub mod journal;
+
 /// Events that occur when bartering. [`MarketEvent`], [`Signal`], [`OrderEvent`], and
 /// [`FillEvent`] are vital to the [`Trader`](crate::engine::trader::Trader) event loop, dictating
-/// the trading sequence. The [`PositionExit`] Event is a representation of work done by the
-/// system, and is useful for analysing performance & reconciliations.
+/// the trading sequence. [`OrderUpdate`]s communicate the lifecycle of every [`OrderEvent`] after
+/// it is sent to the execution handler. The [`PositionExit`] Event is a representation of work
+/// done by the system, and is useful for analysing performance & reconciliations. A
+/// [`TraderError`] Event communicates an error a Trader failed to recover from.
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Event {
     Market(MarketEvent<Instrument, DataKind>),
     Signal(Signal),
     SignalForceExit(SignalForceExit),
     OrderNew(OrderEvent),
-    OrderUpdate,
+    OrderUpdate(OrderUpdate),
     Fill(FillEvent),
-    PositionNew(Position),
+    PositionNew(Box<Position>),
     PositionUpdate(PositionUpdate),
     PositionExit(PositionExit),
     Balance(Balance),
+    Error(Box<TraderError>),
 }
 
 /// Message transmitter for sending Barter messages to downstream consumers.
@@ -1,8 +1,41 @@
+use crate::{engine::policy::ErrorKind, portfolio::OrderType, strategy::Decision};
+use barter_execution::model::ClientOrderId;
 use thiserror::Error;
 
 /// All errors generated in the barter::execution module.
-#[derive(Error, Copy, Clone, Debug)]
+#[derive(Error, Clone, Debug)]
 pub enum ExecutionError {
     #[error("Failed to build struct due to missing attributes: {0}")]
     BuilderIncomplete(&'static str),
+
+    #[error("ExecutionClient does not support OrderType: {0:?}")]
+    UnsupportedOrderType(OrderType),
+
+    #[error("{order_type:?} OrderEvent is missing the required {price}")]
+    MissingOrderPrice {
+        order_type: OrderType,
+        price: &'static str,
+    },
+
+    #[error("Bracket OrderEvent must enter a Position, but has Decision: {0:?}")]
+    BracketWithExitDecision(Decision),
+
+    #[error("ExecutionClient did not respond to the opened order with ClientOrderId: {0}")]
+    MissingOrderResponse(ClientOrderId),
+
+    #[error("ExecutionClient failed to execute order: {0}")]
+    Client(#[from] barter_execution::error::ExecutionError),
+}
+
+impl ErrorKind for ExecutionError {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::BuilderIncomplete(_) => "BuilderIncomplete",
+            Self::UnsupportedOrderType(_) => "UnsupportedOrderType",
+            Self::MissingOrderPrice { .. } => "MissingOrderPrice",
+            Self::BracketWithExitDecision(_) => "BracketWithExitDecision",
+            Self::MissingOrderResponse(_) => "MissingOrderResponse",
+            Self::Client(_) => "Client",
+        }
+    }
 }
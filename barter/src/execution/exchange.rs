@@ -0,0 +1,700 @@
+use crate::{
+    engine::clock::EngineClock,
+    execution::{
+        error::ExecutionError, ExecutionClient, Fees, FillEvent, OrderStatus, OrderUpdate,
+    },
+    portfolio::{OrderEvent, OrderType},
+};
+use barter_execution::model::{
+    order::{Cancelled, Order, OrderId, OrderKind, RequestOpen},
+    trade::Trade,
+    AccountEvent, AccountEventKind, ClientOrderId,
+};
+use barter_integration::model::{
+    amount::{Amount, AmountExt},
+    instrument::{kind::InstrumentKind, symbol::Symbol},
+    Market,
+};
+use std::collections::HashMap;
+use tokio::{runtime::Handle, sync::mpsc};
+use tracing::{debug, warn};
+
+/// [`OrderEvent`] that has been opened via a `barter-execution`
+/// [`ExecutionClient`](barter_execution::ExecutionClient), but has not yet been fully filled.
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub struct InFlightOrder {
+    /// [`OrderEvent`] that generated the opened [`Order`].
+    pub order: OrderEvent,
+    /// Client generated identifier of the opened [`Order`].
+    pub cid: ClientOrderId,
+    /// Absolute quantity requested when opening the [`Order`].
+    pub quantity: Amount,
+    /// Absolute quantity of the [`Order`] filled by [`Trade`]s so far.
+    pub filled_quantity: Amount,
+    /// Sum of abs(Trade quantity) * Trade price, excluding fees, of the [`Trade`]s so far.
+    pub fill_value_gross: Amount,
+    /// Sum of the fees, denominated in the quote currency, of the [`Trade`]s so far.
+    pub fees: Amount,
+}
+
+impl InFlightOrder {
+    /// Determines if the requested quantity of the [`InFlightOrder`] has been filled.
+    pub fn is_filled(&self) -> bool {
+        self.filled_quantity + Amount::from_f64_lossy(f64::EPSILON) * self.quantity >= self.quantity
+    }
+
+    /// Update the [`InFlightOrder`] fill state from a [`Trade`] that partially or fully filled it,
+    /// returning the absolute quantity, gross value & [`Fees`] of the [`Trade`] attributed to it.
+    fn update_from_trade(&mut self, trade: &Trade) -> (Amount, Amount, Fees) {
+        // Trade fees may be denominated in the base or quote Symbol, normalise to quote
+        let fees = Fees {
+            exchange: match trade.fees.symbol == trade.instrument.base {
+                true => trade.fees.fees * trade.price,
+                false => trade.fees.fees,
+            },
+            slippage: Amount::ZERO,
+            network: Amount::ZERO,
+        };
+
+        // Attribute only the part of the Trade within the remaining quantity (& it's pro-rata
+        // Fees) to this InFlightOrder
+        let remaining_quantity = self.quantity - self.filled_quantity;
+        let (quantity, fees) = match trade.quantity > remaining_quantity {
+            true => (
+                remaining_quantity,
+                fees.scale(remaining_quantity / trade.quantity),
+            ),
+            false => (trade.quantity, fees),
+        };
+        let fill_value_gross = quantity * trade.price;
+
+        self.filled_quantity += quantity;
+        self.fill_value_gross += fill_value_gross;
+        self.fees += fees.exchange;
+
+        (quantity, fill_value_gross, fees)
+    }
+}
+
+/// Execution handler that executes [`OrderEvent`]s via any `barter-execution`
+/// [`ExecutionClient`](barter_execution::ExecutionClient).
+///
+/// [`OrderEvent`]s are opened as [`Order<RequestOpen>`]s, and tracked as [`InFlightOrder`]s until
+/// the [`AccountEventKind::Trade`]s received via the `account_rx` fill their requested quantity.
+/// A [`FillEvent`] is generated for every [`Trade`], so the Portfolio books each partial fill as
+/// the venue does, including those of an [`InFlightOrder`] that is cancelled before being filled.
+/// Every partial fill, fill & cancellation of an [`InFlightOrder`] is also reported as an
+/// [`OrderUpdate`].
+///
+/// The asynchronous [`ExecutionClient`](barter_execution::ExecutionClient) is driven to
+/// completion using the provided tokio runtime [`Handle`], so the [`ExchangeExecution`] must not
+/// be used from within an asynchronous execution context (eg/ use it from a `Trader` thread).
+#[derive(Debug)]
+pub struct ExchangeExecution<Client, Clock> {
+    runtime: Handle,
+    client: Client,
+    account_rx: mpsc::UnboundedReceiver<AccountEvent>,
+    market_order_kind: OrderKind,
+    clock: Clock,
+    in_flight: HashMap<OrderId, InFlightOrder>,
+    fills: Vec<(OrderId, FillEvent)>,
+    updates: Vec<OrderUpdate>,
+}
+
+impl<Client, Clock> ExecutionClient for ExchangeExecution<Client, Clock>
+where
+    Client: barter_execution::ExecutionClient,
+    Clock: EngineClock,
+{
+    fn generate_fill(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        // Open the Order<RequestOpen> associated with the OrderEvent
+        let request = self.build_order_request(order)?;
+        let cid = order.cid;
+        let quantity = request.state.quantity;
+
+        let open = self
+            .runtime
+            .block_on(self.client.open_orders(vec![request]))
+            .into_iter()
+            .next()
+            .ok_or(ExecutionError::MissingOrderResponse(cid))??;
+
+        let order_id = open.state.id;
+        debug!(%cid, ?order_id, "opened Order for OrderEvent");
+
+        // Track the opened Order until AccountEvents confirm it has been filled
+        self.in_flight.insert(
+            order_id.clone(),
+            InFlightOrder {
+                order: order.clone(),
+                cid,
+                quantity,
+                filled_quantity: Amount::ZERO,
+                fill_value_gross: Amount::ZERO,
+                fees: Amount::ZERO,
+            },
+        );
+
+        // Return the FillEvent if the Order was filled immediately by a single Trade, otherwise
+        // it's FillEvents & OrderUpdates are yielded by the next poll
+        self.process_account_events();
+        let filled_immediately = !self.in_flight.contains_key(&order_id)
+            && self
+                .fills
+                .iter()
+                .filter(|(filled_id, _)| *filled_id == order_id)
+                .count()
+                == 1;
+        if !filled_immediately {
+            return Ok(None);
+        }
+
+        let fill = self
+            .fills
+            .iter()
+            .position(|(filled_id, _)| *filled_id == order_id)
+            .map(|index| self.fills.remove(index).1);
+
+        // Lifecycle of an immediately filled Order is reported by the caller
+        self.updates.retain(|update| update.cid != cid);
+
+        Ok(fill)
+    }
+
+    fn poll_fills(&mut self) -> Result<Vec<FillEvent>, ExecutionError> {
+        self.process_account_events();
+        Ok(self.fills.drain(..).map(|(_, fill)| fill).collect())
+    }
+
+    fn poll_order_updates(&mut self) -> Result<Vec<OrderUpdate>, ExecutionError> {
+        self.process_account_events();
+        Ok(self.updates.drain(..).collect())
+    }
+
+    /// `barter-execution` [`ExecutionClient`](barter_execution::ExecutionClient)s only report
+    /// asset balances, so the Position quantity is only derived for spot [`Market`]s, where it's
+    /// the total balance of the base asset.
+    fn fetch_position_quantity(
+        &mut self,
+        market: &Market,
+    ) -> Result<Option<Amount>, ExecutionError> {
+        if market.instrument.kind != InstrumentKind::Spot {
+            return Ok(None);
+        }
+
+        Ok(self.fetch_asset_quantities()?.map(|quantities| {
+            quantities
+                .get(&market.instrument.base)
+                .copied()
+                .unwrap_or(Amount::ZERO)
+        }))
+    }
+
+    fn fetch_asset_quantities(
+        &mut self,
+    ) -> Result<Option<HashMap<Symbol, Amount>>, ExecutionError> {
+        let balances = self.runtime.block_on(self.client.fetch_balances())?;
+
+        Ok(Some(
+            balances
+                .into_iter()
+                .map(|symbol_balance| (symbol_balance.symbol, symbol_balance.balance.total))
+                .collect(),
+        ))
+    }
+}
+
+impl<Client, Clock> ExchangeExecution<Client, Clock>
+where
+    Client: barter_execution::ExecutionClient,
+    Clock: EngineClock,
+{
+    /// Returns a [`ExchangeExecutionBuilder`] instance.
+    pub fn builder() -> ExchangeExecutionBuilder<Client, Clock> {
+        ExchangeExecutionBuilder::new()
+    }
+
+    /// Returns the [`InFlightOrder`]s that have been opened but not yet filled, keyed by the
+    /// exchange generated [`OrderId`].
+    pub fn in_flight(&self) -> &HashMap<OrderId, InFlightOrder> {
+        &self.in_flight
+    }
+
+    /// Consume every [`AccountEvent`] received from the
+    /// [`ExecutionClient`](barter_execution::ExecutionClient), updating the [`InFlightOrder`]s &
+    /// generating a [`FillEvent`] for every [`Trade`] that partially or fully fills one.
+    fn process_account_events(&mut self) {
+        while let Ok(event) = self.account_rx.try_recv() {
+            match event.kind {
+                AccountEventKind::Trade(trade) => self.update_from_trade(trade),
+                AccountEventKind::OrdersCancelled(cancelled) => {
+                    self.update_from_cancelled(cancelled)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Update the [`InFlightOrder`] associated with the [`Trade`], generating a [`FillEvent`] for
+    /// the quantity it filled, and an [`OrderUpdate`] communicating it's new [`OrderStatus`].
+    fn update_from_trade(&mut self, trade: Trade) {
+        let Some(in_flight) = self.in_flight.get_mut(&trade.order_id) else {
+            warn!(
+                ?trade,
+                why = "no InFlightOrder associated with Trade OrderId",
+                "ignoring AccountEvent Trade"
+            );
+            return;
+        };
+
+        let (quantity, fill_value_gross, fees) = in_flight.update_from_trade(&trade);
+        let fill = FillEvent {
+            time: self.clock.time(),
+            exchange: in_flight.order.exchange.clone(),
+            instrument: in_flight.order.instrument.clone(),
+            market_meta: in_flight.order.market_meta,
+            decision: in_flight.order.decision,
+            quantity: match in_flight.order.quantity.is_sign_negative() {
+                true => -quantity,
+                false => quantity,
+            },
+            fill_value_gross,
+            fees,
+        };
+        self.fills.push((trade.order_id.clone(), fill));
+
+        if in_flight.is_filled() {
+            let in_flight = self
+                .in_flight
+                .remove(&trade.order_id)
+                .expect("InFlightOrder existence checked above");
+            self.updates.push(OrderUpdate::new(
+                self.clock.time(),
+                &in_flight.order,
+                OrderStatus::Filled,
+            ));
+        } else {
+            let status = OrderStatus::PartiallyFilled {
+                filled_quantity: in_flight.filled_quantity,
+                remaining_quantity: in_flight.quantity - in_flight.filled_quantity,
+            };
+            self.updates.push(OrderUpdate::new(
+                self.clock.time(),
+                &in_flight.order,
+                status,
+            ));
+        }
+    }
+
+    /// Remove the [`InFlightOrder`]s associated with the [`Order<Cancelled>`]s, generating an
+    /// [`OrderUpdate`] communicating the cancellation. The quantity filled before cancellation
+    /// has already been yielded as [`FillEvent`]s.
+    fn update_from_cancelled(&mut self, cancelled: Vec<Order<Cancelled>>) {
+        for order in cancelled {
+            let Some(in_flight) = self.in_flight.remove(&order.state.id) else {
+                continue;
+            };
+
+            warn!(
+                cid = %in_flight.cid,
+                order_id = ?order.state.id,
+                filled_quantity = %in_flight.filled_quantity,
+                "InFlightOrder cancelled before being filled"
+            );
+
+            self.updates.push(OrderUpdate::new(
+                self.clock.time(),
+                &in_flight.order,
+                OrderStatus::Cancelled {
+                    filled_quantity: in_flight.filled_quantity,
+                },
+            ));
+        }
+    }
+
+    /// Map an [`OrderEvent`] to the [`Order<RequestOpen>`] sent to the
+    /// [`ExecutionClient`](barter_execution::ExecutionClient).
+    fn build_order_request(
+        &self,
+        order: &OrderEvent,
+    ) -> Result<Order<RequestOpen>, ExecutionError> {
+        let (kind, price) = match order.order_type {
+            OrderType::Market => (
+                self.market_order_kind,
+                Amount::from_f64_lossy(order.market_meta.close),
+            ),
+            OrderType::Limit => (
+                OrderKind::Limit,
+                order.limit_price.ok_or(ExecutionError::MissingOrderPrice {
+                    order_type: OrderType::Limit,
+                    price: "limit_price",
+                })?,
+            ),
+            unsupported => return Err(ExecutionError::UnsupportedOrderType(unsupported)),
+        };
+
+        Ok(Order {
+            exchange: order.exchange.clone(),
+            instrument: order.instrument.clone(),
+            cid: order.cid,
+            side: order.decision.order_side(),
+            state: RequestOpen {
+                kind,
+                price,
+                quantity: order.quantity.abs(),
+            },
+        })
+    }
+}
+
+/// Builder to construct [`ExchangeExecution`] instances.
+#[derive(Debug)]
+pub struct ExchangeExecutionBuilder<Client, Clock> {
+    runtime: Option<Handle>,
+    client: Option<Client>,
+    account_rx: Option<mpsc::UnboundedReceiver<AccountEvent>>,
+    market_order_kind: Option<OrderKind>,
+    clock: Option<Clock>,
+}
+
+impl<Client, Clock> ExchangeExecutionBuilder<Client, Clock>
+where
+    Client: barter_execution::ExecutionClient,
+    Clock: EngineClock,
+{
+    pub fn new() -> Self {
+        Self {
+            runtime: None,
+            client: None,
+            account_rx: None,
+            market_order_kind: None,
+            clock: None,
+        }
+    }
+
+    pub fn runtime(self, value: Handle) -> Self {
+        Self {
+            runtime: Some(value),
+            ..self
+        }
+    }
+
+    pub fn client(self, value: Client) -> Self {
+        Self {
+            client: Some(value),
+            ..self
+        }
+    }
+
+    pub fn account_rx(self, value: mpsc::UnboundedReceiver<AccountEvent>) -> Self {
+        Self {
+            account_rx: Some(value),
+            ..self
+        }
+    }
+
+    /// [`OrderKind`] used to open [`OrderType::Market`] [`OrderEvent`]s. Defaults to
+    /// [`OrderKind::Market`], but may be set to [`OrderKind::Limit`] for exchanges that only
+    /// support limit orders (eg/ the `barter-execution` `SimulatedExchange`).
+    pub fn market_order_kind(self, value: OrderKind) -> Self {
+        Self {
+            market_order_kind: Some(value),
+            ..self
+        }
+    }
+
+    pub fn clock(self, value: Clock) -> Self {
+        Self {
+            clock: Some(value),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> Result<ExchangeExecution<Client, Clock>, ExecutionError> {
+        Ok(ExchangeExecution {
+            runtime: self
+                .runtime
+                .ok_or(ExecutionError::BuilderIncomplete("runtime"))?,
+            client: self
+                .client
+                .ok_or(ExecutionError::BuilderIncomplete("client"))?,
+            account_rx: self
+                .account_rx
+                .ok_or(ExecutionError::BuilderIncomplete("account_rx"))?,
+            market_order_kind: self.market_order_kind.unwrap_or(OrderKind::Market),
+            clock: self
+                .clock
+                .ok_or(ExecutionError::BuilderIncomplete("clock"))?,
+            in_flight: HashMap::new(),
+            fills: Vec::new(),
+            updates: Vec::new(),
+        })
+    }
+}
+
+impl<Client, Clock> Default for ExchangeExecutionBuilder<Client, Clock>
+where
+    Client: barter_execution::ExecutionClient,
+    Clock: EngineClock,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{engine::clock::LiveClock, test_util::order_event};
+    use barter_data::subscription::trade::PublicTrade;
+    use barter_execution::{
+        model::{
+            balance::{Balance, SymbolBalance},
+            order::{Open, RequestCancel},
+        },
+        simulated::{
+            exchange::{
+                account::{balance::ClientBalances, ClientAccount},
+                SimulatedExchange,
+            },
+            execution::SimulatedExecution,
+            SimulatedEvent,
+        },
+        ExecutionClient as _, ExecutionId,
+    };
+    use barter_integration::{
+        amount,
+        model::{instrument::symbol::Symbol, Side},
+    };
+    use std::time::Duration;
+    use tokio::runtime::Runtime;
+
+    fn run_simulated_exchange(
+        runtime: &Runtime,
+    ) -> (
+        mpsc::UnboundedSender<SimulatedEvent>,
+        ExchangeExecution<SimulatedExecution, LiveClock>,
+    ) {
+        let (event_account_tx, account_rx) = mpsc::unbounded_channel();
+        let (request_tx, event_simulated_rx) = mpsc::unbounded_channel();
+
+        let order = order_event();
+        runtime.spawn(
+            SimulatedExchange::builder()
+                .event_simulated_rx(event_simulated_rx)
+                .account(
+                    ClientAccount::builder()
+                        .latency(Duration::ZERO)
+                        .fees_percent(amount!(0.1))
+                        .event_account_tx(event_account_tx)
+                        .instruments(vec![order.instrument])
+                        .balances(ClientBalances(HashMap::from([
+                            (
+                                Symbol::from("eth"),
+                                Balance::new(amount!(10.0), amount!(10.0)),
+                            ),
+                            (
+                                Symbol::from("usdt"),
+                                Balance::new(amount!(10_000.0), amount!(10_000.0)),
+                            ),
+                        ])))
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap()
+                .run(),
+        );
+
+        let execution = ExchangeExecution::builder()
+            .runtime(runtime.handle().clone())
+            .client(SimulatedExecution {
+                request_tx: request_tx.clone(),
+            })
+            .account_rx(account_rx)
+            .market_order_kind(OrderKind::Limit)
+            .clock(LiveClock)
+            .build()
+            .unwrap();
+
+        (request_tx, execution)
+    }
+
+    fn send_market_trade(
+        runtime: &Runtime,
+        execution: &ExchangeExecution<SimulatedExecution, LiveClock>,
+        request_tx: &mpsc::UnboundedSender<SimulatedEvent>,
+        amount: f64,
+    ) {
+        request_tx
+            .send(SimulatedEvent::MarketTrade((
+                order_event().instrument,
+                PublicTrade {
+                    id: "trade_id".to_string(),
+                    price: 100.0,
+                    amount,
+                    side: Side::Sell,
+                },
+            )))
+            .unwrap();
+
+        // SimulatedExchange actions events in order, so a response guarantees the trade is matched
+        runtime.block_on(execution.client.fetch_balances()).unwrap();
+    }
+
+    #[test]
+    fn exchange_execution_tracks_order_in_flight_until_trades_fill_it() {
+        let runtime = Runtime::new().unwrap();
+        let (request_tx, mut execution) = run_simulated_exchange(&runtime);
+
+        let mut order = order_event();
+        order.quantity = amount!(2.0);
+        order.market_meta.close = 100.0;
+
+        // Order is opened, but not filled until market liquidity matches it
+        assert_eq!(execution.generate_fill(&order).unwrap(), None);
+        assert_eq!(execution.in_flight().len(), 1);
+
+        // Partial fill generates a FillEvent for the quantity filled
+        send_market_trade(&runtime, &execution, &request_tx, 1.5);
+        let fills = execution.poll_fills().unwrap();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].quantity, amount!(1.5));
+        assert_eq!(fills[0].fill_value_gross, amount!(150.0));
+        assert!((fills[0].fees.exchange.to_f64_lossy() - 15.0).abs() < 1e-9);
+        assert_eq!(execution.in_flight().len(), 1);
+
+        let updates = execution.poll_order_updates().unwrap();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].cid, order.cid);
+        assert_eq!(
+            updates[0].status,
+            OrderStatus::PartiallyFilled {
+                filled_quantity: amount!(1.5),
+                remaining_quantity: amount!(0.5),
+            }
+        );
+
+        // Remaining quantity filled generates a FillEvent for the remaining quantity only
+        send_market_trade(&runtime, &execution, &request_tx, 1.0);
+        let fills = execution.poll_fills().unwrap();
+        assert_eq!(fills.len(), 1);
+        assert!(execution.in_flight().is_empty());
+
+        let updates = execution.poll_order_updates().unwrap();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].cid, order.cid);
+        assert_eq!(updates[0].status, OrderStatus::Filled);
+
+        let fill = &fills[0];
+        assert_eq!(fill.decision, order.decision);
+        assert_eq!(fill.quantity, amount!(0.5));
+        assert_eq!(fill.fill_value_gross, amount!(50.0));
+        assert!((fill.fees.exchange.to_f64_lossy() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn exchange_execution_errors_if_client_does_not_respond_to_order() {
+        struct SilentClient;
+
+        #[async_trait::async_trait]
+        impl barter_execution::ExecutionClient for SilentClient {
+            const CLIENT: ExecutionId = ExecutionId::Simulated;
+            type Config = ();
+
+            async fn init(_: Self::Config, _: mpsc::UnboundedSender<AccountEvent>) -> Self {
+                Self
+            }
+
+            async fn fetch_orders_open(
+                &self,
+            ) -> Result<Vec<Order<Open>>, barter_execution::error::ExecutionError> {
+                Ok(vec![])
+            }
+
+            async fn fetch_balances(
+                &self,
+            ) -> Result<Vec<SymbolBalance>, barter_execution::error::ExecutionError> {
+                Ok(vec![])
+            }
+
+            async fn open_orders(
+                &self,
+                _: Vec<Order<RequestOpen>>,
+            ) -> Vec<Result<Order<Open>, barter_execution::error::ExecutionError>> {
+                vec![]
+            }
+
+            async fn cancel_orders(
+                &self,
+                _: Vec<Order<RequestCancel>>,
+            ) -> Vec<Result<Order<Cancelled>, barter_execution::error::ExecutionError>>
+            {
+                vec![]
+            }
+
+            async fn cancel_orders_all(
+                &self,
+            ) -> Result<Vec<Order<Cancelled>>, barter_execution::error::ExecutionError>
+            {
+                Ok(vec![])
+            }
+        }
+
+        let runtime = Runtime::new().unwrap();
+        let (_account_tx, account_rx) = mpsc::unbounded_channel();
+        let mut execution = ExchangeExecution::builder()
+            .runtime(runtime.handle().clone())
+            .client(SilentClient)
+            .account_rx(account_rx)
+            .clock(LiveClock)
+            .build()
+            .unwrap();
+
+        let order = order_event();
+        assert!(matches!(
+            execution.generate_fill(&order),
+            Err(ExecutionError::MissingOrderResponse(cid)) if cid == order.cid
+        ));
+        assert!(execution.in_flight().is_empty());
+    }
+
+    #[test]
+    fn exchange_execution_rejects_unsupported_order_type() {
+        let runtime = Runtime::new().unwrap();
+        let (_request_tx, mut execution) = run_simulated_exchange(&runtime);
+
+        let mut order = order_event();
+        order.order_type = OrderType::Bracket;
+
+        assert!(matches!(
+            execution.generate_fill(&order),
+            Err(ExecutionError::UnsupportedOrderType(OrderType::Bracket))
+        ));
+    }
+
+    #[test]
+    fn exchange_execution_fetches_spot_position_quantity_from_base_asset_balance() {
+        let runtime = Runtime::new().unwrap();
+        let (_request_tx, mut execution) = run_simulated_exchange(&runtime);
+
+        let eth_usdt = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+        let btc_usdt = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+        let eth_usdt_perpetual = Market::new("binance", ("eth", "usdt", InstrumentKind::Perpetual));
+
+        assert_eq!(
+            execution.fetch_position_quantity(&eth_usdt).unwrap(),
+            Some(amount!(10.0))
+        );
+        assert_eq!(
+            execution.fetch_position_quantity(&btc_usdt).unwrap(),
+            Some(Amount::ZERO)
+        );
+        assert_eq!(
+            execution
+                .fetch_position_quantity(&eth_usdt_perpetual)
+                .unwrap(),
+            None
+        );
+    }
+}
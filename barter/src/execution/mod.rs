@@ -1,8 +1,15 @@
 use crate::{data::MarketMeta, portfolio::OrderEvent, strategy::Decision};
-use barter_integration::model::{instrument::Instrument, Exchange};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_execution::model::ClientOrderId;
+use barter_integration::model::{
+    amount::Amount,
+    instrument::{symbol::Symbol, Instrument},
+    Exchange, Market,
+};
 use chrono::{DateTime, Utc};
 use error::ExecutionError;
 use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, ops::AddAssign};
 
 /// Barter execution module specific errors.
 pub mod error;
@@ -10,10 +17,107 @@ pub mod error;
 /// Handlers for simulated and live [`OrderEvent`] execution.
 pub mod simulated;
 
+/// Handler that executes [`OrderEvent`]s via any `barter-execution`
+/// [`ExecutionClient`](barter_execution::ExecutionClient), such as an exchange integration or the
+/// `barter-execution` [`SimulatedExchange`](barter_execution::simulated::exchange::SimulatedExchange).
+pub mod exchange;
+
 /// Generates a result [`FillEvent`] by executing an [`OrderEvent`].
 pub trait ExecutionClient {
-    /// Return a [`FillEvent`] from executing the input [`OrderEvent`].
-    fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError>;
+    /// Execute the input [`OrderEvent`], returning a [`FillEvent`] if it was filled immediately.
+    /// If `None` is returned the order is in-flight, and it's [`FillEvent`] will be yielded by a
+    /// later call to [`ExecutionClient::poll_fills`].
+    fn generate_fill(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError>;
+
+    /// Return the [`FillEvent`]s of any in-flight orders that have been filled since the last
+    /// poll. Execution handlers that always fill immediately can rely on the default.
+    fn poll_fills(&mut self) -> Result<Vec<FillEvent>, ExecutionError> {
+        Ok(Vec::new())
+    }
+
+    /// Update any resting orders using the latest [`MarketEvent`], filling those whose price it
+    /// crosses. The resulting [`FillEvent`]s are yielded by the next call to
+    /// [`ExecutionClient::poll_fills`]. Execution handlers without resting orders can rely on the
+    /// default.
+    fn update_from_market(&mut self, _market: &MarketEvent<Instrument, DataKind>) {}
+
+    /// Return the [`OrderUpdate`]s of any in-flight orders that have changed state since the
+    /// last poll (eg/ partially filled, filled, cancelled or expired).
+    ///
+    /// The lifecycle of an order up to the result of [`ExecutionClient::generate_fill`] (ie/
+    /// acknowledged, immediately filled, or rejected) is reported by the caller, so execution
+    /// handlers that always fill immediately can rely on the default.
+    fn poll_order_updates(&mut self) -> Result<Vec<OrderUpdate>, ExecutionError> {
+        Ok(Vec::new())
+    }
+
+    /// Return the signed quantity of the Position the execution venue holds for the provided
+    /// [`Market`], used to reconcile Portfolio state with the venue before trading resumes.
+    /// Execution handlers that cannot report it (eg/ simulated execution) can rely on the
+    /// default, which returns `None`.
+    fn fetch_position_quantity(
+        &mut self,
+        _market: &Market,
+    ) -> Result<Option<Amount>, ExecutionError> {
+        Ok(None)
+    }
+
+    /// Return the quantity of every asset the execution venue holds, used to report venue
+    /// Positions in [`Market`]s the Portfolio is not configured to trade. Execution handlers that
+    /// cannot report them (eg/ simulated execution) can rely on the default, which returns `None`.
+    fn fetch_asset_quantities(
+        &mut self,
+    ) -> Result<Option<HashMap<Symbol, Amount>>, ExecutionError> {
+        Ok(None)
+    }
+}
+
+/// Communicates a change in the lifecycle state of an [`OrderEvent`], keyed by it's
+/// [`ClientOrderId`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderUpdate {
+    pub time: DateTime<Utc>,
+    /// Client generated identifier of the associated [`OrderEvent`].
+    pub cid: ClientOrderId,
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    pub status: OrderStatus,
+}
+
+impl OrderUpdate {
+    pub const EVENT_TYPE: &'static str = "OrderUpdate";
+
+    /// Constructs a new [`OrderUpdate`] communicating the [`OrderStatus`] of the provided
+    /// [`OrderEvent`].
+    pub fn new(time: DateTime<Utc>, order: &OrderEvent, status: OrderStatus) -> Self {
+        Self {
+            time,
+            cid: order.cid,
+            exchange: order.exchange.clone(),
+            instrument: order.instrument.clone(),
+            status,
+        }
+    }
+}
+
+/// Lifecycle state of an [`OrderEvent`] that has been sent to an [`ExecutionClient`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum OrderStatus {
+    /// Order has been accepted by the [`ExecutionClient`] and is working.
+    Acknowledged,
+    /// Order has been partially filled, and the remaining quantity is still working.
+    PartiallyFilled {
+        filled_quantity: Amount,
+        remaining_quantity: Amount,
+    },
+    /// Order has been completely filled.
+    Filled,
+    /// Order has been cancelled, possibly after being partially filled.
+    Cancelled { filled_quantity: Amount },
+    /// Order was rejected by the [`ExecutionClient`] for the provided reason.
+    Rejected { reason: String },
+    /// Order expired before being completely filled (eg/ due to it's time in force).
+    Expired { filled_quantity: Amount },
 }
 
 /// Fills are journals of work done by an Execution handler. These are sent back to the portfolio
@@ -28,9 +132,9 @@ pub struct FillEvent {
     /// LONG, CloseLong, SHORT or CloseShort
     pub decision: Decision,
     /// +ve or -ve Quantity depending on Decision
-    pub quantity: f64,
+    pub quantity: Amount,
     /// abs(Quantity) * ClosePrice, excluding TotalFees
-    pub fill_value_gross: f64,
+    pub fill_value_gross: Amount,
     /// All fee types incurred when executing an [`OrderEvent`], and their associated [`FeeAmount`].
     pub fees: Fees,
 }
@@ -57,13 +161,31 @@ pub struct Fees {
 
 impl Fees {
     /// Calculates the sum of every [FeeAmount] in [Fees].
-    pub fn calculate_total_fees(&self) -> f64 {
+    pub fn calculate_total_fees(&self) -> Amount {
         self.exchange + self.network + self.slippage
     }
+
+    /// Returns [Fees] with every [FeeAmount] multiplied by the provided ratio. Used to attribute
+    /// [Fees] pro-rata to part of a [`FillEvent`].
+    pub fn scale(&self, ratio: Amount) -> Fees {
+        Fees {
+            exchange: self.exchange * ratio,
+            slippage: self.slippage * ratio,
+            network: self.network * ratio,
+        }
+    }
+}
+
+impl AddAssign for Fees {
+    fn add_assign(&mut self, rhs: Self) {
+        self.exchange += rhs.exchange;
+        self.slippage += rhs.slippage;
+        self.network += rhs.network;
+    }
 }
 
-/// Communicative type alias for Fee amount as f64.
-pub type FeeAmount = f64;
+/// Communicative type alias for Fee amount as an [`Amount`].
+pub type FeeAmount = Amount;
 
 /// Builder to construct [FillEvent] instances.
 #[derive(Debug, Default)]
@@ -73,8 +195,8 @@ pub struct FillEventBuilder {
     pub instrument: Option<Instrument>,
     pub market_meta: Option<MarketMeta>,
     pub decision: Option<Decision>,
-    pub quantity: Option<f64>,
-    pub fill_value_gross: Option<f64>,
+    pub quantity: Option<Amount>,
+    pub fill_value_gross: Option<Amount>,
     pub fees: Option<Fees>,
 }
 
@@ -118,14 +240,14 @@ impl FillEventBuilder {
         }
     }
 
-    pub fn quantity(self, value: f64) -> Self {
+    pub fn quantity(self, value: Amount) -> Self {
         Self {
             quantity: Some(value),
             ..self
         }
     }
 
-    pub fn fill_value_gross(self, value: f64) -> Self {
+    pub fn fill_value_gross(self, value: Amount) -> Self {
         Self {
             fill_value_gross: Some(value),
             ..self
@@ -1,10 +1,22 @@
-use chrono::Utc;
-use serde::{Deserialize, Serialize};
-
 use crate::{
-    execution::{error::ExecutionError, ExecutionClient, Fees, FillEvent},
-    portfolio::OrderEvent,
+    data::MarketMeta,
+    engine::clock::EngineClock,
+    execution::{
+        error::ExecutionError, ExecutionClient, Fees, FillEvent, OrderStatus, OrderUpdate,
+    },
+    portfolio::{OrderEvent, OrderType},
+    strategy::Decision,
 };
+use barter_data::event::{DataKind, MarketEvent};
+use barter_execution::model::ClientOrderId;
+use barter_integration::model::{
+    amount::{Amount, AmountExt},
+    instrument::Instrument,
+    Side,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Configuration for constructing a [`SimulatedExecution`] via the new() constructor method.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
@@ -13,46 +25,283 @@ pub struct Config {
     pub simulated_fees_pct: Fees,
 }
 
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 /// Simulated execution handler that executes [`OrderEvent`]s to generate [`FillEvent`]s via a
-/// simulated broker interaction.
-pub struct SimulatedExecution {
+/// simulated broker interaction. Generated [`FillEvent`]s are timestamped using an [`EngineClock`].
+///
+/// [`OrderType::Market`] orders are filled immediately at the market price. [`OrderType::Limit`],
+/// [`OrderType::Stop`] & [`OrderType::Bracket`] orders that cannot be filled immediately become
+/// [`RestingOrder`]s, and are filled once a subsequent [`MarketEvent`] crosses their price.
+///
+/// Once the entry of an [`OrderType::Bracket`] is filled, it's stop loss & take profit exit legs
+/// rest until one of them fills, at which point the other is cancelled. If both legs are crossed
+/// by the same [`MarketEvent`] (eg/ a wide [`Candle`](barter_data::subscription::candle::Candle)),
+/// the stop loss is conservatively assumed to have filled first.
+pub struct SimulatedExecution<Clock> {
     fees_pct: Fees,
+    clock: Clock,
+    resting: Vec<RestingOrder>,
+    fills: Vec<FillEvent>,
+    updates: Vec<OrderUpdate>,
+}
+
+/// [`OrderEvent`] resting in the [`SimulatedExecution`] until a [`MarketEvent`] crosses it's
+/// price.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct RestingOrder {
+    pub order: OrderEvent,
+    /// [`ClientOrderId`] of the parent [`OrderType::Bracket`] if this is one of it's exit legs.
+    pub bracket_parent: Option<ClientOrderId>,
+}
+
+impl<Clock> ExecutionClient for SimulatedExecution<Clock>
+where
+    Clock: EngineClock,
+{
+    fn generate_fill(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        Self::validate_order(order)?;
+
+        // Fill the OrderEvent immediately if the current market price crosses it
+        let market_price = MarketRange::from_price(order.market_meta.close);
+        match Self::determine_fill_price(order, &market_price) {
+            Some(fill_price) => Ok(Some(self.fill_order(
+                order,
+                fill_price,
+                order.market_meta.time,
+            ))),
+            None => {
+                self.resting.push(RestingOrder {
+                    order: order.clone(),
+                    bracket_parent: None,
+                });
+                Ok(None)
+            }
+        }
+    }
+
+    fn update_from_market(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        let Some(market_range) = MarketRange::from_market(market) else {
+            return;
+        };
+
+        // Filling a RestingOrder may place or cancel others, so re-scan after every fill
+        let mut index = 0;
+        while index < self.resting.len() {
+            let resting = &self.resting[index];
+            let fill_price = match resting.order.exchange == market.exchange
+                && resting.order.instrument == market.instrument
+            {
+                true => Self::determine_fill_price(&resting.order, &market_range),
+                false => None,
+            };
+
+            let Some(fill_price) = fill_price else {
+                index += 1;
+                continue;
+            };
+
+            let resting = self.resting.remove(index);
+            self.updates.push(OrderUpdate::new(
+                self.clock.time(),
+                &resting.order,
+                OrderStatus::Filled,
+            ));
+
+            let fill = self.fill_order(&resting.order, fill_price, market.exchange_time);
+            self.fills.push(fill);
+            index = 0;
+        }
+    }
+
+    fn poll_fills(&mut self) -> Result<Vec<FillEvent>, ExecutionError> {
+        Ok(self.fills.drain(..).collect())
+    }
+
+    fn poll_order_updates(&mut self) -> Result<Vec<OrderUpdate>, ExecutionError> {
+        Ok(self.updates.drain(..).collect())
+    }
 }
 
-impl ExecutionClient for SimulatedExecution {
-    fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
-        // Assume (for now) that all orders are filled at the market price
-        let fill_value_gross = SimulatedExecution::calculate_fill_value_gross(order);
+impl<Clock> SimulatedExecution<Clock>
+where
+    Clock: EngineClock,
+{
+    /// Constructs a new [`SimulatedExecution`] component using the provided [`EngineClock`].
+    pub fn new(cfg: Config, clock: Clock) -> Self {
+        Self {
+            fees_pct: cfg.simulated_fees_pct,
+            clock,
+            resting: Vec::new(),
+            fills: Vec::new(),
+            updates: Vec::new(),
+        }
+    }
+
+    /// Returns the [`RestingOrder`]s waiting for a [`MarketEvent`] to cross their price.
+    pub fn resting(&self) -> &[RestingOrder] {
+        &self.resting
+    }
+
+    /// Validates the [`OrderEvent`] has the prices required by it's [`OrderType`].
+    fn validate_order(order: &OrderEvent) -> Result<(), ExecutionError> {
+        let missing = |price| ExecutionError::MissingOrderPrice {
+            order_type: order.order_type,
+            price,
+        };
+
+        match order.order_type {
+            OrderType::Market => {}
+            OrderType::Limit => {
+                order.limit_price.ok_or_else(|| missing("limit_price"))?;
+            }
+            OrderType::Stop => {
+                order.stop_price.ok_or_else(|| missing("stop_price"))?;
+            }
+            OrderType::Bracket => {
+                if order.decision.is_exit() {
+                    return Err(ExecutionError::BracketWithExitDecision(order.decision));
+                }
+                order.stop_price.ok_or_else(|| missing("stop_price"))?;
+                order
+                    .take_profit_price
+                    .ok_or_else(|| missing("take_profit_price"))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Determines the price the [`OrderEvent`] fills at if the [`MarketRange`] crosses it, or
+    /// `None` if it does not.
+    ///
+    /// Limit prices fill at the better of the limit & open price, whereas stop prices fill at the
+    /// worse of the stop & open price to model the market gapping through them.
+    fn determine_fill_price(order: &OrderEvent, market: &MarketRange) -> Option<Amount> {
+        let side = order.decision.order_side();
+        match (order.order_type, order.limit_price, order.stop_price) {
+            (OrderType::Market, _, _) | (OrderType::Bracket, None, _) => Some(market.open),
+            (OrderType::Limit | OrderType::Bracket, Some(limit), _) => match side {
+                Side::Buy => (market.low <= limit).then(|| limit.min(market.open)),
+                Side::Sell => (market.high >= limit).then(|| limit.max(market.open)),
+            },
+            (OrderType::Stop, _, Some(stop)) => match side {
+                Side::Buy => (market.high >= stop).then(|| stop.max(market.open)),
+                Side::Sell => (market.low <= stop).then(|| stop.min(market.open)),
+            },
+            // Validated OrderEvents always have the price required by their OrderType
+            (OrderType::Limit, None, _) | (OrderType::Stop, _, None) => None,
+        }
+    }
+
+    /// Generate the [`FillEvent`] of an [`OrderEvent`] filled at the provided price, which becomes
+    /// the [`MarketMeta`] close price.
+    ///
+    /// Filling an [`OrderType::Bracket`] entry places it's exit legs, and filling any exit
+    /// cancels the resting bracket legs protecting the exited Position.
+    fn fill_order(
+        &mut self,
+        order: &OrderEvent,
+        fill_price: Amount,
+        time: DateTime<Utc>,
+    ) -> FillEvent {
+        let fill_value_gross = Self::calculate_fill_value_gross(order, fill_price);
 
-        Ok(FillEvent {
-            time: Utc::now(),
+        if order.decision.is_exit() {
+            self.cancel_bracket_legs(order);
+        }
+
+        if order.order_type == OrderType::Bracket {
+            self.place_bracket_legs(order);
+        }
+
+        FillEvent {
+            time: self.clock.time(),
             exchange: order.exchange.clone(),
             instrument: order.instrument.clone(),
-            market_meta: order.market_meta,
+            market_meta: MarketMeta {
+                close: fill_price.to_f64_lossy(),
+                time,
+            },
             decision: order.decision,
             quantity: order.quantity,
             fill_value_gross,
-            fees: self.calculate_fees(&fill_value_gross),
-        })
+            fees: self.calculate_fees(fill_value_gross),
+        }
     }
-}
 
-impl SimulatedExecution {
-    /// Constructs a new [`SimulatedExecution`] component.
-    pub fn new(cfg: Config) -> Self {
-        Self {
-            fees_pct: cfg.simulated_fees_pct,
+    /// Place the stop loss & take profit exit legs of a filled [`OrderType::Bracket`] entry.
+    fn place_bracket_legs(&mut self, entry: &OrderEvent) {
+        let decision = match entry.decision {
+            Decision::Long => Decision::CloseLong,
+            _ => Decision::CloseShort,
+        };
+
+        // Leg ClientOrderIds are derived from the parent ClientOrderId so they are deterministic
+        let leg = |name: &str, order_type, limit_price, stop_price| OrderEvent {
+            time: self.clock.time(),
+            cid: ClientOrderId(Uuid::new_v5(&entry.cid.0, name.as_bytes())),
+            exchange: entry.exchange.clone(),
+            instrument: entry.instrument.clone(),
+            market_meta: entry.market_meta,
+            decision,
+            quantity: -entry.quantity,
+            order_type,
+            limit_price,
+            stop_price,
+            take_profit_price: None,
+        };
+
+        let stop_loss = leg("stop_loss", OrderType::Stop, None, entry.stop_price);
+        let take_profit = leg(
+            "take_profit",
+            OrderType::Limit,
+            entry.take_profit_price,
+            None,
+        );
+
+        for order in [stop_loss, take_profit] {
+            self.updates.push(OrderUpdate::new(
+                self.clock.time(),
+                &order,
+                OrderStatus::Acknowledged,
+            ));
+            self.resting.push(RestingOrder {
+                order,
+                bracket_parent: Some(entry.cid),
+            });
         }
     }
 
-    /// Calculates the simulated gross fill value (excluding TotalFees) based on the input [`OrderEvent`].
-    fn calculate_fill_value_gross(order: &OrderEvent) -> f64 {
-        order.quantity.abs() * order.market_meta.close
+    /// Cancel the resting bracket legs for the same instrument as the exit [`OrderEvent`].
+    fn cancel_bracket_legs(&mut self, exit: &OrderEvent) {
+        let (cancelled, resting) = std::mem::take(&mut self.resting)
+            .into_iter()
+            .partition::<Vec<_>, _>(|resting| {
+                resting.bracket_parent.is_some()
+                    && resting.order.exchange == exit.exchange
+                    && resting.order.instrument == exit.instrument
+            });
+
+        self.resting = resting;
+        for resting in cancelled {
+            self.updates.push(OrderUpdate::new(
+                self.clock.time(),
+                &resting.order,
+                OrderStatus::Cancelled {
+                    filled_quantity: Amount::ZERO,
+                },
+            ));
+        }
+    }
+
+    /// Calculates the simulated gross fill value (excluding TotalFees) of the input [`OrderEvent`]
+    /// filled at the provided price.
+    fn calculate_fill_value_gross(order: &OrderEvent, fill_price: Amount) -> Amount {
+        order.quantity.abs() * fill_price
     }
 
     /// Calculates the simulated [`Fees`] a [`FillEvent`] will incur, based on the input [`OrderEvent`].
-    fn calculate_fees(&self, fill_value_gross: &f64) -> Fees {
+    fn calculate_fees(&self, fill_value_gross: Amount) -> Fees {
         Fees {
             exchange: self.fees_pct.exchange * fill_value_gross,
             slippage: self.fees_pct.slippage * fill_value_gross,
@@ -61,36 +310,95 @@ impl SimulatedExecution {
     }
 }
 
+/// Range of prices a [`MarketEvent`] traded through, used to determine if it crosses the price
+/// of a [`RestingOrder`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+struct MarketRange {
+    open: Amount,
+    low: Amount,
+    high: Amount,
+}
+
+impl MarketRange {
+    /// Constructs a [`MarketRange`] of a single price.
+    fn from_price(price: f64) -> Self {
+        let price = Amount::from_f64_lossy(price);
+        Self {
+            open: price,
+            low: price,
+            high: price,
+        }
+    }
+
+    /// Determines the [`MarketRange`] of a [`MarketEvent`], if it contains a price.
+    fn from_market(market: &MarketEvent<Instrument, DataKind>) -> Option<Self> {
+        match &market.kind {
+            DataKind::Trade(trade) => Some(Self::from_price(trade.price)),
+            DataKind::Candle(candle) => Some(Self {
+                open: Amount::from_f64_lossy(candle.open),
+                low: Amount::from_f64_lossy(candle.low),
+                high: Amount::from_f64_lossy(candle.high),
+            }),
+            DataKind::OrderBookL1(book_l1) => {
+                Some(Self::from_price(book_l1.volume_weighed_mid_price()))
+            }
+            DataKind::OrderBook(book) => book.volume_weighed_mid_price().map(Self::from_price),
+            DataKind::Liquidation(_) | DataKind::FundingRate(_) => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_util::order_event;
+    use crate::{
+        engine::clock::LiveClock,
+        test_util::{market_event_candle, market_event_trade, order_event},
+    };
+    use barter_integration::amount;
+
+    fn simulated_execution() -> SimulatedExecution<LiveClock> {
+        SimulatedExecution::new(Config::default(), LiveClock)
+    }
+
+    fn market_event_at_price(order: &OrderEvent, price: f64) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.exchange = order.exchange.clone();
+        market.instrument = order.instrument.clone();
+        if let DataKind::Trade(trade) = &mut market.kind {
+            trade.price = price;
+        }
+        market
+    }
 
     #[test]
     fn should_generate_ok_fill_event_with_valid_order_event_provided() {
-        let simulated_execution = SimulatedExecution::new(Config {
-            simulated_fees_pct: Fees {
-                exchange: 0.1,
-                slippage: 0.05,
-                network: 0.0,
+        let mut simulated_execution = SimulatedExecution::new(
+            Config {
+                simulated_fees_pct: Fees {
+                    exchange: amount!(0.1),
+                    slippage: amount!(0.05),
+                    network: amount!(0.0),
+                },
             },
-        });
+            LiveClock,
+        );
 
         let mut input_order = order_event();
-        input_order.quantity = 10.0;
+        input_order.quantity = amount!(10.0);
         input_order.market_meta.close = 10.0;
 
         let actual_result = simulated_execution.generate_fill(&input_order);
 
-        let expected_fill_value_gross = 100.0;
+        let expected_fill_value_gross = amount!(100.0);
         let expected_fees = Fees {
-            exchange: 10.0,
-            slippage: 5.0,
-            network: 0.0,
+            exchange: amount!(10.0),
+            slippage: amount!(5.0),
+            network: amount!(0.0),
         };
 
         assert!(actual_result.is_ok());
-        let actual_result = actual_result.unwrap();
+        let actual_result = actual_result.unwrap().unwrap();
         assert_eq!(actual_result.fill_value_gross, expected_fill_value_gross);
         assert_eq!(actual_result.fees, expected_fees);
     }
@@ -98,12 +406,15 @@ mod tests {
     #[test]
     fn should_calculate_fill_value_gross_correctly() {
         let mut input_order = order_event();
-        input_order.quantity = 100.0;
+        input_order.quantity = amount!(100.0);
         input_order.market_meta.close = 10.0;
 
-        let actual = SimulatedExecution::calculate_fill_value_gross(&input_order);
+        let actual = SimulatedExecution::<LiveClock>::calculate_fill_value_gross(
+            &input_order,
+            Amount::from_f64_lossy(input_order.market_meta.close),
+        );
 
-        let expected = 100.0 * 10.0;
+        let expected = amount!(100.0) * amount!(10.0);
 
         assert_eq!(actual, expected)
     }
@@ -111,36 +422,214 @@ mod tests {
     #[test]
     fn should_calculate_fill_value_gross_correctly_with_negative_order_quantity_provided() {
         let mut input_order = order_event();
-        input_order.quantity = -(100.0);
+        input_order.quantity = -amount!(100.0);
         input_order.market_meta.close = 10.0;
 
-        let actual = SimulatedExecution::calculate_fill_value_gross(&input_order);
+        let actual = SimulatedExecution::<LiveClock>::calculate_fill_value_gross(
+            &input_order,
+            Amount::from_f64_lossy(input_order.market_meta.close),
+        );
 
-        let expected = (100.0 * 10.0) as f64;
+        let expected = amount!(100.0) * amount!(10.0);
 
         assert_eq!(actual, expected)
     }
 
     #[test]
     fn should_calculate_simulated_fees_correctly() {
-        let simulated_execution = SimulatedExecution::new(Config {
-            simulated_fees_pct: Fees {
-                exchange: 0.5,
-                slippage: 0.1,
-                network: 0.001,
+        let simulated_execution = SimulatedExecution::new(
+            Config {
+                simulated_fees_pct: Fees {
+                    exchange: amount!(0.5),
+                    slippage: amount!(0.1),
+                    network: amount!(0.001),
+                },
             },
-        });
+            LiveClock,
+        );
 
-        let input_fill_value_gross = 100.0;
+        let input_fill_value_gross = amount!(100.0);
 
-        let actual_result = simulated_execution.calculate_fees(&input_fill_value_gross);
+        let actual_result = simulated_execution.calculate_fees(input_fill_value_gross);
 
         let expected = Fees {
-            exchange: 50.0,
-            slippage: 10.0,
-            network: 0.1,
+            exchange: amount!(50.0),
+            slippage: amount!(10.0),
+            network: amount!(0.1),
         };
 
         assert_eq!(actual_result, expected)
     }
+
+    #[test]
+    fn limit_order_rests_until_market_crosses_limit_price() {
+        let mut execution = simulated_execution();
+
+        let mut order = order_event();
+        order.decision = Decision::Long;
+        order.quantity = amount!(2.0);
+        order.market_meta.close = 100.0;
+        order.order_type = OrderType::Limit;
+        order.limit_price = Some(amount!(90.0));
+
+        // Limit price below the market price rests
+        assert_eq!(execution.generate_fill(&order).unwrap(), None);
+        assert_eq!(execution.resting().len(), 1);
+
+        // MarketEvent above the limit price does not fill
+        execution.update_from_market(&market_event_at_price(&order, 95.0));
+        assert!(execution.poll_fills().unwrap().is_empty());
+        assert!(execution.poll_order_updates().unwrap().is_empty());
+
+        // MarketEvent reaching the limit price fills at the limit price
+        execution.update_from_market(&market_event_at_price(&order, 90.0));
+        let fills = execution.poll_fills().unwrap();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].quantity, amount!(2.0));
+        assert_eq!(fills[0].market_meta.close, 90.0);
+        assert_eq!(fills[0].fill_value_gross, amount!(2.0) * amount!(90.0));
+        assert!(execution.resting().is_empty());
+
+        let updates = execution.poll_order_updates().unwrap();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].cid, order.cid);
+        assert_eq!(updates[0].status, OrderStatus::Filled);
+    }
+
+    #[test]
+    fn marketable_limit_order_fills_immediately_at_market_price() {
+        let mut execution = simulated_execution();
+
+        let mut order = order_event();
+        order.decision = Decision::Short;
+        order.quantity = -amount!(1.0);
+        order.market_meta.close = 100.0;
+        order.order_type = OrderType::Limit;
+        order.limit_price = Some(amount!(90.0));
+
+        let fill = execution.generate_fill(&order).unwrap().unwrap();
+        assert_eq!(fill.fill_value_gross, amount!(100.0));
+        assert!(execution.resting().is_empty());
+    }
+
+    #[test]
+    fn stop_order_fills_at_open_price_when_market_gaps_through_stop_price() {
+        let mut execution = simulated_execution();
+
+        let mut order = order_event();
+        order.decision = Decision::CloseLong;
+        order.quantity = -amount!(1.0);
+        order.market_meta.close = 1000.0;
+        order.order_type = OrderType::Stop;
+        order.stop_price = Some(amount!(980.0));
+
+        assert_eq!(execution.generate_fill(&order).unwrap(), None);
+
+        // Candle opens at 960.0, below the stop price, so the stop fills at the open price
+        let mut market = market_event_candle();
+        market.exchange = order.exchange.clone();
+        market.instrument = order.instrument.clone();
+        execution.update_from_market(&market);
+
+        let fills = execution.poll_fills().unwrap();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].decision, Decision::CloseLong);
+        assert_eq!(fills[0].fill_value_gross, amount!(960.0));
+    }
+
+    #[test]
+    fn bracket_order_take_profit_fill_cancels_stop_loss_leg() {
+        let mut execution = simulated_execution();
+
+        let mut order = order_event();
+        order.decision = Decision::Long;
+        order.quantity = amount!(1.0);
+        order.market_meta.close = 100.0;
+        order.order_type = OrderType::Bracket;
+        order.stop_price = Some(amount!(90.0));
+        order.take_profit_price = Some(amount!(120.0));
+
+        // Entry without a limit price fills immediately, placing the stop loss & take profit legs
+        let entry = execution.generate_fill(&order).unwrap().unwrap();
+        assert_eq!(entry.fill_value_gross, amount!(100.0));
+        assert_eq!(execution.resting().len(), 2);
+        assert!(execution
+            .resting()
+            .iter()
+            .all(|leg| leg.bracket_parent == Some(order.cid)
+                && leg.order.decision == Decision::CloseLong
+                && leg.order.quantity == -amount!(1.0)));
+
+        let updates = execution.poll_order_updates().unwrap();
+        assert_eq!(updates.len(), 2);
+        assert!(updates
+            .iter()
+            .all(|update| update.status == OrderStatus::Acknowledged));
+        let (stop_loss_cid, take_profit_cid) = (updates[0].cid, updates[1].cid);
+
+        // MarketEvent between the legs fills neither
+        execution.update_from_market(&market_event_at_price(&order, 110.0));
+        assert!(execution.poll_fills().unwrap().is_empty());
+
+        // MarketEvent crossing the take profit fills it & cancels the stop loss
+        execution.update_from_market(&market_event_at_price(&order, 125.0));
+        let fills = execution.poll_fills().unwrap();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].decision, Decision::CloseLong);
+        assert_eq!(fills[0].quantity, -amount!(1.0));
+        assert_eq!(fills[0].fill_value_gross, amount!(125.0));
+        assert!(execution.resting().is_empty());
+
+        let updates = execution.poll_order_updates().unwrap();
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates[0].cid, take_profit_cid);
+        assert_eq!(updates[0].status, OrderStatus::Filled);
+        assert_eq!(updates[1].cid, stop_loss_cid);
+        assert_eq!(
+            updates[1].status,
+            OrderStatus::Cancelled {
+                filled_quantity: amount!(0.0)
+            }
+        );
+
+        // Cancelled stop loss leg is not filled by a later MarketEvent
+        execution.update_from_market(&market_event_at_price(&order, 80.0));
+        assert!(execution.poll_fills().unwrap().is_empty());
+    }
+
+    #[test]
+    fn generate_fill_with_invalid_order_prices_and_return_err() {
+        let mut execution = simulated_execution();
+
+        let mut limit_without_price = order_event();
+        limit_without_price.order_type = OrderType::Limit;
+
+        let mut bracket_without_take_profit = order_event();
+        bracket_without_take_profit.order_type = OrderType::Bracket;
+        bracket_without_take_profit.stop_price = Some(amount!(90.0));
+
+        let mut bracket_exit = order_event();
+        bracket_exit.order_type = OrderType::Bracket;
+        bracket_exit.decision = Decision::CloseLong;
+
+        assert!(matches!(
+            execution.generate_fill(&limit_without_price),
+            Err(ExecutionError::MissingOrderPrice {
+                order_type: OrderType::Limit,
+                price: "limit_price"
+            })
+        ));
+        assert!(matches!(
+            execution.generate_fill(&bracket_without_take_profit),
+            Err(ExecutionError::MissingOrderPrice {
+                order_type: OrderType::Bracket,
+                price: "take_profit_price"
+            })
+        ));
+        assert!(matches!(
+            execution.generate_fill(&bracket_exit),
+            Err(ExecutionError::BracketWithExitDecision(Decision::CloseLong))
+        ));
+        assert!(execution.resting().is_empty());
+    }
 }
@@ -35,6 +35,10 @@
 //! * **Engine**: Multi-threaded trading Engine capable of trading with an arbitrary number of Trader market pairs. Each
 //! contained Trader instance operates on its own thread.
 //!
+//! Monetary amounts (prices, quantities, fees & balances) are
+//! [`Amount`](barter_integration::model::amount::Amount)s, which are `f64`s by default, or exact
+//! `Decimal`s with the `decimal` feature enabled.
+//!
 //! [`Barter`]: https://github.com/barter-rs/barter-rs
 //! [`Barter-Data`]: https://crates.io/crates/barter-data
 //! [`Readme`]: https://crates.io/crates/barter
@@ -80,6 +84,7 @@
 //! ### Portfolio
 //! ```
 //! use barter::{
+//!     engine::clock::LiveClock,
 //!     portfolio::{
 //!         MarketUpdater, OrderGenerator, FillUpdater,
 //!         portfolio::{PortfolioLego, MetaPortfolio},
@@ -94,22 +99,28 @@
 //!     event::Event,
 //!     test_util,
 //! };
-//! use barter_integration::model::{Market, instrument::kind::InstrumentKind};
-//! use std::marker::PhantomData;
+//! use barter_integration::{amount, model::{Market, instrument::kind::InstrumentKind}};
+//! use std::{collections::HashMap, marker::PhantomData};
 //! use uuid::Uuid;
 //!
 //! let components = PortfolioLego {
 //!     engine_id: Uuid::new_v4(),
 //!     markets: vec![Market::new("binance", ("btc", "usdt", InstrumentKind::Spot))],
 //!     repository: InMemoryRepository::new(),
-//!     allocator: DefaultAllocator{ default_order_value: 100.0 },
+//!     allocator: DefaultAllocator{ default_order_value: 100.0, ..Default::default() },
 //!     risk: DefaultRisk{},
-//!     starting_cash: 10000.0,
+//!     starting_cash: amount!(10000.0),
+//!     starting_balances: HashMap::new(),
 //!     statistic_config: StatisticConfig {
 //!         starting_equity: 10000.0 ,
 //!         trading_days_per_year: 365,
 //!         risk_free_return: 0.0
 //!     },
+//!     clock: LiveClock,
+//!     protective_exits: Default::default(),
+//!     margin: None,
+//!     reporting_currency: None,
+//!     equity_snapshots: None,
 //!     _statistic_marker: PhantomData::<TradingSummary>::default()
 //! };
 //!
@@ -137,6 +148,7 @@
 //! ### Execution
 //! ```
 //! use barter::{
+//!     engine::clock::LiveClock,
 //!     test_util,
 //!     portfolio::OrderEvent,
 //!     execution::{
@@ -144,16 +156,17 @@
 //!         Fees, ExecutionClient,
 //!     }
 //! };
+//! use barter_integration::amount;
 //!
 //! let config = ExecutionConfig {
 //!     simulated_fees_pct: Fees {
-//!         exchange: 0.1,
-//!         slippage: 0.05, // Simulated slippage modelled as a Fee
-//!         network: 0.0,
+//!         exchange: amount!(0.1),
+//!         slippage: amount!(0.05), // Simulated slippage modelled as a Fee
+//!         network: amount!(0.0),
 //!     }
 //! };
 //!
-//! let mut execution = SimulatedExecution::new(config);
+//! let mut execution = SimulatedExecution::new(config, LiveClock);
 //!
 //! let order_event = test_util::order_event();
 //!
@@ -170,6 +183,7 @@
 //!         Initialiser, PositionSummariser, TableBuilder
 //!     }
 //! };
+//! use chrono::Utc;
 //!
 //! // Do some automated trading with barter components that generates a vector of closed Positions
 //! let positions = vec![test_util::position(), test_util::position()];
@@ -180,7 +194,7 @@
 //!     risk_free_return: 0.5,
 //! };
 //!
-//! let mut trading_summary = TradingSummary::init(config);
+//! let mut trading_summary = TradingSummary::init(config, Utc::now());
 //!
 //! trading_summary.generate_summary(&positions);
 //!
@@ -220,6 +234,9 @@ pub mod strategy;
 /// generic Repository. This also contains example implementations of an OrderAllocator &
 /// OrderEvaluator, which help the Portfolio make decisions on whether to generate OrderEvents and
 /// of what size.
+// With the `decimal` feature PortfolioError::ReconciliationBreak holds a Market plus two 16 byte
+// Decimals, which tips PortfolioError over clippy's result_large_err threshold.
+#[cfg_attr(feature = "decimal", allow(clippy::result_large_err))]
 pub mod portfolio;
 
 /// Defines a FillEvent, and provides a useful trait FillGenerator for handling the generation
@@ -257,12 +274,17 @@ pub mod test_util {
         exchange::ExchangeId,
         subscription::{candle::Candle, trade::PublicTrade},
     };
-    use barter_integration::model::{
-        instrument::{kind::InstrumentKind, Instrument},
-        Exchange, Side,
+    use barter_execution::model::ClientOrderId;
+    use barter_integration::{
+        amount,
+        model::{
+            instrument::{kind::InstrumentKind, Instrument},
+            Exchange, Side,
+        },
     };
     use chrono::Utc;
     use std::ops::Add;
+    use uuid::Uuid;
 
     /// Build a [`MarketEvent`] of [`DataKind::PublicTrade`](DataKind) with the provided [`Side`].
     pub fn market_event_trade(side: Side) -> MarketEvent<Instrument, DataKind> {
@@ -307,6 +329,7 @@ pub mod test_util {
             exchange: Exchange::from("binance"),
             instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
             signals: Default::default(),
+            target_exposure: None,
             market_meta: Default::default(),
         }
     }
@@ -315,12 +338,16 @@ pub mod test_util {
     pub fn order_event() -> OrderEvent {
         OrderEvent {
             time: Utc::now(),
+            cid: ClientOrderId(Uuid::new_v4()),
             exchange: Exchange::from("binance"),
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
             market_meta: MarketMeta::default(),
             decision: Decision::default(),
-            quantity: 1.0,
+            quantity: amount!(1.0),
             order_type: OrderType::default(),
+            limit_price: None,
+            stop_price: None,
+            take_profit_price: None,
         }
     }
 
@@ -332,8 +359,8 @@ pub mod test_util {
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
             market_meta: Default::default(),
             decision: Decision::default(),
-            quantity: 1.0,
-            fill_value_gross: 100.0,
+            quantity: amount!(1.0),
+            fill_value_gross: amount!(100.0),
             fees: Fees::default(),
         }
     }
@@ -346,19 +373,22 @@ pub mod test_util {
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
             meta: Default::default(),
             side: Side::Buy,
-            quantity: 1.0,
+            quantity: amount!(1.0),
             enter_fees: Default::default(),
-            enter_fees_total: 0.0,
-            enter_avg_price_gross: 100.0,
-            enter_value_gross: 100.0,
+            enter_fees_total: amount!(0.0),
+            enter_fees_realised: amount!(0.0),
+            enter_avg_price_gross: amount!(100.0),
+            enter_value_gross: amount!(100.0),
             exit_fees: Default::default(),
-            exit_fees_total: 0.0,
-            exit_avg_price_gross: 0.0,
-            exit_value_gross: 0.0,
-            current_symbol_price: 100.0,
-            current_value_gross: 100.0,
-            unrealised_profit_loss: 0.0,
-            realised_profit_loss: 0.0,
+            exit_fees_total: amount!(0.0),
+            exit_avg_price_gross: amount!(0.0),
+            exit_value_gross: amount!(0.0),
+            current_symbol_price: amount!(100.0),
+            current_value_gross: amount!(100.0),
+            unrealised_profit_loss: amount!(0.0),
+            realised_profit_loss: amount!(0.0),
+            protective_exits: Default::default(),
+            margin: None,
         }
     }
 }
@@ -1,26 +1,125 @@
 use crate::{
-    portfolio::{position::Position, OrderEvent},
+    portfolio::{position::Position, risk::PortfolioState, OrderEvent},
+    statistic::summary::{pnl::PnLReturnSummary, PositionSummariser},
     strategy::{Decision, SignalStrength},
 };
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::{
+    amount::{Amount, AmountExt},
+    instrument::Instrument,
+    MarketId,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 
 /// Allocates an appropriate [`OrderEvent`] quantity.
 pub trait OrderAllocator {
     /// Returns an [`OrderEvent`] with a calculated order quantity based on the input order,
-    /// [`SignalStrength`] and potential existing [`Position`].
+    /// [`SignalStrength`], potential existing [`Position`] and current [`PortfolioState`].
     fn allocate_order(
         &self,
         order: &mut OrderEvent,
         position: Option<&Position>,
         signal_strength: SignalStrength,
+        state: &PortfolioState,
     );
+
+    /// Update any market derived state used to size orders (eg/ a volatility estimate) using the
+    /// latest [`MarketEvent`]. Allocators without market derived state can rely on the default.
+    fn update_from_market(&mut self, _market: &MarketEvent<Instrument, DataKind>) {}
+
+    /// Update any trade statistics used to size orders (eg/ win rate) using an exited
+    /// [`Position`]. Allocators without trade statistics can rely on the default.
+    fn update_from_exit(&mut self, _position: &Position) {}
+}
+
+/// Exchange rules an [`OrderEvent`] quantity must conform to.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct LotSize {
+    /// Increment the quantity must be a multiple of (eg/ 0.001).
+    pub step_size: f64,
+    /// Minimum quantity of an order.
+    pub min_quantity: f64,
+    /// Minimum notional value (quantity * price) of an order.
+    pub min_notional: f64,
+}
+
+impl Default for LotSize {
+    fn default() -> Self {
+        Self {
+            step_size: 0.0001,
+            min_quantity: 0.0,
+            min_notional: 0.0,
+        }
+    }
+}
+
+impl LotSize {
+    /// Rounds the quantity down to the nearest step_size, returning 0.0 if the result is less
+    /// than the min_quantity or it's value at the provided price is less than the min_notional.
+    pub fn round(&self, quantity: f64, price: f64) -> f64 {
+        // Tolerate float error in quantities that are already an exact multiple of step_size
+        let precision = self.step_size.recip();
+        let rounded = (quantity.abs() * precision + 1e-9).floor() / precision;
+
+        if rounded < self.min_quantity || rounded * price < self.min_notional {
+            return 0.0;
+        }
+
+        rounded.copysign(quantity)
+    }
+}
+
+/// [`LotSize`] of each market, with a default for markets that are not configured.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct LotSizes {
+    pub default: LotSize,
+    pub markets: HashMap<MarketId, LotSize>,
+}
+
+impl LotSizes {
+    /// Returns the [`LotSize`] of the [`OrderEvent`] market.
+    pub fn get(&self, order: &OrderEvent) -> &LotSize {
+        self.markets
+            .get(&MarketId::new(&order.exchange, &order.instrument))
+            .unwrap_or(&self.default)
+    }
+}
+
+/// Sets the quantity of an entry [`OrderEvent`] worth the provided value scaled by the
+/// [`SignalStrength`] & rounded to it's [`LotSize`], or the quantity required to exit the
+/// open [`Position`].
+fn allocate_entry_value(
+    order: &mut OrderEvent,
+    position: Option<&Position>,
+    signal_strength: SignalStrength,
+    lot_sizes: &LotSizes,
+    entry_value: f64,
+) {
+    let entry_quantity = Amount::from_f64_lossy(lot_sizes.get(order).round(
+        entry_value / order.market_meta.close * signal_strength.0,
+        order.market_meta.close,
+    ));
+
+    match order.decision {
+        // Entry
+        Decision::Long => order.quantity = entry_quantity,
+
+        // Entry
+        Decision::Short => order.quantity = -entry_quantity,
+
+        // Exit
+        _ => order.quantity = -position.as_ref().unwrap().quantity,
+    }
 }
 
 /// Default allocation manager that implements [`OrderAllocator`]. Order size is calculated by
 /// using the default_order_value, symbol close value, and [`SignalStrength`].
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
 pub struct DefaultAllocator {
     pub default_order_value: f64,
+    #[serde(default)]
+    pub lot_sizes: LotSizes,
 }
 
 impl OrderAllocator for DefaultAllocator {
@@ -29,40 +128,255 @@ impl OrderAllocator for DefaultAllocator {
         order: &mut OrderEvent,
         position: Option<&Position>,
         signal_strength: SignalStrength,
+        _: &PortfolioState,
+    ) {
+        allocate_entry_value(
+            order,
+            position,
+            signal_strength,
+            &self.lot_sizes,
+            self.default_order_value,
+        )
+    }
+}
+
+/// Fixed-fractional allocation manager that implements [`OrderAllocator`]. Order size is
+/// calculated as a fraction of the current Portfolio equity, scaled by the [`SignalStrength`].
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct FixedFractionAllocator {
+    /// Fraction of equity allocated to each entry in decimal form (eg/ 0.1 for 10%).
+    pub fraction: f64,
+    #[serde(default)]
+    pub lot_sizes: LotSizes,
+}
+
+impl OrderAllocator for FixedFractionAllocator {
+    fn allocate_order(
+        &self,
+        order: &mut OrderEvent,
+        position: Option<&Position>,
+        signal_strength: SignalStrength,
+        state: &PortfolioState,
+    ) {
+        allocate_entry_value(
+            order,
+            position,
+            signal_strength,
+            &self.lot_sizes,
+            state.equity() * self.fraction,
+        )
+    }
+}
+
+/// Volatility targeted allocation manager that implements [`OrderAllocator`]. Order size is
+/// calculated so that an adverse price move of one [`AverageTrueRange`] loses the risk_fraction
+/// of current Portfolio equity, scaled by the [`SignalStrength`].
+///
+/// No entry is allocated for a market until it's [`AverageTrueRange`] has been estimated from
+/// period [`MarketEvent`]s.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct VolatilityTargetAllocator {
+    /// Fraction of equity risked per [`AverageTrueRange`] in decimal form (eg/ 0.01 for 1%).
+    pub risk_fraction: f64,
+    /// Number of true ranges the [`AverageTrueRange`] of each market is estimated over.
+    pub period: usize,
+    #[serde(default)]
+    pub lot_sizes: LotSizes,
+    /// [`AverageTrueRange`] of each market, estimated at runtime from [`MarketEvent`]s.
+    #[serde(skip)]
+    pub volatility: HashMap<MarketId, AverageTrueRange>,
+}
+
+impl OrderAllocator for VolatilityTargetAllocator {
+    fn allocate_order(
+        &self,
+        order: &mut OrderEvent,
+        position: Option<&Position>,
+        signal_strength: SignalStrength,
+        state: &PortfolioState,
     ) {
-        // Calculate exact order_size, then round it to a more appropriate decimal place
-        let default_order_size = self.default_order_value / order.market_meta.close;
-        let default_order_size = (default_order_size * 10000.0).floor() / 10000.0;
+        let entry_value = self
+            .volatility
+            .get(&MarketId::new(&order.exchange, &order.instrument))
+            .and_then(AverageTrueRange::value)
+            .map(|atr| state.equity() * self.risk_fraction / atr * order.market_meta.close)
+            .unwrap_or_default();
+
+        allocate_entry_value(
+            order,
+            position,
+            signal_strength,
+            &self.lot_sizes,
+            entry_value,
+        )
+    }
 
-        match order.decision {
-            // Entry
-            Decision::Long => order.quantity = default_order_size * signal_strength.0,
+    fn update_from_market(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        let (high, low, close) = match &market.kind {
+            DataKind::Trade(trade) => (trade.price, trade.price, trade.price),
+            DataKind::Candle(candle) => (candle.high, candle.low, candle.close),
+            DataKind::OrderBookL1(book_l1) => {
+                let price = book_l1.volume_weighed_mid_price();
+                (price, price, price)
+            }
+            DataKind::OrderBook(book) => match book.volume_weighed_mid_price() {
+                Some(price) => (price, price, price),
+                None => return,
+            },
+            DataKind::Liquidation(_) | DataKind::FundingRate(_) => return,
+        };
 
-            // Entry
-            Decision::Short => order.quantity = -default_order_size * signal_strength.0,
+        self.volatility
+            .entry(MarketId::new(&market.exchange, &market.instrument))
+            .or_insert_with(|| AverageTrueRange::new(self.period))
+            .update(high, low, close);
+    }
+}
+
+/// Rolling average of the true range of a market over a period.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct AverageTrueRange {
+    period: usize,
+    prev_close: Option<f64>,
+    true_ranges: VecDeque<f64>,
+}
+
+impl AverageTrueRange {
+    pub fn new(period: usize) -> Self {
+        Self {
+            period,
+            prev_close: None,
+            true_ranges: VecDeque::with_capacity(period),
+        }
+    }
+
+    /// Updates the [`AverageTrueRange`] with the high, low & close of the next period of
+    /// market data.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) {
+        if let Some(prev_close) = self.prev_close {
+            if self.true_ranges.len() == self.period {
+                self.true_ranges.pop_front();
+            }
+            self.true_ranges
+                .push_back(high.max(prev_close) - low.min(prev_close));
+        }
+        self.prev_close = Some(close);
+    }
+
+    /// Returns the [`AverageTrueRange`] once a full period of true ranges has been observed, if
+    /// it is non-zero.
+    pub fn value(&self) -> Option<f64> {
+        if self.period == 0 || self.true_ranges.len() < self.period {
+            return None;
+        }
+
+        let atr = self.true_ranges.iter().sum::<f64>() / self.period as f64;
+        (atr > 0.0).then_some(atr)
+    }
+}
+
+/// Fractional Kelly allocation manager that implements [`OrderAllocator`]. Order size is
+/// calculated as the kelly_fraction of the Kelly criterion (derived from the running win rate &
+/// payoff ratio of exited [`Position`]s) of current Portfolio equity, scaled by the
+/// [`SignalStrength`].
+///
+/// Until min_trades [`Position`]s have been exited, the default_fraction of equity is allocated.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct KellyAllocator {
+    /// Fraction of the Kelly criterion allocated in decimal form (eg/ 0.5 for half-Kelly).
+    pub kelly_fraction: f64,
+    /// Fraction of equity allocated until min_trades [`Position`]s have been exited.
+    pub default_fraction: f64,
+    /// Number of exited [`Position`]s required before the Kelly criterion is used.
+    pub min_trades: u64,
+    #[serde(default)]
+    pub lot_sizes: LotSizes,
+    /// Running win rate & payoff ratio of exited [`Position`]s, accumulated at runtime.
+    #[serde(skip)]
+    pub summary: PnLReturnSummary,
+}
+
+impl OrderAllocator for KellyAllocator {
+    fn allocate_order(
+        &self,
+        order: &mut OrderEvent,
+        position: Option<&Position>,
+        signal_strength: SignalStrength,
+        state: &PortfolioState,
+    ) {
+        allocate_entry_value(
+            order,
+            position,
+            signal_strength,
+            &self.lot_sizes,
+            state.equity() * self.equity_fraction(),
+        )
+    }
+
+    fn update_from_exit(&mut self, position: &Position) {
+        self.summary.update(position)
+    }
+}
 
-            // Exit
-            _ => order.quantity = 0.0 - position.as_ref().unwrap().quantity,
+impl KellyAllocator {
+    /// Fraction of equity to allocate to the next entry, which is never -ve.
+    pub fn equity_fraction(&self) -> f64 {
+        if self.summary.total.count < self.min_trades {
+            return self.default_fraction;
         }
+
+        let kelly_criterion = match (self.summary.win_rate(), self.summary.payoff_ratio()) {
+            (Some(win_rate), Some(payoff_ratio)) => win_rate - (1.0 - win_rate) / payoff_ratio,
+            // Every exited Position was profitable
+            (Some(1.0), None) => 1.0,
+            _ => 0.0,
+        };
+
+        (kelly_criterion * self.kelly_fraction).max(0.0)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_util::{order_event, position};
+    use crate::{
+        portfolio::Balance,
+        test_util::{market_event_candle, order_event, position},
+    };
+    use barter_data::subscription::candle::Candle;
+    use barter_integration::amount;
+    use chrono::Utc;
+
+    fn state_with_equity(equity: f64) -> PortfolioState {
+        PortfolioState {
+            balance: Balance {
+                time: Utc::now(),
+                total: Amount::from_f64_lossy(equity),
+                available: Amount::from_f64_lossy(equity),
+            },
+            open_positions: vec![],
+        }
+    }
+
+    fn entry_order(decision: Decision, close: f64) -> OrderEvent {
+        let mut order = order_event();
+        order.decision = decision;
+        order.market_meta.close = close;
+        order
+    }
 
     #[test]
     fn should_allocate_order_to_exit_open_long_position() {
         let allocator = DefaultAllocator {
             default_order_value: 1000.0,
+            ..Default::default()
         };
 
         let mut input_order = order_event();
         input_order.decision = Decision::CloseLong;
 
         let mut input_position = position();
-        input_position.quantity = 100.0;
+        input_position.quantity = amount!(100.0);
 
         let input_signal_strength = SignalStrength(0.0);
 
@@ -70,10 +384,11 @@ mod tests {
             &mut input_order,
             Some(&input_position),
             input_signal_strength,
+            &PortfolioState::default(),
         );
 
         let actual_result = input_order.quantity;
-        let expected_result = 0.0 - input_position.quantity;
+        let expected_result = -input_position.quantity;
 
         assert_eq!(actual_result, expected_result)
     }
@@ -82,13 +397,14 @@ mod tests {
     fn should_allocate_order_to_exit_open_short_position() {
         let allocator = DefaultAllocator {
             default_order_value: 1000.0,
+            ..Default::default()
         };
 
         let mut input_order = order_event();
         input_order.decision = Decision::CloseShort;
 
         let mut input_position = position();
-        input_position.quantity = -100.0;
+        input_position.quantity = -amount!(100.0);
 
         let input_signal_strength = SignalStrength(0.0);
 
@@ -96,10 +412,11 @@ mod tests {
             &mut input_order,
             Some(&input_position),
             input_signal_strength,
+            &PortfolioState::default(),
         );
 
         let actual_result = input_order.quantity;
-        let expected_result = 0.0 - input_position.quantity;
+        let expected_result = -input_position.quantity;
 
         assert_eq!(actual_result, expected_result)
     }
@@ -109,6 +426,7 @@ mod tests {
         let default_order_value = 1000.0;
         let allocator = DefaultAllocator {
             default_order_value,
+            ..Default::default()
         };
 
         let order_close = 10.0;
@@ -118,9 +436,14 @@ mod tests {
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            input_signal_strength,
+            &PortfolioState::default(),
+        );
 
-        let actual_result = input_order.quantity;
+        let actual_result = input_order.quantity.to_f64_lossy();
         let expected_result = (default_order_value / order_close) * input_signal_strength.0 as f64;
 
         assert_eq!(actual_result, expected_result)
@@ -131,6 +454,7 @@ mod tests {
         let default_order_value = 200.0;
         let allocator = DefaultAllocator {
             default_order_value,
+            ..Default::default()
         };
 
         let order_close = 226.753403;
@@ -140,9 +464,14 @@ mod tests {
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            input_signal_strength,
+            &PortfolioState::default(),
+        );
 
-        let actual_result = input_order.quantity;
+        let actual_result = input_order.quantity.to_f64_lossy();
         let expected_order_size = ((default_order_value / order_close) * 10000.0).floor() / 10000.0;
         let expected_result = expected_order_size * input_signal_strength.0 as f64;
 
@@ -155,6 +484,7 @@ mod tests {
         let default_order_value = 1000.0;
         let allocator = DefaultAllocator {
             default_order_value,
+            ..Default::default()
         };
 
         let order_close = 10.0;
@@ -164,9 +494,14 @@ mod tests {
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            input_signal_strength,
+            &PortfolioState::default(),
+        );
 
-        let actual_result = input_order.quantity;
+        let actual_result = input_order.quantity.to_f64_lossy();
         let expected_result = -(default_order_value / order_close) * input_signal_strength.0 as f64;
 
         assert_eq!(actual_result, expected_result)
@@ -177,6 +512,7 @@ mod tests {
         let default_order_value = 200.0;
         let allocator = DefaultAllocator {
             default_order_value,
+            ..Default::default()
         };
 
         let order_close = 226.753403;
@@ -186,13 +522,187 @@ mod tests {
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            input_signal_strength,
+            &PortfolioState::default(),
+        );
 
-        let actual_result = input_order.quantity;
+        let actual_result = input_order.quantity.to_f64_lossy();
         let expected_order_size = ((default_order_value / order_close) * 10000.0).floor() / 10000.0;
         let expected_result = -expected_order_size * input_signal_strength.0;
 
         assert_ne!(actual_result, 0.0);
         assert_eq!(actual_result, expected_result)
     }
+
+    #[test]
+    fn lot_size_rounds_quantity_down_to_step_size_and_enforces_minimums() {
+        let lot_size = LotSize {
+            step_size: 0.01,
+            min_quantity: 0.05,
+            min_notional: 10.0,
+        };
+
+        // Rounded down to the step size, preserving sign
+        assert_eq!(lot_size.round(1.239, 100.0), 1.23);
+        assert_eq!(lot_size.round(-1.239, 100.0), -1.23);
+
+        // Quantity already a multiple of the step size is unchanged despite float error
+        assert_eq!(lot_size.round(0.29, 100.0), 0.29);
+
+        // Below min quantity & min notional
+        assert_eq!(lot_size.round(0.049, 1000.0), 0.0);
+        assert_eq!(lot_size.round(0.09, 100.0), 0.0);
+    }
+
+    #[test]
+    fn lot_sizes_uses_market_lot_size_or_default() {
+        let order = order_event();
+        let market_lot_size = LotSize {
+            step_size: 1.0,
+            min_quantity: 0.0,
+            min_notional: 0.0,
+        };
+        let lot_sizes = LotSizes {
+            default: LotSize::default(),
+            markets: HashMap::from([(
+                MarketId::new(&order.exchange, &order.instrument),
+                market_lot_size,
+            )]),
+        };
+
+        assert_eq!(lot_sizes.get(&order), &market_lot_size);
+
+        let mut other_market = order;
+        other_market.exchange = barter_integration::model::Exchange::from("ftx");
+        assert_eq!(lot_sizes.get(&other_market), &LotSize::default());
+    }
+
+    #[test]
+    fn fixed_fraction_allocator_sizes_entry_from_equity() {
+        let allocator = FixedFractionAllocator {
+            fraction: 0.1,
+            ..Default::default()
+        };
+
+        let mut order = entry_order(Decision::Short, 50.0);
+        allocator.allocate_order(
+            &mut order,
+            None,
+            SignalStrength(0.5),
+            &state_with_equity(10_000.0),
+        );
+
+        // 10% of 10,000.0 equity is 1000.0, or 20.0 at 50.0, scaled by SignalStrength
+        assert_eq!(order.quantity, -amount!(10.0));
+    }
+
+    #[test]
+    fn volatility_target_allocator_sizes_entry_once_average_true_range_is_estimated() {
+        let mut allocator = VolatilityTargetAllocator {
+            risk_fraction: 0.01,
+            period: 2,
+            ..Default::default()
+        };
+        let state = state_with_equity(10_000.0);
+
+        let mut order = entry_order(Decision::Long, 100.0);
+        let mut market = market_event_candle();
+        market.exchange = order.exchange.clone();
+        market.instrument = order.instrument.clone();
+
+        let candles = [
+            (100.0, 100.0, 100.0),
+            (104.0, 98.0, 102.0),
+            (103.0, 99.0, 100.0),
+        ];
+        for (index, (high, low, close)) in candles.into_iter().enumerate() {
+            // No entry is allocated before a full period of true ranges has been observed
+            allocator.allocate_order(&mut order, None, SignalStrength(1.0), &state);
+            assert_eq!(order.quantity, amount!(0.0), "candle {} failed", index);
+
+            market.kind = DataKind::Candle(Candle {
+                close_time: Utc::now(),
+                open: close,
+                high,
+                low,
+                close,
+                volume: 1.0,
+                trade_count: 1,
+            });
+            allocator.update_from_market(&market);
+        }
+
+        // True ranges are 6.0 & 4.0, so 1% of equity (100.0) is risked per 5.0 ATR
+        allocator.allocate_order(&mut order, None, SignalStrength(1.0), &state);
+        assert_eq!(order.quantity, amount!(20.0));
+    }
+
+    #[test]
+    fn kelly_allocator_sizes_entry_from_running_win_rate_and_payoff_ratio() {
+        let mut allocator = KellyAllocator {
+            kelly_fraction: 0.5,
+            default_fraction: 0.05,
+            min_trades: 4,
+            ..Default::default()
+        };
+        let state = state_with_equity(10_000.0);
+
+        let exited_position = |realised_profit_loss: f64| Position {
+            enter_value_gross: amount!(100.0),
+            realised_profit_loss: Amount::from_f64_lossy(realised_profit_loss),
+            ..position()
+        };
+
+        // Default fraction is used until min_trades Positions have been exited
+        for realised_profit_loss in [20.0, 20.0, 20.0] {
+            allocator.update_from_exit(&exited_position(realised_profit_loss));
+        }
+        assert_eq!(allocator.equity_fraction(), 0.05);
+
+        // Win rate of 0.75 & payoff ratio of 2.0, so Kelly criterion is 0.625 & half-Kelly 0.3125
+        allocator.update_from_exit(&exited_position(-10.0));
+        assert_eq!(allocator.equity_fraction(), 0.3125);
+
+        let mut order = entry_order(Decision::Long, 125.0);
+        allocator.allocate_order(&mut order, None, SignalStrength(1.0), &state);
+        assert_eq!(order.quantity, amount!(25.0));
+
+        // Negative Kelly criterion allocates nothing
+        for _ in 0..8 {
+            allocator.update_from_exit(&exited_position(-10.0));
+        }
+        assert_eq!(allocator.equity_fraction(), 0.0);
+    }
+
+    #[test]
+    fn allocators_deserialize_from_config_without_runtime_state() {
+        let volatility_target: VolatilityTargetAllocator =
+            serde_json::from_str(r#"{"risk_fraction": 0.01, "period": 14}"#)
+                .expect("failed to deserialize VolatilityTargetAllocator");
+        assert_eq!(
+            volatility_target,
+            VolatilityTargetAllocator {
+                risk_fraction: 0.01,
+                period: 14,
+                ..Default::default()
+            }
+        );
+
+        let kelly: KellyAllocator = serde_json::from_str(
+            r#"{"kelly_fraction": 0.5, "default_fraction": 0.05, "min_trades": 20}"#,
+        )
+        .expect("failed to deserialize KellyAllocator");
+        assert_eq!(
+            kelly,
+            KellyAllocator {
+                kelly_fraction: 0.5,
+                default_fraction: 0.05,
+                min_trades: 20,
+                ..Default::default()
+            }
+        );
+    }
 }
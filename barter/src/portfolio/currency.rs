@@ -0,0 +1,154 @@
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::{symbol::Symbol, Instrument};
+use std::collections::HashMap;
+
+/// Live conversion rates between asset [`Symbol`]s, derived from the latest price of every
+/// market a Portfolio receives [`MarketEvent`]s for. Used to value balances, P&L & statistics
+/// in a Portfolio reporting currency.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ConversionRates {
+    /// Latest price of one unit of the base [`Symbol`] denominated in the quote [`Symbol`].
+    rates: HashMap<(Symbol, Symbol), f64>,
+}
+
+impl ConversionRates {
+    /// Update the base/quote conversion rate of the [`MarketEvent`] [`Instrument`] using it's
+    /// latest price.
+    pub fn update_from_market(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        let price = match &market.kind {
+            DataKind::Trade(trade) => trade.price,
+            DataKind::Candle(candle) => candle.close,
+            DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price(),
+            DataKind::OrderBook(book) => match book.volume_weighed_mid_price() {
+                Some(price) => price,
+                None => return,
+            },
+            DataKind::FundingRate(funding) => funding.mark_price,
+            DataKind::Liquidation(_) => return,
+        };
+
+        self.set_rate(
+            market.instrument.base.clone(),
+            market.instrument.quote.clone(),
+            price,
+        );
+    }
+
+    /// Set the price of one unit of the base [`Symbol`] denominated in the quote [`Symbol`].
+    pub fn set_rate(&mut self, base: Symbol, quote: Symbol, price: f64) {
+        if price.is_finite() && price > 0.0 {
+            self.rates.insert((base, quote), price);
+        }
+    }
+
+    /// Returns the rate that converts an amount of the `from` [`Symbol`] into the `to`
+    /// [`Symbol`], if it can be derived directly, inversely, or via one intermediate [`Symbol`]
+    /// (eg/ EUR -> USDT -> BTC).
+    pub fn rate(&self, from: &Symbol, to: &Symbol) -> Option<f64> {
+        self.direct_rate(from, to).or_else(|| {
+            self.rates
+                .keys()
+                .flat_map(|(base, quote)| [base, quote])
+                .filter(|intermediate| *intermediate != from && *intermediate != to)
+                .find_map(|intermediate| {
+                    Some(
+                        self.direct_rate(from, intermediate)?
+                            * self.direct_rate(intermediate, to)?,
+                    )
+                })
+        })
+    }
+
+    /// Converts an amount of the `from` [`Symbol`] into the `to` [`Symbol`]. See
+    /// [`ConversionRates::rate`].
+    pub fn convert(&self, amount: f64, from: &Symbol, to: &Symbol) -> Option<f64> {
+        self.rate(from, to).map(|rate| amount * rate)
+    }
+
+    /// Returns the rate that converts the `from` [`Symbol`] into the `to` [`Symbol`] without an
+    /// intermediate [`Symbol`].
+    fn direct_rate(&self, from: &Symbol, to: &Symbol) -> Option<f64> {
+        if from == to {
+            return Some(1.0);
+        }
+
+        self.rates
+            .get(&(from.clone(), to.clone()))
+            .copied()
+            .or_else(|| {
+                self.rates
+                    .get(&(to.clone(), from.clone()))
+                    .map(|price| 1.0 / price)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+
+    #[test]
+    fn conversion_rates_direct_inverse_and_via_intermediate() {
+        struct TestCase {
+            from: Symbol,
+            to: Symbol,
+            expected: Option<f64>,
+        }
+
+        let mut rates = ConversionRates::default();
+
+        // BTC/USDT @ 20,000 from a MarketEvent, & EUR/USDT @ 1.25
+        let mut market = market_event_trade(Side::Buy);
+        market.instrument = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        if let DataKind::Trade(ref mut trade) = market.kind {
+            trade.price = 20_000.0;
+        }
+        rates.update_from_market(&market);
+        rates.set_rate(Symbol::new("eur"), Symbol::new("usdt"), 1.25);
+
+        let cases = vec![
+            // TC0: Same Symbol
+            TestCase {
+                from: Symbol::new("usdt"),
+                to: Symbol::new("usdt"),
+                expected: Some(1.0),
+            },
+            // TC1: Direct
+            TestCase {
+                from: Symbol::new("btc"),
+                to: Symbol::new("usdt"),
+                expected: Some(20_000.0),
+            },
+            // TC2: Inverse
+            TestCase {
+                from: Symbol::new("usdt"),
+                to: Symbol::new("btc"),
+                expected: Some(1.0 / 20_000.0),
+            },
+            // TC3: Via the intermediate USDT
+            TestCase {
+                from: Symbol::new("btc"),
+                to: Symbol::new("eur"),
+                expected: Some(16_000.0),
+            },
+            // TC4: Unknown Symbol
+            TestCase {
+                from: Symbol::new("gbp"),
+                to: Symbol::new("usdt"),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = rates.rate(&test.from, &test.to);
+            match (actual, test.expected) {
+                (Some(actual), Some(expected)) => {
+                    assert!((actual - expected).abs() < 1e-9, "TC{} failed", index)
+                }
+                (actual, expected) => assert_eq!(actual, expected, "TC{} failed", index),
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Frequency at which a Portfolio snapshots it's mark-to-market equity (cash + unrealised PnL
+/// across every open [`Position`](crate::portfolio::position::Position)) into an
+/// [`EquityPoint`](crate::statistic::metric::EquityPoint).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum EquitySnapshotFrequency {
+    /// Snapshot on every [`MarketEvent`](barter_data::event::MarketEvent) received.
+    EveryMarketUpdate,
+    /// Snapshot on the first [`MarketEvent`](barter_data::event::MarketEvent) received at least
+    /// the interval (in exchange time) after the previous snapshot.
+    Interval(Duration),
+}
+
+impl EquitySnapshotFrequency {
+    /// Determines if an equity snapshot is due at the provided time, given the time of the
+    /// previous snapshot (if any).
+    pub fn is_due(&self, last_snapshot: Option<DateTime<Utc>>, time: DateTime<Utc>) -> bool {
+        match (self, last_snapshot) {
+            (EquitySnapshotFrequency::EveryMarketUpdate, _) | (_, None) => true,
+            (EquitySnapshotFrequency::Interval(interval), Some(last_snapshot)) => {
+                chrono::Duration::from_std(*interval)
+                    .map(|interval| time >= last_snapshot + interval)
+                    .unwrap_or(false)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equity_snapshot_frequency_is_due() {
+        struct TestCase {
+            frequency: EquitySnapshotFrequency,
+            last_snapshot: Option<DateTime<Utc>>,
+            time: DateTime<Utc>,
+            expected: bool,
+        }
+
+        let base_time = Utc::now();
+
+        let cases = vec![
+            // TC0: Every market update is always due
+            TestCase {
+                frequency: EquitySnapshotFrequency::EveryMarketUpdate,
+                last_snapshot: Some(base_time),
+                time: base_time,
+                expected: true,
+            },
+            // TC1: Interval with no previous snapshot is due
+            TestCase {
+                frequency: EquitySnapshotFrequency::Interval(Duration::from_secs(60)),
+                last_snapshot: None,
+                time: base_time,
+                expected: true,
+            },
+            // TC2: Interval not yet elapsed since previous snapshot is not due
+            TestCase {
+                frequency: EquitySnapshotFrequency::Interval(Duration::from_secs(60)),
+                last_snapshot: Some(base_time),
+                time: base_time + chrono::Duration::seconds(59),
+                expected: false,
+            },
+            // TC3: Interval elapsed since previous snapshot is due
+            TestCase {
+                frequency: EquitySnapshotFrequency::Interval(Duration::from_secs(60)),
+                last_snapshot: Some(base_time),
+                time: base_time + chrono::Duration::seconds(60),
+                expected: true,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.frequency.is_due(test.last_snapshot, test.time);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,4 +1,9 @@
-use crate::portfolio::repository::error::RepositoryError;
+use crate::{
+    engine::policy::ErrorKind,
+    execution::error::ExecutionError,
+    portfolio::{position::PositionId, repository::error::RepositoryError},
+};
+use barter_integration::model::{amount::Amount, instrument::symbol::Symbol, Market};
 use thiserror::Error;
 
 /// All errors generated in the barter::portfolio module.
@@ -16,9 +21,59 @@ pub enum PortfolioError {
     #[error("Cannot exit Position with an entry decision FillEvent.")]
     CannotExitPositionWithEntryFill,
 
+    #[error(
+        "Cannot reduce Position with a FillEvent that does not partially offset it's quantity"
+    )]
+    CannotReducePosition,
+
+    #[error("Cannot flip Position with an exit decision FillEvent that exceeds it's quantity")]
+    CannotFlipPositionWithExitFill,
+
     #[error("Cannot generate PositionExit from Position that has not been exited")]
     PositionExit,
 
     #[error("Failed to interact with repository")]
     RepositoryInteraction(#[from] RepositoryError),
+
+    #[error("Cannot resume Portfolio since no Balance is persisted for engine_id: {0}")]
+    ResumeStateNotPresent(uuid::Uuid),
+
+    #[error("Cannot resume Portfolio with invalid open Position: {0}")]
+    ResumeInvalidPosition(PositionId),
+
+    #[error("Failed to fetch Position quantity from the execution venue due to: {0}")]
+    VenuePosition(#[from] ExecutionError),
+
+    #[error(
+        "Portfolio Position quantity {portfolio_quantity} for {market:?} does not reconcile with \
+         execution venue quantity {venue_quantity}"
+    )]
+    ReconciliationBreak {
+        market: Market,
+        portfolio_quantity: Amount,
+        venue_quantity: Amount,
+    },
+
+    #[error("Cannot convert {from} to {to} since no conversion rate can be derived")]
+    ConversionRateNotPresent { from: Symbol, to: Symbol },
+}
+
+impl ErrorKind for PortfolioError {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::BuilderIncomplete(_) => "BuilderIncomplete",
+            Self::ParseEntrySide => "ParseEntrySide",
+            Self::CannotEnterPositionWithExitFill => "CannotEnterPositionWithExitFill",
+            Self::CannotExitPositionWithEntryFill => "CannotExitPositionWithEntryFill",
+            Self::CannotReducePosition => "CannotReducePosition",
+            Self::CannotFlipPositionWithExitFill => "CannotFlipPositionWithExitFill",
+            Self::PositionExit => "PositionExit",
+            Self::RepositoryInteraction(_) => "RepositoryInteraction",
+            Self::ResumeStateNotPresent(_) => "ResumeStateNotPresent",
+            Self::ResumeInvalidPosition(_) => "ResumeInvalidPosition",
+            Self::VenuePosition(_) => "VenuePosition",
+            Self::ReconciliationBreak { .. } => "ReconciliationBreak",
+            Self::ConversionRateNotPresent { .. } => "ConversionRateNotPresent",
+        }
+    }
 }
@@ -0,0 +1,207 @@
+use barter_data::subscription::funding::FundingRate;
+use barter_integration::model::{
+    amount::{Amount, AmountExt},
+    Side,
+};
+use serde::{Deserialize, Serialize};
+
+/// Margin-aware accounting a [`MetaPortfolio`](crate::portfolio::portfolio::MetaPortfolio)
+/// applies to every [`Position`](crate::portfolio::position::Position) it enters (eg/ when trading
+/// perpetual futures), rather than treating each market like cash spot.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MarginConfig {
+    /// Leverage Positions are entered with, ie/ initial margin = enter value / leverage.
+    pub leverage: f64,
+    /// Proportion of a Position's notional value that must be maintained as margin to avoid
+    /// liquidation.
+    pub maintenance_margin_rate: f64,
+}
+
+impl MarginConfig {
+    /// Derive the [`Margin`] of a new Position on the provided [`Side`] from it's open quantity
+    /// & enter price.
+    pub fn margin(&self, side: Side, quantity: Amount, enter_avg_price_gross: Amount) -> Margin {
+        let mut margin = Margin {
+            leverage: self.leverage,
+            maintenance_margin_rate: self.maintenance_margin_rate,
+            initial_margin: Amount::ZERO,
+            maintenance_margin: Amount::ZERO,
+            liquidation_price: Amount::ZERO,
+            funding_profit_loss: Amount::ZERO,
+            liquidated: false,
+        };
+        margin.update_open(side, quantity, enter_avg_price_gross);
+        margin.update_price(side, quantity, enter_avg_price_gross);
+        margin
+    }
+}
+
+/// Isolated margin state of an open [`Position`](crate::portfolio::position::Position), derived
+/// from a [`MarginConfig`] when it was entered.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Margin {
+    /// Leverage the Position was entered with.
+    pub leverage: f64,
+    /// Proportion of the Position's notional value that must be maintained as margin.
+    pub maintenance_margin_rate: f64,
+    /// Margin committed to the open quantity, ie/ abs(Quantity) * enter_avg_price_gross / leverage.
+    pub initial_margin: Amount,
+    /// Margin required to keep the open quantity open at the current price.
+    pub maintenance_margin: Amount,
+    /// Price at which the initial margin plus unrealised P&L falls to the maintenance margin.
+    pub liquidation_price: Amount,
+    /// Funding received (+ve) or paid (-ve) whilst the Position has been open.
+    pub funding_profit_loss: Amount,
+    /// True once the liquidation price has been breached. Each Position is only liquidated once.
+    pub liquidated: bool,
+}
+
+impl Margin {
+    /// Re-derive the initial margin & liquidation price of the open quantity (eg/ after the
+    /// Position has been increased or reduced).
+    pub fn update_open(&mut self, side: Side, quantity: Amount, enter_avg_price_gross: Amount) {
+        let leverage = Amount::from_f64_lossy(self.leverage);
+        let maintenance_margin_rate = Amount::from_f64_lossy(self.maintenance_margin_rate);
+
+        self.initial_margin = quantity.abs() * enter_avg_price_gross / leverage;
+        self.liquidation_price = match side {
+            Side::Buy => {
+                enter_avg_price_gross * (Amount::ONE - Amount::ONE / leverage)
+                    / (Amount::ONE - maintenance_margin_rate)
+            }
+            Side::Sell => {
+                enter_avg_price_gross * (Amount::ONE + Amount::ONE / leverage)
+                    / (Amount::ONE + maintenance_margin_rate)
+            }
+        };
+    }
+
+    /// Updates the maintenance margin of the open quantity using the latest price, returning
+    /// true if the liquidation price is newly breached.
+    pub fn update_price(&mut self, side: Side, quantity: Amount, price: Amount) -> bool {
+        self.maintenance_margin =
+            quantity.abs() * price * Amount::from_f64_lossy(self.maintenance_margin_rate);
+
+        if self.liquidated {
+            return false;
+        }
+
+        self.liquidated = match side {
+            Side::Buy => price <= self.liquidation_price,
+            Side::Sell => price >= self.liquidation_price,
+        };
+        self.liquidated
+    }
+
+    /// Applies a [`FundingRate`] settlement to the open quantity, returning the funding received
+    /// (+ve) or paid (-ve). Every [`FundingRate`] is assumed to be a realised settlement.
+    pub fn apply_funding(&mut self, side: Side, quantity: Amount, funding: &FundingRate) -> Amount {
+        let notional = quantity.abs() * Amount::from_f64_lossy(funding.mark_price);
+        let rate = Amount::from_f64_lossy(funding.rate);
+        let funding_profit_loss = match side {
+            Side::Buy => -rate * notional,
+            Side::Sell => rate * notional,
+        };
+
+        self.funding_profit_loss += funding_profit_loss;
+        funding_profit_loss
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::amount;
+    use chrono::Utc;
+
+    #[test]
+    fn margin_liquidation_price_and_funding() {
+        struct TestCase {
+            side: Side,
+            funding_rate: f64,
+            expected_initial_margin: Amount,
+            expected_liquidation_price: f64,
+            expected_funding: f64,
+        }
+
+        let config = MarginConfig {
+            leverage: 10.0,
+            maintenance_margin_rate: 0.005,
+        };
+
+        let cases = vec![
+            // TC0: Long pays a positive funding rate
+            TestCase {
+                side: Side::Buy,
+                funding_rate: 0.0001,
+                expected_initial_margin: amount!(200.0),
+                expected_liquidation_price: 100.0 * 0.9 / 0.995,
+                expected_funding: -0.2,
+            },
+            // TC1: Short receives a positive funding rate
+            TestCase {
+                side: Side::Sell,
+                funding_rate: 0.0001,
+                expected_initial_margin: amount!(200.0),
+                expected_liquidation_price: 100.0 * 1.1 / 1.005,
+                expected_funding: 0.2,
+            },
+            // TC2: Short pays a negative funding rate
+            TestCase {
+                side: Side::Sell,
+                funding_rate: -0.0001,
+                expected_initial_margin: amount!(200.0),
+                expected_liquidation_price: 100.0 * 1.1 / 1.005,
+                expected_funding: -0.2,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut margin = config.margin(test.side, amount!(20.0), amount!(100.0));
+            assert_eq!(
+                margin.initial_margin, test.expected_initial_margin,
+                "TC{} failed",
+                index
+            );
+            assert!(
+                (margin.liquidation_price.to_f64_lossy() - test.expected_liquidation_price).abs()
+                    < 1e-9,
+                "TC{} failed",
+                index
+            );
+            assert!(!margin.liquidated, "TC{} failed", index);
+
+            let funding = margin.apply_funding(
+                test.side,
+                amount!(20.0),
+                &FundingRate {
+                    rate: test.funding_rate,
+                    mark_price: 100.0,
+                    time: Utc::now(),
+                },
+            );
+            assert!(
+                (funding.to_f64_lossy() - test.expected_funding).abs() < 1e-9,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(margin.funding_profit_loss, funding, "TC{} failed", index);
+
+            // Breaching the liquidation price liquidates the Position only once
+            let breach = match test.side {
+                Side::Buy => margin.liquidation_price - amount!(0.01),
+                Side::Sell => margin.liquidation_price + amount!(0.01),
+            };
+            assert!(
+                margin.update_price(test.side, amount!(20.0), breach),
+                "TC{} failed",
+                index
+            );
+            assert!(
+                !margin.update_price(test.side, amount!(20.0), breach),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
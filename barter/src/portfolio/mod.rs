@@ -6,7 +6,12 @@ use crate::{
     strategy::{Decision, Signal, SignalForceExit},
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{instrument::Instrument, Exchange};
+use barter_execution::model::ClientOrderId;
+use barter_integration::model::{
+    amount::Amount,
+    instrument::{symbol::Symbol, Instrument},
+    Exchange, Market,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -14,9 +19,19 @@ use uuid::Uuid;
 /// Logic for [`OrderEvent`] quantity allocation.
 pub mod allocator;
 
+/// Live conversion rates used to value multi-currency balances in a reporting currency.
+pub mod currency;
+
+/// Frequency of the mark-to-market equity snapshots that form a Portfolio's equity curve.
+pub mod equity;
+
 /// Barter portfolio module specific errors.
 pub mod error;
 
+/// Margin, leverage & funding accounting for Positions in margined markets (eg/ perpetual
+/// futures).
+pub mod margin;
+
 /// Core Portfolio logic containing an implementation of [`MarketUpdater`],
 /// [`OrderGenerator`] and [`FillUpdater`]. Utilises the risk and allocator logic to optimise
 /// [`OrderEvent`] generation.
@@ -26,6 +41,10 @@ pub mod portfolio;
 /// well as the logic for entering, updating and exiting them.
 pub mod position;
 
+/// Protective exits (stop loss, take profit, trailing stop & maximum holding period) evaluated
+/// every time an open [`Position`](position::Position) is updated.
+pub mod protection;
+
 /// Repositories for persisting Portfolio state.
 pub mod repository;
 
@@ -36,11 +55,21 @@ pub mod risk;
 pub trait MarketUpdater {
     /// Determines if the Portfolio has an open Position relating to the input [`MarketEvent`]. If
     /// so it updates it using the market data, and returns a [`PositionUpdate`] detailing the
-    /// changes.
+    /// changes. If the update triggers one of the Position's protective exits, the
+    /// [`PositionUpdate`] communicates the [`ExitTrigger`](protection::ExitTrigger) so a
+    /// [`SignalForceExit`] can be actioned.
     fn update_from_market(
         &mut self,
         market: &MarketEvent<Instrument, DataKind>,
     ) -> Result<Option<PositionUpdate>, PortfolioError>;
+
+    /// Re-arms the protective exits of the open Position of the provided [`Market`] after the
+    /// exit they triggered failed (eg/ the exit [`OrderEvent`] was rejected), so they trigger
+    /// again on the next [`MarketEvent`]. Portfolios without protective exits can rely on the
+    /// default.
+    fn rearm_protective_exits(&mut self, _market: &Market) -> Result<(), PortfolioError> {
+        Ok(())
+    }
 }
 
 /// May generate an [`OrderEvent`] from an input advisory [`Signal`].
@@ -48,6 +77,15 @@ pub trait OrderGenerator {
     /// May generate an [`OrderEvent`] after analysing an input advisory [`Signal`].
     fn generate_order(&mut self, signal: &Signal) -> Result<Option<OrderEvent>, PortfolioError>;
 
+    /// May generate an [`OrderEvent`] after analysing an input advisory [`Signal`], but only if
+    /// it reduces or exits the open [`Position`](position::Position) of the [`Signal`] market
+    /// (eg/ for a paused [`Trader`](crate::engine::trader::Trader)). A [`Signal`] that would
+    /// flip the open [`Position`](position::Position) only exits it.
+    fn generate_reduce_only_order(
+        &mut self,
+        signal: &Signal,
+    ) -> Result<Option<OrderEvent>, PortfolioError>;
+
     /// Generates an exit [`OrderEvent`] if there is an open [`Position`](position::Position)
     /// associated with the input [`SignalForceExit`]'s [`PositionId`](position::PositionId).
     fn generate_exit_order(
@@ -69,6 +107,9 @@ pub trait FillUpdater {
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct OrderEvent {
     pub time: DateTime<Utc>,
+    /// Client generated identifier that keys every [`OrderUpdate`](crate::execution::OrderUpdate)
+    /// in the lifecycle of this order.
+    pub cid: ClientOrderId,
     pub exchange: Exchange,
     pub instrument: Instrument,
     /// Metadata propagated from source MarketEvent
@@ -76,9 +117,18 @@ pub struct OrderEvent {
     /// LONG, CloseLong, SHORT or CloseShort
     pub decision: Decision,
     /// +ve or -ve Quantity depending on Decision
-    pub quantity: f64,
+    pub quantity: Amount,
     /// MARKET, LIMIT etc
     pub order_type: OrderType,
+    /// Limit price of an [`OrderType::Limit`] order, or of the entry leg of an
+    /// [`OrderType::Bracket`] order. A [`OrderType::Bracket`] without a limit price enters at the
+    /// market price.
+    pub limit_price: Option<Amount>,
+    /// Trigger price of an [`OrderType::Stop`] order, or of the stop loss leg of an
+    /// [`OrderType::Bracket`] order.
+    pub stop_price: Option<Amount>,
+    /// Limit price of the take profit leg of an [`OrderType::Bracket`] order.
+    pub take_profit_price: Option<Amount>,
 }
 
 impl OrderEvent {
@@ -94,8 +144,15 @@ impl OrderEvent {
 /// Type of order the portfolio wants the execution::handler to place.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub enum OrderType {
+    /// Fill immediately at the market price.
     Market,
+    /// Fill at the [`OrderEvent::limit_price`] or better.
     Limit,
+    /// Fill at the market price once it crosses the [`OrderEvent::stop_price`].
+    Stop,
+    /// Enter at the market price (or [`OrderEvent::limit_price`] if provided), then exit via
+    /// whichever of the stop loss ([`OrderEvent::stop_price`]) & take profit
+    /// ([`OrderEvent::take_profit_price`]) legs fills first, cancelling the other.
     Bracket,
 }
 
@@ -109,12 +166,16 @@ impl Default for OrderType {
 #[derive(Debug, Default)]
 pub struct OrderEventBuilder {
     pub time: Option<DateTime<Utc>>,
+    pub cid: Option<ClientOrderId>,
     pub exchange: Option<Exchange>,
     pub instrument: Option<Instrument>,
     pub market_meta: Option<MarketMeta>,
     pub decision: Option<Decision>,
-    pub quantity: Option<f64>,
+    pub quantity: Option<Amount>,
     pub order_type: Option<OrderType>,
+    pub limit_price: Option<Amount>,
+    pub stop_price: Option<Amount>,
+    pub take_profit_price: Option<Amount>,
 }
 
 impl OrderEventBuilder {
@@ -129,6 +190,14 @@ impl OrderEventBuilder {
         }
     }
 
+    /// Client generated identifier of the [`OrderEvent`].
+    pub fn cid(self, value: ClientOrderId) -> Self {
+        Self {
+            cid: Some(value),
+            ..self
+        }
+    }
+
     pub fn exchange(self, value: Exchange) -> Self {
         Self {
             exchange: Some(value),
@@ -157,7 +226,7 @@ impl OrderEventBuilder {
         }
     }
 
-    pub fn quantity(self, value: f64) -> Self {
+    pub fn quantity(self, value: Amount) -> Self {
         Self {
             quantity: Some(value),
             ..self
@@ -171,9 +240,31 @@ impl OrderEventBuilder {
         }
     }
 
+    pub fn limit_price(self, value: Amount) -> Self {
+        Self {
+            limit_price: Some(value),
+            ..self
+        }
+    }
+
+    pub fn stop_price(self, value: Amount) -> Self {
+        Self {
+            stop_price: Some(value),
+            ..self
+        }
+    }
+
+    pub fn take_profit_price(self, value: Amount) -> Self {
+        Self {
+            take_profit_price: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<OrderEvent, PortfolioError> {
         Ok(OrderEvent {
             time: self.time.ok_or(PortfolioError::BuilderIncomplete("time"))?,
+            cid: self.cid.ok_or(PortfolioError::BuilderIncomplete("cid"))?,
             exchange: self
                 .exchange
                 .ok_or(PortfolioError::BuilderIncomplete("exchange"))?,
@@ -192,6 +283,9 @@ impl OrderEventBuilder {
             order_type: self
                 .order_type
                 .ok_or(PortfolioError::BuilderIncomplete("order_type"))?,
+            limit_price: self.limit_price,
+            stop_price: self.stop_price,
+            take_profit_price: self.take_profit_price,
         })
     }
 }
@@ -203,23 +297,23 @@ pub type BalanceId = String;
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Balance {
     pub time: DateTime<Utc>,
-    pub total: f64,
-    pub available: f64,
+    pub total: Amount,
+    pub available: Amount,
 }
 
 impl Default for Balance {
     fn default() -> Self {
         Self {
             time: Utc::now(),
-            total: 0.0,
-            available: 0.0,
+            total: Amount::default(),
+            available: Amount::default(),
         }
     }
 }
 
 impl Balance {
     /// Construct a new [`Balance`] using the provided total & available balance values.
-    pub fn new(time: DateTime<Utc>, total: f64, available: f64) -> Self {
+    pub fn new(time: DateTime<Utc>, total: Amount, available: Amount) -> Self {
         Self {
             time,
             total,
@@ -232,3 +326,29 @@ impl Balance {
         format!("{}_balance", engine_id)
     }
 }
+
+/// [`Balance`] of an individual asset [`Symbol`] held by a Portfolio, denominated in that asset.
+/// Mirrors the barter-execution `SymbolBalance`.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct SymbolBalance {
+    pub symbol: Symbol,
+    pub balance: Balance,
+}
+
+impl SymbolBalance {
+    /// Construct a new [`SymbolBalance`] from a [`Symbol`] and it's associated [`Balance`].
+    pub fn new<S>(symbol: S, balance: Balance) -> Self
+    where
+        S: Into<Symbol>,
+    {
+        Self {
+            symbol: symbol.into(),
+            balance,
+        }
+    }
+
+    /// Returns the unique identifier for an Engine's [`SymbolBalance`]s.
+    pub fn symbol_balances_id(engine_id: Uuid) -> BalanceId {
+        format!("{}_symbol_balances", engine_id)
+    }
+}
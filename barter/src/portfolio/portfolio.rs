@@ -1,38 +1,56 @@
 use super::{
     allocator::OrderAllocator,
+    currency::ConversionRates,
+    equity::EquitySnapshotFrequency,
     error::PortfolioError,
+    margin::MarginConfig,
     position::{
-        determine_position_id, Position, PositionEnterer, PositionExiter, PositionId,
-        PositionUpdate, PositionUpdater,
+        determine_position_id, FillImpact, Position, PositionEnterer, PositionExiter, PositionId,
+        PositionScaler, PositionUpdate, PositionUpdater, QUANTITY_TOLERANCE,
     },
-    repository::{error::RepositoryError, BalanceHandler, PositionHandler, StatisticHandler},
-    risk::OrderEvaluator,
-    Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator, OrderType,
+    protection::ProtectiveExitConfig,
+    repository::{
+        error::RepositoryError, BalanceHandler, EquityHandler, OrderSequenceHandler,
+        PositionHandler, StatisticHandler, StrategySnapshotHandler,
+    },
+    risk::{OrderEvaluator, PortfolioState},
+    Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator, OrderType, SymbolBalance,
 };
 use crate::{
     data::MarketMeta,
+    engine::clock::EngineClock,
     event::Event,
-    execution::FillEvent,
-    statistic::summary::{Initialiser, PositionSummariser},
-    strategy::{Decision, Signal, SignalForceExit, SignalStrength},
+    execution::{ExecutionClient, FillEvent},
+    statistic::{
+        metric::EquityPoint,
+        summary::{Initialiser, PositionSummariser},
+    },
+    strategy::{Decision, Signal, SignalForceExit, SignalStrength, TargetExposure},
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{instrument::Instrument, Market, MarketId, Side};
-use chrono::Utc;
+use barter_execution::model::ClientOrderId;
+use barter_integration::model::{
+    amount::{Amount, AmountExt},
+    instrument::{symbol::Symbol, Instrument},
+    Exchange, Market, MarketId, Side,
+};
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 use std::{collections::HashMap, marker::PhantomData};
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 /// Lego components for constructing & initialising a [`MetaPortfolio`] via the init() constructor
 /// method.
 #[derive(Debug)]
-pub struct PortfolioLego<Repository, Allocator, RiskManager, Statistic>
+pub struct PortfolioLego<Repository, Allocator, RiskManager, Statistic, Clock>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository:
+        PositionHandler + BalanceHandler + StatisticHandler<Statistic> + OrderSequenceHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
+    Clock: EngineClock,
 {
     /// Identifier for the [`Engine`](crate::engine::Engine) a [`MetaPortfolio`] is associated
     /// with (1-to-1 relationship).
@@ -47,25 +65,48 @@ where
     /// Risk manager implements [`OrderEvaluator`].
     pub risk: RiskManager,
     /// Cash balance a [`MetaPortfolio`] starts with.
-    pub starting_cash: f64,
+    pub starting_cash: Amount,
+    /// Cash a [`MetaPortfolio`] with a reporting currency starts with per asset (eg/ USDT & BTC),
+    /// or empty to hold all of the `starting_cash` in the reporting currency. The `starting_cash`
+    /// is the reporting currency value of these balances until they are first settled.
+    pub starting_balances: HashMap<Symbol, Amount>,
     /// Configuration used to initialise the Statistics for every Market's performance tracked by a
     /// [`MetaPortfolio`].
     pub statistic_config: Statistic::Config,
+    /// [`EngineClock`] used to timestamp the [`OrderEvent`]s & [`Balance`]s a [`MetaPortfolio`]
+    /// generates.
+    pub clock: Clock,
+    /// Protective exits applied to every [`Position`] a [`MetaPortfolio`] enters.
+    pub protective_exits: ProtectiveExitConfig,
+    /// Margin-aware accounting applied to every [`Position`] a [`MetaPortfolio`] enters, or None
+    /// to account for every market like cash spot.
+    pub margin: Option<MarginConfig>,
+    /// Currency a [`MetaPortfolio`] holds a [`SymbolBalance`] per asset & values it's [`Balance`]
+    /// & statistics in, or None to treat every quote currency as the same currency. The
+    /// `starting_cash` is denominated in this currency.
+    pub reporting_currency: Option<Symbol>,
+    /// Frequency a [`MetaPortfolio`] snapshots it's mark-to-market equity into it's equity curve
+    /// & statistics, or None to only account for equity when a [`Position`] exits.
+    pub equity_snapshots: Option<EquitySnapshotFrequency>,
     pub _statistic_marker: PhantomData<Statistic>,
 }
 
 /// Portfolio with state persisted in a repository. [`MarketUpdater`], [`OrderGenerator`],
 /// [`FillUpdater`] and [`PositionHandler`].
 #[derive(Debug)]
-pub struct MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+pub struct MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository:
+        PositionHandler + BalanceHandler + StatisticHandler<Statistic> + OrderSequenceHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
+    Clock: EngineClock,
 {
     /// Identifier for the [`Engine`](crate::engine::Engine) this Portfolio is associated with (1-to-1 relationship).
     engine_id: Uuid,
+    /// [`Market`]s being tracked by this Portfolio.
+    markets: Vec<Market>,
     /// Repository for the [`MetaPortfolio`] to persist it's state in. Implements
     /// [`PositionHandler`], [`BalanceHandler`], and [`StatisticHandler`]
     repository: Repository,
@@ -73,46 +114,109 @@ where
     allocation_manager: Allocator,
     /// Risk manager implements [`OrderEvaluator`].
     risk_manager: RiskManager,
+    /// [`EngineClock`] used to timestamp the [`OrderEvent`]s & [`Balance`]s this Portfolio
+    /// generates.
+    clock: Clock,
+    /// Protective exits applied to every [`Position`] this Portfolio enters.
+    protective_exits: ProtectiveExitConfig,
+    /// Margin-aware accounting applied to every [`Position`] this Portfolio enters, or None to
+    /// account for every market like cash spot.
+    margin: Option<MarginConfig>,
+    /// Currency this Portfolio holds a [`SymbolBalance`] per asset & values it's [`Balance`] &
+    /// statistics in, or None to treat every quote currency as the same currency.
+    reporting_currency: Option<Symbol>,
+    /// Live conversion rates between assets, derived from every [`MarketEvent`] received.
+    conversion_rates: ConversionRates,
+    /// Frequency this Portfolio snapshots it's mark-to-market equity into it's equity curve &
+    /// statistics, or None to only account for equity when a [`Position`] exits.
+    equity_snapshots: Option<EquitySnapshotFrequency>,
+    /// Exchange time of the most recent mark-to-market equity snapshot.
+    last_equity_snapshot: Option<DateTime<Utc>>,
+    /// Sequence number of the next [`OrderEvent`] this Portfolio generates, used to derive it's
+    /// deterministic [`ClientOrderId`].
+    order_sequence: u64,
     _statistic_marker: PhantomData<Statistic>,
 }
 
-impl<Repository, Allocator, RiskManager, Statistic> MarketUpdater
-    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+impl<Repository, Allocator, RiskManager, Statistic, Clock> MarketUpdater
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler
+        + BalanceHandler
+        + StatisticHandler<Statistic>
+        + OrderSequenceHandler
+        + EquityHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
+    Clock: EngineClock,
 {
     fn update_from_market(
         &mut self,
         market: &MarketEvent<Instrument, DataKind>,
     ) -> Result<Option<PositionUpdate>, PortfolioError> {
+        // Update any market derived state the Allocator sizes OrderEvents with
+        self.allocation_manager.update_from_market(market);
+
+        // Update the conversion rates used to value balances in the reporting currency
+        self.conversion_rates.update_from_market(market);
+
         // Determine the position_id associated to the input MarketEvent
         let position_id =
             determine_position_id(self.engine_id, &market.exchange, &market.instrument);
 
         // Update Position if Portfolio has an open Position for that Symbol-Exchange combination
+        let mut position_update = None;
         if let Some(mut position) = self.repository.get_open_position(&position_id)? {
             // Derive PositionUpdate event that communicates the open Position's change in state
-            if let Some(position_update) = position.update(market) {
+            let realised_profit_loss_prior = position.realised_profit_loss;
+            if let Some(update) = position.update(market) {
+                // Realise any funding settled by a margined Position in the Portfolio Balance
+                let funding_profit_loss =
+                    position.realised_profit_loss - realised_profit_loss_prior;
+                if funding_profit_loss != Amount::ZERO {
+                    let quote = &market.instrument.quote;
+                    self.validate_conversion_rates(quote)?;
+                    let mut balance = self.settlement_balance(quote, market.exchange_time)?;
+                    balance.total += funding_profit_loss;
+                    balance.available += funding_profit_loss;
+                    self.settle_balance(quote, balance)?;
+                }
+
                 // Save updated open Position in the repository
                 self.repository.set_open_position(position)?;
-                return Ok(Some(position_update));
+                position_update = Some(update);
             }
         }
 
-        Ok(None)
+        // Snapshot the mark-to-market equity of the Portfolio if one is due
+        self.snapshot_equity(market.exchange_time)?;
+
+        Ok(position_update)
+    }
+
+    fn rearm_protective_exits(&mut self, market: &Market) -> Result<(), PortfolioError> {
+        let position_id =
+            determine_position_id(self.engine_id, &market.exchange, &market.instrument);
+
+        if let Some(mut position) = self.repository.get_open_position(&position_id)? {
+            position.rearm_exit_triggers();
+            self.repository.set_open_position(position)?;
+        }
+
+        Ok(())
     }
 }
 
-impl<Repository, Allocator, RiskManager, Statistic> OrderGenerator
-    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+impl<Repository, Allocator, RiskManager, Statistic, Clock> OrderGenerator
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository:
+        PositionHandler + BalanceHandler + StatisticHandler<Statistic> + OrderSequenceHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
+    Clock: EngineClock,
 {
     fn generate_order(&mut self, signal: &Signal) -> Result<Option<OrderEvent>, PortfolioError> {
         // Determine the position_id & associated Option<Position> related to input SignalEvent
@@ -121,10 +225,15 @@ where
         let position = self.repository.get_open_position(&position_id)?;
 
         // If signal is advising to open a new Position rather than close one, check we have cash
-        if position.is_none() && self.no_cash_to_enter_new_position()? {
+        if position.is_none() && self.no_cash_to_enter_new_position(&signal.instrument.quote)? {
             return Ok(None);
         }
 
+        // Rebalance towards the target exposure rather than parsing the net signal decision
+        if let Some(target_exposure) = signal.target_exposure {
+            return self.generate_target_order(signal, target_exposure, position.as_ref(), false);
+        }
+
         // Parse signals from Strategy to determine net signal decision & associated strength
         let position = position.as_ref();
         let (signal_decision, signal_strength) =
@@ -134,22 +243,60 @@ where
             };
 
         // Construct mutable OrderEvent that can be modified by Allocation & Risk management
+        // '--> ClientOrderId is assigned once the OrderEvent is accepted (see Self::assign_cid)
         let mut order = OrderEvent {
-            time: Utc::now(),
+            time: self.clock.time(),
+            cid: ClientOrderId(Uuid::nil()),
             exchange: signal.exchange.clone(),
             instrument: signal.instrument.clone(),
             market_meta: signal.market_meta,
             decision: *signal_decision,
-            quantity: 0.0,
+            quantity: Amount::ZERO,
             order_type: OrderType::default(),
+            limit_price: None,
+            stop_price: None,
+            take_profit_price: None,
         };
 
+        // Snapshot the current Portfolio state for Allocation & Risk management
+        let state = self.portfolio_state(&signal.instrument.quote)?;
+
         // Manage OrderEvent size allocation
         self.allocation_manager
-            .allocate_order(&mut order, position, *signal_strength);
+            .allocate_order(&mut order, position, *signal_strength, &state);
+
+        // If the allocated quantity rounds to zero (eg/ below the min lot size), there is no order
+        if order.quantity == Amount::ZERO {
+            return Ok(None);
+        }
 
         // Manage global risk when evaluating OrderEvent - keep the same, refine or cancel
-        Ok(self.risk_manager.evaluate_order(order))
+        let order = self.risk_manager.evaluate_order(order, &state);
+        self.assign_cid(order)
+    }
+
+    fn generate_reduce_only_order(
+        &mut self,
+        signal: &Signal,
+    ) -> Result<Option<OrderEvent>, PortfolioError> {
+        // Only an open Position can be reduced
+        let position_id =
+            determine_position_id(self.engine_id, &signal.exchange, &signal.instrument);
+        let Some(position) = self.repository.get_open_position(&position_id)? else {
+            return Ok(None);
+        };
+
+        match signal.target_exposure {
+            Some(target_exposure) => {
+                self.generate_target_order(signal, target_exposure, Some(&position), true)
+            }
+            None => {
+                // Only Decisions advising to exit the Position reduce it
+                let mut signal = signal.clone();
+                signal.signals.retain(|decision, _| decision.is_exit());
+                self.generate_order(&signal)
+            }
+        }
     }
 
     fn generate_exit_order(
@@ -173,98 +320,152 @@ where
             Some(position) => position,
         };
 
+        let time = self.clock.time();
         Ok(Some(OrderEvent {
-            time: Utc::now(),
+            time,
+            cid: self.next_cid(&signal.exchange, &signal.instrument, time)?,
             exchange: signal.exchange,
             instrument: signal.instrument,
             market_meta: MarketMeta {
-                close: position.current_symbol_price,
+                close: position.current_symbol_price.to_f64_lossy(),
                 time: position.meta.update_time,
             },
             decision: position.determine_exit_decision(),
-            quantity: 0.0 - position.quantity,
+            quantity: -position.quantity,
             order_type: OrderType::Market,
+            limit_price: None,
+            stop_price: None,
+            take_profit_price: None,
         }))
     }
 }
 
-impl<Repository, Allocator, RiskManager, Statistic> FillUpdater
-    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+impl<Repository, Allocator, RiskManager, Statistic, Clock> FillUpdater
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository:
+        PositionHandler + BalanceHandler + StatisticHandler<Statistic> + OrderSequenceHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser + Serialize,
+    Clock: EngineClock,
 {
     fn update_from_fill(&mut self, fill: &FillEvent) -> Result<Vec<Event>, PortfolioError> {
         // Allocate Vector<Event> to contain any update_from_fill generated events
         let mut generated_events: Vec<Event> = Vec::with_capacity(2);
 
-        // Get the Portfolio Balance from Repository & update timestamp
-        let mut balance = self.repository.get_balance(self.engine_id)?;
-        balance.time = fill.time;
+        // Validate the Balances can be valued before any state is mutated
+        self.validate_conversion_rates(&fill.instrument.quote)?;
+
+        // Get the Balance the FillEvent settles against from Repository & update timestamp
+        let mut balance = self.settlement_balance(&fill.instrument.quote, fill.time)?;
 
         // Determine the position_id that is related to the input FillEvent
         let position_id = determine_position_id(self.engine_id, &fill.exchange, &fill.instrument);
 
         // Determine FillEvent context based on existence or absence of an open Position
-        match self.repository.remove_position(&position_id)? {
-            // EXIT SCENARIO - FillEvent for Symbol-Exchange combination with open Position
-            Some(mut position) => {
-                // Exit Position (in place mutation), & add the PositionExit event to Vec<Event>
-                let position_exit = position.exit(balance, fill)?;
-                generated_events.push(Event::PositionExit(position_exit));
-
-                // Update Portfolio balance on Position exit
-                // '--> available balance adds enter_total_fees since included in result PnL calc
-                balance.available += position.enter_value_gross
-                    + position.realised_profit_loss
-                    + position.enter_fees_total;
-                balance.total += position.realised_profit_loss;
-
-                // Update statistics for exited Position market
-                let market_id = MarketId::new(&fill.exchange, &fill.instrument);
-
-                let mut stats = self.repository.get_statistics(&market_id)?;
-                stats.update(&position);
-
-                // Persist exited Position & Updated Market statistics in Repository
-                self.repository.set_statistics(market_id, stats)?;
-                self.repository
-                    .set_exited_position(self.engine_id, position)?;
-            }
+        // '--> repository is only mutated once the FillEvent has been validated & applied, so an
+        //      invalid FillEvent leaves the open Position untouched
+        match self.repository.get_open_position(&position_id)? {
+            // OPEN POSITION SCENARIO - FillEvent for Symbol-Exchange combination with open Position
+            Some(mut position) => match position.determine_fill_impact(fill) {
+                // INCREASE SCENARIO - FillEvent adds to the open Position
+                FillImpact::Increase => {
+                    let enter_open_prior = position.committed_balance_open();
+                    let position_update = position.increase(fill)?;
+                    generated_events.push(Event::PositionUpdate(position_update));
+
+                    // Update Portfolio Balance.available by the additional enter value & fees
+                    balance.available -= position.committed_balance_open() - enter_open_prior;
+
+                    self.repository.set_open_position(position)?;
+                }
+
+                // REDUCE SCENARIO - FillEvent partially exits the open Position
+                FillImpact::Reduce => {
+                    let enter_open_prior = position.committed_balance_open();
+                    let realised_profit_loss_prior = position.realised_profit_loss;
+                    let position_update = position.reduce(fill)?;
+                    generated_events.push(Event::PositionUpdate(position_update));
+
+                    // Update Portfolio Balance on reduced tranche realisation
+                    // '--> available balance adds tranche enter value & fees since included in PnL
+                    let tranche_profit_loss =
+                        position.realised_profit_loss - realised_profit_loss_prior;
+                    balance.available += enter_open_prior - (position.committed_balance_open())
+                        + tranche_profit_loss;
+                    balance.total += tranche_profit_loss;
+
+                    self.repository.set_open_position(position)?;
+                }
+
+                // EXIT SCENARIO - FillEvent exits the entire open Position
+                // '--> an opposite side entry FillEvent of exactly the open quantity is treated
+                //      as an exit, consistent with the Reduce & Flip scenarios
+                FillImpact::Exit => {
+                    let exit_fill = match fill.decision.is_entry() {
+                        true => FillEvent {
+                            decision: position.determine_exit_decision(),
+                            ..fill.clone()
+                        },
+                        false => fill.clone(),
+                    };
+                    let exited = self.exit_position(
+                        position,
+                        &mut balance,
+                        &exit_fill,
+                        &mut generated_events,
+                    )?;
+                    self.persist_exited_position(exited)?;
+                }
+
+                // FLIP SCENARIO - FillEvent exits the open Position & enters the opposite side
+                FillImpact::Flip => {
+                    if fill.decision.is_exit() {
+                        return Err(PortfolioError::CannotFlipPositionWithExitFill);
+                    }
+
+                    let (exit_fill, enter_fill) = position.split_flip_fill(fill);
+                    let exited = self.exit_position(
+                        position,
+                        &mut balance,
+                        &exit_fill,
+                        &mut generated_events,
+                    )?;
+                    let entered =
+                        self.enter_position(&mut balance, &enter_fill, &mut generated_events)?;
+
+                    self.persist_exited_position(exited)?;
+                    self.repository.set_open_position(entered)?;
+                }
+            },
 
             // ENTRY SCENARIO - FillEvent for Symbol-Exchange with no Position
             None => {
-                // Enter new Position, & add the PositionNew event to Vec<Event>
-                let position = Position::enter(self.engine_id, fill)?;
-                generated_events.push(Event::PositionNew(position.clone()));
-
-                // Update Portfolio Balance.available on Position entry
-                balance.available += -position.enter_value_gross - position.enter_fees_total;
-
-                // Add to current Positions in Repository
-                self.repository.set_open_position(position)?;
+                let entered = self.enter_position(&mut balance, fill, &mut generated_events)?;
+                self.repository.set_open_position(entered)?;
             }
         };
 
+        // Persist updated Portfolio Balance in Repository, valued in the reporting currency
+        let balance = self.settle_balance(&fill.instrument.quote, balance)?;
+
         // Add new Balance event to the Vec<Event>
         generated_events.push(Event::Balance(balance));
 
-        // Persist updated Portfolio Balance in Repository
-        self.repository.set_balance(self.engine_id, balance)?;
-
         Ok(generated_events)
     }
 }
 
-impl<Repository, Allocator, RiskManager, Statistic> PositionHandler
-    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+impl<Repository, Allocator, RiskManager, Statistic, Clock> PositionHandler
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository:
+        PositionHandler + BalanceHandler + StatisticHandler<Statistic> + OrderSequenceHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
+    Clock: EngineClock,
 {
     fn set_open_position(&mut self, position: Position) -> Result<(), RepositoryError> {
         self.repository.set_open_position(position)
@@ -302,13 +503,106 @@ where
     }
 }
 
-impl<Repository, Allocator, RiskManager, Statistic> StatisticHandler<Statistic>
-    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+impl<Repository, Allocator, RiskManager, Statistic, Clock> BalanceHandler
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>
+where
+    Repository:
+        PositionHandler + BalanceHandler + StatisticHandler<Statistic> + OrderSequenceHandler,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser,
+    Clock: EngineClock,
+{
+    fn set_balance(&mut self, _: Uuid, balance: Balance) -> Result<(), RepositoryError> {
+        self.repository.set_balance(self.engine_id, balance)
+    }
+
+    fn get_balance(&mut self, _: Uuid) -> Result<Balance, RepositoryError> {
+        self.repository.get_balance(self.engine_id)
+    }
+
+    fn set_symbol_balance(
+        &mut self,
+        _: Uuid,
+        balance: SymbolBalance,
+    ) -> Result<(), RepositoryError> {
+        self.repository.set_symbol_balance(self.engine_id, balance)
+    }
+
+    fn get_symbol_balances(&mut self, _: Uuid) -> Result<Vec<SymbolBalance>, RepositoryError> {
+        self.repository.get_symbol_balances(self.engine_id)
+    }
+}
+
+impl<Repository, Allocator, RiskManager, Statistic, Clock> StrategySnapshotHandler
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>
+where
+    Repository: PositionHandler
+        + BalanceHandler
+        + StatisticHandler<Statistic>
+        + OrderSequenceHandler
+        + StrategySnapshotHandler,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser,
+    Clock: EngineClock,
+{
+    fn set_strategy_snapshot(
+        &mut self,
+        _: Uuid,
+        market_id: &MarketId,
+        snapshot: serde_json::Value,
+    ) -> Result<(), RepositoryError> {
+        self.repository
+            .set_strategy_snapshot(self.engine_id, market_id, snapshot)
+    }
+
+    fn get_strategy_snapshot(
+        &mut self,
+        _: Uuid,
+        market_id: &MarketId,
+    ) -> Result<Option<serde_json::Value>, RepositoryError> {
+        self.repository
+            .get_strategy_snapshot(self.engine_id, market_id)
+    }
+}
+
+impl<Repository, Allocator, RiskManager, Statistic, Clock> EquityHandler
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>
+where
+    Repository: PositionHandler
+        + BalanceHandler
+        + StatisticHandler<Statistic>
+        + OrderSequenceHandler
+        + EquityHandler,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser,
+    Clock: EngineClock,
+{
+    fn append_equity_point(
+        &mut self,
+        _: Uuid,
+        equity_point: EquityPoint,
+    ) -> Result<(), RepositoryError> {
+        self.repository
+            .append_equity_point(self.engine_id, equity_point)
+    }
+
+    fn get_equity_curve(&mut self, _: Uuid) -> Result<Vec<EquityPoint>, RepositoryError> {
+        self.repository.get_equity_curve(self.engine_id)
+    }
+}
+
+impl<Repository, Allocator, RiskManager, Statistic, Clock> StatisticHandler<Statistic>
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository:
+        PositionHandler + BalanceHandler + StatisticHandler<Statistic> + OrderSequenceHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
+    Clock: EngineClock,
 {
     fn set_statistics(
         &mut self,
@@ -323,39 +617,247 @@ where
     }
 }
 
-impl<Repository, Allocator, RiskManager, Statistic>
-    MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+impl<Repository, Allocator, RiskManager, Statistic, Clock>
+    MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository:
+        PositionHandler + BalanceHandler + StatisticHandler<Statistic> + OrderSequenceHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
+    Clock: EngineClock,
 {
     /// Constructs a new [`MetaPortfolio`] using the provided [`PortfolioLego`] components, and
     /// persists the initial [`MetaPortfolio`] state in the Repository.
     pub fn init(
-        lego: PortfolioLego<Repository, Allocator, RiskManager, Statistic>,
+        lego: PortfolioLego<Repository, Allocator, RiskManager, Statistic, Clock>,
     ) -> Result<Self, PortfolioError> {
         // Construct MetaPortfolio instance
         let mut portfolio = Self {
             engine_id: lego.engine_id,
+            markets: lego.markets,
             repository: lego.repository,
             allocation_manager: lego.allocator,
             risk_manager: lego.risk,
+            clock: lego.clock,
+            protective_exits: lego.protective_exits,
+            margin: lego.margin,
+            reporting_currency: lego.reporting_currency,
+            conversion_rates: ConversionRates::default(),
+            equity_snapshots: lego.equity_snapshots,
+            last_equity_snapshot: None,
+            order_sequence: 0,
             _statistic_marker: PhantomData,
         };
 
         // Persist initial state in the repository
-        portfolio.bootstrap_repository(lego.starting_cash, &lego.markets, lego.statistic_config)?;
+        let markets = portfolio.markets.clone();
+        portfolio.bootstrap_repository(
+            lego.starting_cash,
+            lego.starting_balances,
+            &markets,
+            lego.statistic_config,
+        )?;
 
         Ok(portfolio)
     }
 
+    /// Constructs a [`MetaPortfolio`] using the provided [`PortfolioLego`] components, and resumes
+    /// the state persisted in the Repository by a previous run of the same engine_id (eg/ after a
+    /// crash), rather than overwriting it. The resumed state is reconciled with the provided
+    /// execution venue before it is returned for trading. The `starting_cash` of the
+    /// [`PortfolioLego`] is unused.
+    ///
+    /// See [`MetaPortfolio::resume_repository`] & [`MetaPortfolio::reconcile_venue`].
+    pub fn resume<Execution>(
+        lego: PortfolioLego<Repository, Allocator, RiskManager, Statistic, Clock>,
+        execution: &mut Execution,
+    ) -> Result<Self, PortfolioError>
+    where
+        Execution: ExecutionClient,
+    {
+        // Construct MetaPortfolio instance
+        let mut portfolio = Self {
+            engine_id: lego.engine_id,
+            markets: lego.markets,
+            repository: lego.repository,
+            allocation_manager: lego.allocator,
+            risk_manager: lego.risk,
+            clock: lego.clock,
+            protective_exits: lego.protective_exits,
+            margin: lego.margin,
+            reporting_currency: lego.reporting_currency,
+            conversion_rates: ConversionRates::default(),
+            equity_snapshots: lego.equity_snapshots,
+            last_equity_snapshot: None,
+            order_sequence: 0,
+            _statistic_marker: PhantomData,
+        };
+
+        // Load & validate existing state in the repository, & reconcile it with the venue
+        portfolio.resume_repository(lego.statistic_config)?;
+        portfolio.reconcile_venue(execution)?;
+
+        Ok(portfolio)
+    }
+
+    /// Load & validate the [`MetaPortfolio`] state persisted in the repository for the
+    /// engine_id. The [`Balance`] must be present, and every open [`Position`] of the configured
+    /// [`Market`]s must be valid. Statistics are initialised for any configured [`Market`] that
+    /// has none persisted (eg/ a [`Market`] added to the configuration since the previous run).
+    pub fn resume_repository(
+        &mut self,
+        statistic_config: Statistic::Config,
+    ) -> Result<(), PortfolioError> {
+        // Balance must have been persisted by a previous run
+        let balance = match self.repository.get_balance(self.engine_id) {
+            Ok(balance) => balance,
+            Err(RepositoryError::ExpectedDataNotPresentError) => {
+                return Err(PortfolioError::ResumeStateNotPresent(self.engine_id))
+            }
+            Err(error) => return Err(PortfolioError::RepositoryInteraction(error)),
+        };
+
+        // Initialise Statistics for any newly configured Market
+        for market in self.markets.iter() {
+            let market_id = MarketId::from(market);
+            match self.repository.get_statistics(&market_id) {
+                Ok(_) => {}
+                Err(RepositoryError::ExpectedDataNotPresentError) => {
+                    warn!(
+                        engine_id = %self.engine_id,
+                        market = ?market,
+                        action = "initialising Statistics",
+                        "no Statistics persisted for Market"
+                    );
+                    self.repository.set_statistics(
+                        market_id,
+                        Statistic::init(statistic_config, self.clock.time()),
+                    )?;
+                }
+                Err(error) => return Err(PortfolioError::RepositoryInteraction(error)),
+            }
+        }
+
+        // Continue the order sequence of the previous run
+        self.restore_order_sequence()?;
+
+        // Validate open Positions
+        let open_positions = self
+            .repository
+            .get_open_positions(self.engine_id, self.markets.iter())?;
+        if let Some(invalid) = open_positions.iter().find(|position| {
+            position.quantity == Amount::ZERO
+                || !position.quantity.to_f64_lossy().is_finite()
+                || position.meta.exit_balance.is_some()
+        }) {
+            return Err(PortfolioError::ResumeInvalidPosition(
+                invalid.position_id.clone(),
+            ));
+        }
+
+        info!(
+            engine_id = %self.engine_id,
+            ?balance,
+            open_positions = open_positions.len(),
+            "resumed MetaPortfolio state from repository"
+        );
+
+        Ok(())
+    }
+
+    /// Reconciles the open [`Position`] quantity of the provided [`Market`] with the quantity the
+    /// execution venue holds, returning a [`PortfolioError::ReconciliationBreak`] if they differ.
+    /// Called for every configured [`Market`] when resuming (see [`Self::reconcile_venue`]), and
+    /// should be called for any [`Market`] hot-added (see [`Self::add_market`]) before trading it.
+    /// Reconciliation is skipped if the execution venue cannot report it's Position quantity.
+    pub fn reconcile<Execution>(
+        &mut self,
+        market: &Market,
+        execution: &mut Execution,
+    ) -> Result<(), PortfolioError>
+    where
+        Execution: ExecutionClient,
+    {
+        let Some(venue_quantity) = execution.fetch_position_quantity(market)? else {
+            info!(
+                engine_id = %self.engine_id,
+                market = ?market,
+                why = "execution venue cannot report it's Position quantity",
+                "skipping Position reconciliation"
+            );
+            return Ok(());
+        };
+
+        let position_id =
+            determine_position_id(self.engine_id, &market.exchange, &market.instrument);
+        let portfolio_quantity = self
+            .repository
+            .get_open_position(&position_id)?
+            .map(|position| position.quantity)
+            .unwrap_or(Amount::ZERO);
+
+        let tolerance = portfolio_quantity.abs().max(venue_quantity.abs())
+            * Amount::from_f64_lossy(QUANTITY_TOLERANCE);
+        if (portfolio_quantity - venue_quantity).abs() > tolerance {
+            return Err(PortfolioError::ReconciliationBreak {
+                market: market.clone(),
+                portfolio_quantity,
+                venue_quantity,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Reconciles the open [`Position`] quantity of every configured [`Market`] with the execution
+    /// venue (see [`Self::reconcile`]), and reports any asset the venue holds that is not traded
+    /// by a configured [`Market`] (ie/ a venue Position this Portfolio does not manage).
+    pub fn reconcile_venue<Execution>(
+        &mut self,
+        execution: &mut Execution,
+    ) -> Result<(), PortfolioError>
+    where
+        Execution: ExecutionClient,
+    {
+        let markets = self.markets.clone();
+        for market in markets.iter() {
+            self.reconcile(market, execution)?;
+        }
+
+        let Some(asset_quantities) = execution.fetch_asset_quantities()? else {
+            return Ok(());
+        };
+
+        for (asset, quantity) in asset_quantities {
+            let configured = markets
+                .iter()
+                .any(|market| market.instrument.base == asset || market.instrument.quote == asset);
+            if !configured && quantity != Amount::ZERO {
+                warn!(
+                    engine_id = %self.engine_id,
+                    %asset,
+                    %quantity,
+                    why = "no configured Market trades the asset",
+                    "execution venue holds a Position this Portfolio does not manage"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     /// Persist initial [`MetaPortfolio`] state in the repository. This includes initialised
     /// Statistics every market provided, as well as starting `AvailableCash` & `TotalEquity`.
+    ///
+    /// If the Portfolio has a reporting currency, a starting [`SymbolBalance`] is also persisted
+    /// for every asset of the `starting_balances`, and for every quote asset of the configured
+    /// [`Market`]s. With no `starting_balances`, the `starting_cash` is held in the reporting
+    /// currency.
     pub fn bootstrap_repository<Markets, Id>(
         &mut self,
-        starting_cash: f64,
+        starting_cash: Amount,
+        starting_balances: HashMap<Symbol, Amount>,
         markets: Markets,
         statistic_config: Statistic::Config,
     ) -> Result<(), PortfolioError>
@@ -363,73 +865,573 @@ where
         Markets: IntoIterator<Item = Id>,
         Id: Into<MarketId>,
     {
+        // Continue the order sequence of any previous run, so ClientOrderIds never collide
+        self.restore_order_sequence()?;
+
         // Persist initial Balance (total & available)
-        self.repository.set_balance(
-            self.engine_id,
-            Balance {
-                time: Utc::now(),
-                total: starting_cash,
-                available: starting_cash,
-            },
-        )?;
+        let balance = Balance {
+            time: self.clock.time(),
+            total: starting_cash,
+            available: starting_cash,
+        };
+        self.repository.set_balance(self.engine_id, balance)?;
+
+        // Starting cash is held per asset, or in the reporting currency if not provided
+        if let Some(reporting_currency) = &self.reporting_currency {
+            let mut starting_balances = starting_balances;
+            if starting_balances.is_empty() {
+                starting_balances.insert(reporting_currency.clone(), starting_cash);
+            }
+
+            // Every configured quote asset is held, even if the Portfolio starts without any
+            for market in self.markets.iter() {
+                starting_balances
+                    .entry(market.instrument.quote.clone())
+                    .or_insert(Amount::ZERO);
+            }
+
+            for (symbol, cash) in starting_balances {
+                self.repository.set_symbol_balance(
+                    self.engine_id,
+                    SymbolBalance::new(
+                        symbol,
+                        Balance {
+                            time: balance.time,
+                            total: cash,
+                            available: cash,
+                        },
+                    ),
+                )?;
+            }
+        }
 
         // Persist initial MetaPortfolio Statistics for every Market
         markets.into_iter().try_for_each(|market| {
             self.repository
-                .set_statistics(market.into(), Statistic::init(statistic_config))
+                .set_statistics(
+                    market.into(),
+                    Statistic::init(statistic_config, self.clock.time()),
+                )
                 .map_err(PortfolioError::RepositoryInteraction)
         })
     }
 
+    /// Start tracking a new [`Market`] (eg/ before hot-adding a [`Trader`](crate::engine::trader::Trader)
+    /// for it to a running [`Engine`](crate::engine::Engine)), persisting initialised Statistics
+    /// for it in the repository. Markets that are already tracked are left unchanged.
+    pub fn add_market(
+        &mut self,
+        market: Market,
+        statistic_config: Statistic::Config,
+    ) -> Result<(), PortfolioError> {
+        if self.markets.contains(&market) {
+            return Ok(());
+        }
+
+        self.repository.set_statistics(
+            MarketId::from(&market),
+            Statistic::init(statistic_config, self.clock.time()),
+        )?;
+        self.markets.push(market);
+        Ok(())
+    }
+
     /// Returns a [`MetaPortfolioBuilder`] instance.
-    pub fn builder() -> MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic> {
+    pub fn builder() -> MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic, Clock> {
         MetaPortfolioBuilder::new()
     }
 
-    /// Determines if the Portfolio has any cash to enter a new [`Position`].
-    fn no_cash_to_enter_new_position(&mut self) -> Result<bool, PortfolioError> {
+    /// Enter a new [`Position`] using the entry [`FillEvent`], updating the Portfolio [`Balance`]
+    /// & adding the [`Event::PositionNew`] to the generated [`Event`]s. The entered [`Position`]
+    /// is returned for the caller to persist once the entire [`FillEvent`] has been applied.
+    fn enter_position(
+        &mut self,
+        balance: &mut Balance,
+        fill: &FillEvent,
+        generated_events: &mut Vec<Event>,
+    ) -> Result<Position, PortfolioError> {
+        // Enter new Position with it's protective exits, & add the PositionNew event to Vec<Event>
+        let mut position = Position::enter(self.engine_id, fill)?;
+        position.protective_exits = self.protective_exits.protective_exits(
+            position.side,
+            position.enter_avg_price_gross.to_f64_lossy(),
+            fill.time,
+        );
+        position.margin = self.margin.map(|margin| {
+            margin.margin(
+                position.side,
+                position.quantity,
+                position.enter_avg_price_gross,
+            )
+        });
+        generated_events.push(Event::PositionNew(Box::new(position.clone())));
+
+        // Update Portfolio Balance.available on Position entry by the enter value (or initial
+        // margin if margined) & fees
+        balance.available -= position.committed_balance_open();
+
+        Ok(position)
+    }
+
+    /// Exit an open [`Position`] using the exit [`FillEvent`], updating the Portfolio
+    /// [`Balance`] & adding the [`Event::PositionExit`] to the generated [`Event`]s. The exited
+    /// [`Position`] & updated market statistics are returned for the caller to persist once the
+    /// entire [`FillEvent`] has been applied (see [`Self::persist_exited_position`]).
+    fn exit_position(
+        &mut self,
+        mut position: Position,
+        balance: &mut Balance,
+        fill: &FillEvent,
+        generated_events: &mut Vec<Event>,
+    ) -> Result<ExitedPosition<Statistic>, PortfolioError> {
+        let enter_open_prior = position.committed_balance_open();
+        let realised_profit_loss_prior = position.realised_profit_loss;
+
+        // Exit Position (in place mutation), & add the PositionExit event to Vec<Event>
+        let position_exit = position.exit(*balance, fill)?;
+        generated_events.push(Event::PositionExit(position_exit));
+
+        // Update Portfolio balance on Position exit
+        // '--> available balance adds open enter value & fees since included in result PnL calc
+        let exit_profit_loss = position.realised_profit_loss - realised_profit_loss_prior;
+        balance.available += enter_open_prior + exit_profit_loss;
+        balance.total += exit_profit_loss;
+
+        // Update statistics for exited Position market, valued in the reporting currency
+        // '--> keyed by the same MarketId the statistics were initialised with
+        let market_id = MarketId::from(&Market::<Instrument>::new(
+            fill.exchange.clone(),
+            fill.instrument.clone(),
+        ));
+        let rate = self.reporting_rate(&fill.instrument.quote)?;
+        let mut statistic = self.repository.get_statistics(&market_id)?;
+        statistic.update(&position.converted(rate));
+
+        Ok(ExitedPosition {
+            position,
+            market_id,
+            statistic,
+        })
+    }
+
+    /// Persist an [`ExitedPosition`] in the repository, removing it from the open Positions, and
+    /// update the Allocator trade statistics.
+    fn persist_exited_position(
+        &mut self,
+        exited: ExitedPosition<Statistic>,
+    ) -> Result<(), PortfolioError> {
+        self.allocation_manager.update_from_exit(&exited.position);
+        self.repository
+            .remove_position(&exited.position.position_id)?;
+        self.repository
+            .set_statistics(exited.market_id, exited.statistic)?;
+        self.repository
+            .set_exited_position(self.engine_id, exited.position)?;
+        Ok(())
+    }
+
+    /// Returns the [`Balance`] that [`FillEvent`]s & funding denominated in the provided quote
+    /// currency settle against, timestamped with the provided time. This is the [`SymbolBalance`]
+    /// of the quote currency if the Portfolio has a reporting currency, otherwise the Portfolio
+    /// [`Balance`].
+    fn settlement_balance(
+        &mut self,
+        quote: &Symbol,
+        time: DateTime<Utc>,
+    ) -> Result<Balance, PortfolioError> {
+        let mut balance = match self.reporting_currency {
+            None => self.repository.get_balance(self.engine_id)?,
+            Some(_) => self
+                .repository
+                .get_symbol_balances(self.engine_id)?
+                .into_iter()
+                .find(|symbol_balance| &symbol_balance.symbol == quote)
+                .map(|symbol_balance| symbol_balance.balance)
+                .unwrap_or_default(),
+        };
+
+        balance.time = time;
+        Ok(balance)
+    }
+
+    /// Persist the updated settlement [`Balance`] of the provided quote currency (see
+    /// [`Self::settlement_balance`]), returning the Portfolio [`Balance`] valued in the reporting
+    /// currency using the live [`ConversionRates`].
+    fn settle_balance(
+        &mut self,
+        quote: &Symbol,
+        balance: Balance,
+    ) -> Result<Balance, PortfolioError> {
+        let Some(reporting_currency) = &self.reporting_currency else {
+            self.repository.set_balance(self.engine_id, balance)?;
+            return Ok(balance);
+        };
+
+        self.repository
+            .set_symbol_balance(self.engine_id, SymbolBalance::new(quote.clone(), balance))?;
+
+        // Value every asset Balance in the reporting currency
+        let mut reporting_balance = Balance {
+            time: balance.time,
+            total: Amount::ZERO,
+            available: Amount::ZERO,
+        };
+        for symbol_balance in self.repository.get_symbol_balances(self.engine_id)? {
+            let rate = Amount::from_f64_lossy(
+                self.conversion_rate(&symbol_balance.symbol, reporting_currency)?,
+            );
+            reporting_balance.total += symbol_balance.balance.total * rate;
+            reporting_balance.available += symbol_balance.balance.available * rate;
+        }
+
+        self.repository
+            .set_balance(self.engine_id, reporting_balance)?;
+        Ok(reporting_balance)
+    }
+
+    /// Validates that the provided quote currency, and every asset the Portfolio holds a
+    /// [`SymbolBalance`] of, can be valued in the reporting currency using the live
+    /// [`ConversionRates`]. Called before a [`Balance`] is settled so a missing rate returns a
+    /// [`PortfolioError::ConversionRateNotPresent`] before the repository is mutated.
+    fn validate_conversion_rates(&mut self, quote: &Symbol) -> Result<(), PortfolioError> {
+        let Some(reporting_currency) = self.reporting_currency.clone() else {
+            return Ok(());
+        };
+
+        self.conversion_rate(quote, &reporting_currency)?;
+        for symbol_balance in self.repository.get_symbol_balances(self.engine_id)? {
+            self.conversion_rate(&symbol_balance.symbol, &reporting_currency)?;
+        }
+
+        Ok(())
+    }
+
+    /// Snapshot the current Portfolio state for Allocation & Risk management, with every value
+    /// denominated in the provided quote currency if the Portfolio has a reporting currency.
+    ///
+    /// The [`Balance`] is the one orders in the quote currency settle against (see
+    /// [`Self::settlement_balance`]), so orders are sized on the balance of the quote asset
+    /// itself rather than on the Portfolio [`Balance`] of every asset.
+    fn portfolio_state(&mut self, quote: &Symbol) -> Result<PortfolioState, PortfolioError> {
+        // Orders are only generated if the FillEvents they result in can be valued
+        self.validate_conversion_rates(quote)?;
+
+        let time = self.clock.time();
+        let balance = self.settlement_balance(quote, time)?;
+        let open_positions = self
+            .repository
+            .get_open_positions(self.engine_id, self.markets.iter())?;
+
+        if self.reporting_currency.is_none() {
+            return Ok(PortfolioState {
+                balance,
+                open_positions,
+            });
+        }
+
+        let open_positions = open_positions
+            .into_iter()
+            .map(|position| {
+                self.conversion_rate(&position.instrument.quote, quote)
+                    .map(|rate| position.converted(rate))
+            })
+            .collect::<Result<Vec<Position>, PortfolioError>>()?;
+
+        Ok(PortfolioState {
+            balance,
+            open_positions,
+        })
+    }
+
+    /// Returns the rate that converts the provided currency into the reporting currency, or 1.0
+    /// if the Portfolio has no reporting currency.
+    fn reporting_rate(&self, currency: &Symbol) -> Result<f64, PortfolioError> {
+        match &self.reporting_currency {
+            None => Ok(1.0),
+            Some(reporting_currency) => self.conversion_rate(currency, reporting_currency),
+        }
+    }
+
+    /// Returns the live rate that converts the `from` currency into the `to` currency.
+    fn conversion_rate(&self, from: &Symbol, to: &Symbol) -> Result<f64, PortfolioError> {
+        self.conversion_rates.rate(from, to).ok_or_else(|| {
+            PortfolioError::ConversionRateNotPresent {
+                from: from.clone(),
+                to: to.clone(),
+            }
+        })
+    }
+
+    /// Derive the deterministic [`ClientOrderId`] of the next [`OrderEvent`] this Portfolio
+    /// generates from it's engine_id, the market, the order time & a per-Portfolio sequence
+    /// number. Replaying the same inputs therefore reproduces the same [`ClientOrderId`]s.
+    ///
+    /// The sequence number is persisted in the repository, so a resumed Portfolio continues the
+    /// sequence rather than reusing the [`ClientOrderId`]s of a previous run.
+    fn next_cid(
+        &mut self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+        time: DateTime<Utc>,
+    ) -> Result<ClientOrderId, PortfolioError> {
+        let name = format!(
+            "{}_{}_{}_{}",
+            exchange,
+            instrument,
+            time.timestamp_nanos_opt().unwrap_or_default(),
+            self.order_sequence
+        );
+        self.order_sequence += 1;
+        self.repository
+            .set_order_sequence(self.engine_id, self.order_sequence)?;
+        Ok(ClientOrderId(Uuid::new_v5(
+            &self.engine_id,
+            name.as_bytes(),
+        )))
+    }
+
+    /// Assign the next [`ClientOrderId`] (see [`Self::next_cid`]) to an [`OrderEvent`] accepted
+    /// by Allocation & Risk management. Orders that are dropped or rejected therefore do not
+    /// consume a sequence number.
+    fn assign_cid(
+        &mut self,
+        order: Option<OrderEvent>,
+    ) -> Result<Option<OrderEvent>, PortfolioError> {
+        order
+            .map(|mut order| {
+                order.cid = self.next_cid(&order.exchange, &order.instrument, order.time)?;
+                Ok(order)
+            })
+            .transpose()
+    }
+
+    /// Restore the order sequence number persisted in the repository by a previous run of the
+    /// same engine_id, if any.
+    fn restore_order_sequence(&mut self) -> Result<(), PortfolioError> {
+        self.order_sequence = self
+            .repository
+            .get_order_sequence(self.engine_id)?
+            .unwrap_or_default();
+        Ok(())
+    }
+
+    /// Generate the delta [`OrderEvent`] that moves the open [`Position`] (if any) of the
+    /// [`Signal`] market to the [`TargetExposure`]. The target quantity is allocated by the
+    /// Allocator as the absolute [`TargetExposure`] [`SignalStrength`] of a new entry.
+    ///
+    /// The delta [`OrderEvent`] increases, reduces, exits, or flips the open [`Position`], and
+    /// is None if the open [`Position`] is already at the target quantity. If `reduce_only`, a
+    /// delta that would increase the open [`Position`] is None, and one that would flip it
+    /// only exits it.
+    fn generate_target_order(
+        &mut self,
+        signal: &Signal,
+        target_exposure: TargetExposure,
+        position: Option<&Position>,
+        reduce_only: bool,
+    ) -> Result<Option<OrderEvent>, PortfolioError> {
+        // ClientOrderId is assigned once the OrderEvent is accepted (see Self::assign_cid)
+        let mut order = OrderEvent {
+            time: self.clock.time(),
+            cid: ClientOrderId(Uuid::nil()),
+            exchange: signal.exchange.clone(),
+            instrument: signal.instrument.clone(),
+            market_meta: signal.market_meta,
+            decision: Decision::Long,
+            quantity: Amount::ZERO,
+            order_type: OrderType::default(),
+            limit_price: None,
+            stop_price: None,
+            take_profit_price: None,
+        };
+
+        // Snapshot the current Portfolio state for Allocation & Risk management
+        let state = self.portfolio_state(&signal.instrument.quote)?;
+
+        // Allocate the target quantity as if entering a new Position at the target exposure
+        self.allocation_manager.allocate_order(
+            &mut order,
+            None,
+            SignalStrength(target_exposure.0.abs()),
+            &state,
+        );
+        let target_quantity = match target_exposure.0.is_sign_negative() {
+            true => -order.quantity,
+            false => order.quantity,
+        };
+
+        // Determine the delta quantity between the open & target quantities
+        let open_quantity = position
+            .map(|position| position.quantity)
+            .unwrap_or_default();
+        let mut delta_quantity = target_quantity - open_quantity;
+        if delta_quantity.abs() <= open_quantity.abs() * Amount::from_f64_lossy(QUANTITY_TOLERANCE)
+        {
+            return Ok(None);
+        }
+
+        if reduce_only {
+            // Delta entering or increasing a Position is not a reduction
+            if open_quantity == Amount::ZERO
+                || delta_quantity.is_sign_positive() == open_quantity.is_sign_positive()
+            {
+                return Ok(None);
+            }
+
+            // Delta flipping the Position only exits it
+            if delta_quantity.abs() > open_quantity.abs() {
+                delta_quantity = -open_quantity;
+            }
+        }
+
+        order.quantity = delta_quantity;
+        order.decision = match position {
+            // Delta reduces or exits the open Position without flipping it's side
+            Some(position)
+                if delta_quantity.is_sign_positive() != position.quantity.is_sign_positive()
+                    && delta_quantity.abs() <= position.quantity.abs() =>
+            {
+                position.determine_exit_decision()
+            }
+            // Delta enters, increases, or flips to a Position on the side of the delta
+            _ if delta_quantity.is_sign_positive() => Decision::Long,
+            _ => Decision::Short,
+        };
+
+        // Manage global risk when evaluating OrderEvent - keep the same, refine or cancel
+        let order = self.risk_manager.evaluate_order(order, &state);
+        self.assign_cid(order)
+    }
+
+    /// Determines if the Portfolio has any cash available in the provided quote currency to
+    /// enter a new [`Position`] (see [`Self::settlement_balance`]).
+    fn no_cash_to_enter_new_position(&mut self, quote: &Symbol) -> Result<bool, PortfolioError> {
+        let time = self.clock.time();
+        self.settlement_balance(quote, time)
+            .map(|balance| balance.available <= Amount::ZERO)
+    }
+}
+
+impl<Repository, Allocator, RiskManager, Statistic, Clock>
+    MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>
+where
+    Repository: PositionHandler
+        + BalanceHandler
+        + StatisticHandler<Statistic>
+        + OrderSequenceHandler
+        + EquityHandler,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser,
+    Clock: EngineClock,
+{
+    /// Snapshot the mark-to-market equity of the Portfolio at the provided time if one is due
+    /// according to the configured [`EquitySnapshotFrequency`]. The [`EquityPoint`] is appended to
+    /// the Portfolio level equity curve in the repository. Per [`Market`] statistics are only
+    /// updated by exited [`Position`]s, since the equity of the Portfolio is not attributable to
+    /// any single [`Market`].
+    fn snapshot_equity(&mut self, time: DateTime<Utc>) -> Result<(), PortfolioError> {
+        let Some(frequency) = self.equity_snapshots else {
+            return Ok(());
+        };
+        if !frequency.is_due(self.last_equity_snapshot, time) {
+            return Ok(());
+        }
+
+        let equity_point = self.mark_to_market_equity(time)?;
         self.repository
-            .get_balance(self.engine_id)
-            .map(|balance| balance.available == 0.0)
-            .map_err(PortfolioError::RepositoryInteraction)
+            .append_equity_point(self.engine_id, equity_point)?;
+
+        self.last_equity_snapshot = Some(time);
+        Ok(())
     }
+
+    /// Calculates the mark-to-market equity of the Portfolio at the provided time, which is the
+    /// [`Balance`] total plus the unrealised PnL of every open [`Position`], valued in the
+    /// reporting currency.
+    fn mark_to_market_equity(
+        &mut self,
+        time: DateTime<Utc>,
+    ) -> Result<EquityPoint, PortfolioError> {
+        let balance = self.repository.get_balance(self.engine_id)?;
+        let open_positions = self
+            .repository
+            .get_open_positions(self.engine_id, self.markets.iter())?;
+
+        let unrealised_profit_loss = open_positions
+            .iter()
+            .map(|position| {
+                self.reporting_rate(&position.instrument.quote)
+                    .map(|rate| position.unrealised_profit_loss * Amount::from_f64_lossy(rate))
+            })
+            .sum::<Result<Amount, PortfolioError>>()?;
+
+        Ok(EquityPoint {
+            time,
+            total: (balance.total + unrealised_profit_loss).to_f64_lossy(),
+        })
+    }
+}
+
+/// Exited [`Position`] & the updated statistics of it's market, awaiting persistence by
+/// [`MetaPortfolio::persist_exited_position`].
+struct ExitedPosition<Statistic> {
+    position: Position,
+    market_id: MarketId,
+    statistic: Statistic,
 }
 
 #[derive(Debug, Default)]
-pub struct MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic>
+pub struct MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic, Clock>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository:
+        PositionHandler + BalanceHandler + StatisticHandler<Statistic> + OrderSequenceHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
+    Clock: EngineClock,
 {
     engine_id: Option<Uuid>,
     markets: Option<Vec<Market>>,
-    starting_cash: Option<f64>,
+    starting_cash: Option<Amount>,
+    starting_balances: Option<HashMap<Symbol, Amount>>,
     repository: Option<Repository>,
     allocation_manager: Option<Allocator>,
     risk_manager: Option<RiskManager>,
     statistic_config: Option<Statistic::Config>,
+    clock: Option<Clock>,
+    protective_exits: Option<ProtectiveExitConfig>,
+    margin: Option<MarginConfig>,
+    reporting_currency: Option<Symbol>,
+    equity_snapshots: Option<EquitySnapshotFrequency>,
     _statistic_marker: Option<PhantomData<Statistic>>,
 }
 
-impl<Repository, Allocator, RiskManager, Statistic>
-    MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic>
+impl<Repository, Allocator, RiskManager, Statistic, Clock>
+    MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic, Clock>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository:
+        PositionHandler + BalanceHandler + StatisticHandler<Statistic> + OrderSequenceHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
+    Clock: EngineClock,
 {
     pub fn new() -> Self {
         Self {
             engine_id: None,
             markets: None,
             starting_cash: None,
+            starting_balances: None,
             repository: None,
             allocation_manager: None,
             risk_manager: None,
             statistic_config: None,
+            clock: None,
+            protective_exits: None,
+            margin: None,
+            reporting_currency: None,
+            equity_snapshots: None,
             _statistic_marker: None,
         }
     }
@@ -448,13 +1450,23 @@ where
         }
     }
 
-    pub fn starting_cash(self, value: f64) -> Self {
+    pub fn starting_cash(self, value: Amount) -> Self {
         Self {
             starting_cash: Some(value),
             ..self
         }
     }
 
+    /// Cash the [`MetaPortfolio`] starts with per asset if it has a reporting currency. The
+    /// `starting_cash` is the reporting currency value of these balances. Defaults to holding
+    /// all of the `starting_cash` in the reporting currency.
+    pub fn starting_balances(self, value: HashMap<Symbol, Amount>) -> Self {
+        Self {
+            starting_balances: Some(value),
+            ..self
+        }
+    }
+
     pub fn repository(self, value: Repository) -> Self {
         Self {
             repository: Some(value),
@@ -476,21 +1488,111 @@ where
         }
     }
 
-    pub fn statistic_config(self, value: Statistic::Config) -> Self {
-        Self {
-            statistic_config: Some(value),
-            ..self
-        }
+    pub fn statistic_config(self, value: Statistic::Config) -> Self {
+        Self {
+            statistic_config: Some(value),
+            ..self
+        }
+    }
+
+    pub fn clock(self, value: Clock) -> Self {
+        Self {
+            clock: Some(value),
+            ..self
+        }
+    }
+
+    /// Protective exits applied to every [`Position`] the [`MetaPortfolio`] enters. Defaults to
+    /// no protective exits.
+    pub fn protective_exits(self, value: ProtectiveExitConfig) -> Self {
+        Self {
+            protective_exits: Some(value),
+            ..self
+        }
+    }
+
+    /// Margin-aware accounting applied to every [`Position`] the [`MetaPortfolio`] enters.
+    /// Defaults to accounting for every market like cash spot.
+    pub fn margin(self, value: MarginConfig) -> Self {
+        Self {
+            margin: Some(value),
+            ..self
+        }
+    }
+
+    /// Currency the [`MetaPortfolio`] holds a [`SymbolBalance`] per asset & values it's
+    /// [`Balance`] & statistics in. The `starting_cash` is denominated in this currency. Defaults
+    /// to treating every quote currency as the same currency.
+    pub fn reporting_currency(self, value: Symbol) -> Self {
+        Self {
+            reporting_currency: Some(value),
+            ..self
+        }
+    }
+
+    /// Frequency the [`MetaPortfolio`] snapshots it's mark-to-market equity into it's equity
+    /// curve & statistics. Defaults to only accounting for equity when a [`Position`] exits.
+    pub fn equity_snapshots(self, value: EquitySnapshotFrequency) -> Self {
+        Self {
+            equity_snapshots: Some(value),
+            ..self
+        }
+    }
+
+    pub fn build_and_init(
+        self,
+    ) -> Result<MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>, PortfolioError>
+    {
+        let starting_cash = self.starting_cash;
+        let starting_balances = self.starting_balances.clone().unwrap_or_default();
+        let statistic_config = self.statistic_config;
+        let mut portfolio = self.build()?;
+
+        // Persist initial state in the Repository
+        let markets = portfolio.markets.clone();
+        portfolio.bootstrap_repository(
+            starting_cash.ok_or(PortfolioError::BuilderIncomplete("starting_cash"))?,
+            starting_balances,
+            &markets,
+            statistic_config.ok_or(PortfolioError::BuilderIncomplete("statistic_config"))?,
+        )?;
+
+        Ok(portfolio)
+    }
+
+    /// Builds a [`MetaPortfolio`] that resumes the state persisted in the Repository by a previous
+    /// run of the same engine_id, reconciled with the provided execution venue. See
+    /// [`MetaPortfolio::resume`].
+    pub fn build_and_resume<Execution>(
+        self,
+        execution: &mut Execution,
+    ) -> Result<MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>, PortfolioError>
+    where
+        Execution: ExecutionClient,
+    {
+        let statistic_config = self.statistic_config;
+        let mut portfolio = self.build()?;
+
+        // Load & validate existing state in the Repository, & reconcile it with the venue
+        portfolio.resume_repository(
+            statistic_config.ok_or(PortfolioError::BuilderIncomplete("statistic_config"))?,
+        )?;
+        portfolio.reconcile_venue(execution)?;
+
+        Ok(portfolio)
     }
 
-    pub fn build_and_init(
+    fn build(
         self,
-    ) -> Result<MetaPortfolio<Repository, Allocator, RiskManager, Statistic>, PortfolioError> {
-        // Construct Portfolio
-        let mut portfolio = MetaPortfolio {
+    ) -> Result<MetaPortfolio<Repository, Allocator, RiskManager, Statistic, Clock>, PortfolioError>
+    {
+        Ok(MetaPortfolio {
             engine_id: self
                 .engine_id
                 .ok_or(PortfolioError::BuilderIncomplete("engine_id"))?,
+            markets: self
+                .markets
+                .ok_or(PortfolioError::BuilderIncomplete("markets"))?,
             repository: self
                 .repository
                 .ok_or(PortfolioError::BuilderIncomplete("repository"))?,
@@ -500,21 +1602,18 @@ where
             risk_manager: self
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            clock: self
+                .clock
+                .ok_or(PortfolioError::BuilderIncomplete("clock"))?,
+            protective_exits: self.protective_exits.unwrap_or_default(),
+            margin: self.margin,
+            reporting_currency: self.reporting_currency,
+            conversion_rates: ConversionRates::default(),
+            equity_snapshots: self.equity_snapshots,
+            last_equity_snapshot: None,
+            order_sequence: 0,
             _statistic_marker: PhantomData,
-        };
-
-        // Persist initial state in the Repository
-        portfolio.bootstrap_repository(
-            self.starting_cash
-                .ok_or(PortfolioError::BuilderIncomplete("starting_cash"))?,
-            &self
-                .markets
-                .ok_or(PortfolioError::BuilderIncomplete("markets"))?,
-            self.statistic_config
-                .ok_or(PortfolioError::BuilderIncomplete("statistic_config"))?,
-        )?;
-
-        Ok(portfolio)
+        })
     }
 }
 
@@ -552,19 +1651,29 @@ pub mod tests {
     use super::*;
 
     use crate::{
+        engine::clock::{HistoricalClock, LiveClock},
         execution::Fees,
         portfolio::{
-            allocator::DefaultAllocator, position::PositionBuilder,
-            repository::error::RepositoryError, risk::DefaultRisk,
+            allocator::DefaultAllocator,
+            margin::MarginConfig,
+            position::PositionBuilder,
+            protection::{ExitTrigger, PriceOffset},
+            repository::{error::RepositoryError, in_memory::InMemoryRepository},
+            risk::DefaultRisk,
         },
         statistic::summary::pnl::PnLReturnSummary,
         strategy::SignalForceExit,
         test_util::{fill_event, market_event_trade, position, signal},
     };
-    use barter_integration::model::{
-        instrument::{kind::InstrumentKind, Instrument},
-        Exchange, Side,
+    use barter_data::subscription::funding::FundingRate;
+    use barter_integration::{
+        amount,
+        model::{
+            instrument::{kind::InstrumentKind, Instrument},
+            Exchange, Side,
+        },
     };
+    use chrono::Utc;
 
     #[derive(Default)]
     struct MockRepository<Statistic> {
@@ -581,17 +1690,24 @@ pub mod tests {
         get_exited_positions: Option<fn(engine_id: Uuid) -> Result<Vec<Position>, RepositoryError>>,
         set_balance: Option<fn(engine_id: Uuid, balance: Balance) -> Result<(), RepositoryError>>,
         get_balance: Option<fn(engine_id: Uuid) -> Result<Balance, RepositoryError>>,
+        set_symbol_balance:
+            Option<fn(engine_id: Uuid, balance: SymbolBalance) -> Result<(), RepositoryError>>,
+        get_symbol_balances:
+            Option<fn(engine_id: Uuid) -> Result<Vec<SymbolBalance>, RepositoryError>>,
         set_statistics:
             Option<fn(market_id: MarketId, statistic: Statistic) -> Result<(), RepositoryError>>,
         get_statistics: Option<fn(market_id: &MarketId) -> Result<Statistic, RepositoryError>>,
         position: Option<PositionBuilder>,
         balance: Option<Balance>,
+        equity_curve: Vec<EquityPoint>,
+        order_sequence: Option<u64>,
     }
 
     impl<Statistic> PositionHandler for MockRepository<Statistic> {
         fn set_open_position(&mut self, position: Position) -> Result<(), RepositoryError> {
-            self.position = Some(
-                Position::builder()
+            self.position = Some(PositionBuilder {
+                margin: position.margin,
+                ..Position::builder()
                     .side(position.side.clone())
                     .current_symbol_price(position.current_symbol_price)
                     .current_value_gross(position.current_value_gross)
@@ -602,8 +1718,9 @@ pub mod tests {
                     .exit_value_gross(position.exit_value_gross)
                     .exit_avg_price_gross(position.exit_avg_price_gross)
                     .unrealised_profit_loss(position.unrealised_profit_loss)
-                    .realised_profit_loss(position.realised_profit_loss),
-            );
+                    .realised_profit_loss(position.realised_profit_loss)
+                    .protective_exits(position.protective_exits)
+            });
             self.set_open_position.unwrap()(position)
         }
 
@@ -658,6 +1775,21 @@ pub mod tests {
         fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError> {
             self.get_balance.unwrap()(engine_id)
         }
+
+        fn set_symbol_balance(
+            &mut self,
+            engine_id: Uuid,
+            balance: SymbolBalance,
+        ) -> Result<(), RepositoryError> {
+            self.set_symbol_balance.unwrap()(engine_id, balance)
+        }
+
+        fn get_symbol_balances(
+            &mut self,
+            engine_id: Uuid,
+        ) -> Result<Vec<SymbolBalance>, RepositoryError> {
+            self.get_symbol_balances.unwrap()(engine_id)
+        }
     }
 
     impl<Statistic> StatisticHandler<Statistic> for MockRepository<Statistic> {
@@ -674,36 +1806,79 @@ pub mod tests {
         }
     }
 
+    impl<Statistic> OrderSequenceHandler for MockRepository<Statistic> {
+        fn set_order_sequence(&mut self, _: Uuid, sequence: u64) -> Result<(), RepositoryError> {
+            self.order_sequence = Some(sequence);
+            Ok(())
+        }
+
+        fn get_order_sequence(&mut self, _: Uuid) -> Result<Option<u64>, RepositoryError> {
+            Ok(self.order_sequence)
+        }
+    }
+
+    impl<Statistic> EquityHandler for MockRepository<Statistic> {
+        fn append_equity_point(
+            &mut self,
+            _: Uuid,
+            equity_point: EquityPoint,
+        ) -> Result<(), RepositoryError> {
+            self.equity_curve.push(equity_point);
+            Ok(())
+        }
+
+        fn get_equity_curve(&mut self, _: Uuid) -> Result<Vec<EquityPoint>, RepositoryError> {
+            Ok(self.equity_curve.clone())
+        }
+    }
+
     fn new_mocked_portfolio<Repository, Statistic>(
         mock_repository: Repository,
-    ) -> Result<MetaPortfolio<Repository, DefaultAllocator, DefaultRisk, Statistic>, PortfolioError>
+    ) -> Result<
+        MetaPortfolio<Repository, DefaultAllocator, DefaultRisk, Statistic, LiveClock>,
+        PortfolioError,
+    >
     where
-        Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+        Repository:
+            PositionHandler + BalanceHandler + StatisticHandler<Statistic> + OrderSequenceHandler,
         Statistic: PositionSummariser + Initialiser,
     {
         let builder = MetaPortfolio::builder()
             .engine_id(Uuid::new_v4())
-            .starting_cash(1000.0)
+            .starting_cash(amount!(1000.0))
             .repository(mock_repository)
             .allocation_manager(DefaultAllocator {
                 default_order_value: 100.0,
+                ..Default::default()
             })
-            .risk_manager(DefaultRisk {});
+            .risk_manager(DefaultRisk {})
+            .clock(LiveClock);
 
         build_uninitialised_portfolio(builder)
     }
 
     fn build_uninitialised_portfolio<Repository, Statistic>(
-        builder: MetaPortfolioBuilder<Repository, DefaultAllocator, DefaultRisk, Statistic>,
-    ) -> Result<MetaPortfolio<Repository, DefaultAllocator, DefaultRisk, Statistic>, PortfolioError>
+        builder: MetaPortfolioBuilder<
+            Repository,
+            DefaultAllocator,
+            DefaultRisk,
+            Statistic,
+            LiveClock,
+        >,
+    ) -> Result<
+        MetaPortfolio<Repository, DefaultAllocator, DefaultRisk, Statistic, LiveClock>,
+        PortfolioError,
+    >
     where
-        Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+        Repository:
+            PositionHandler + BalanceHandler + StatisticHandler<Statistic> + OrderSequenceHandler,
         Statistic: PositionSummariser + Initialiser,
     {
         Ok(MetaPortfolio {
             engine_id: builder
                 .engine_id
                 .ok_or(PortfolioError::BuilderIncomplete("engine_id"))?,
+            markets: builder.markets.unwrap_or_default(),
             repository: builder
                 .repository
                 .ok_or(PortfolioError::BuilderIncomplete("repository"))?,
@@ -713,6 +1888,16 @@ pub mod tests {
             risk_manager: builder
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            clock: builder
+                .clock
+                .ok_or(PortfolioError::BuilderIncomplete("clock"))?,
+            protective_exits: builder.protective_exits.unwrap_or_default(),
+            margin: builder.margin,
+            reporting_currency: builder.reporting_currency,
+            conversion_rates: ConversionRates::default(),
+            equity_snapshots: builder.equity_snapshots,
+            last_equity_snapshot: None,
+            order_sequence: 0,
             _statistic_marker: Default::default(),
         })
     }
@@ -733,11 +1918,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Buy;
-                input_position.quantity = 1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = amount!(1.0);
+                input_position.enter_fees_total = amount!(3.0);
+                input_position.current_symbol_price = amount!(100.0);
+                input_position.current_value_gross = amount!(100.0);
+                input_position.unrealised_profit_loss = -amount!(3.0); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -760,17 +1945,201 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 200.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 200.0);
+        assert_eq!(
+            updated_position.current_symbol_price.unwrap(),
+            amount!(200.0)
+        );
+        assert_eq!(
+            updated_position.current_value_gross.unwrap(),
+            amount!(200.0)
+        );
 
         // Unreal PnL Long = current_value_gross - enter_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            200.0 - 100.0 - 6.0
+            amount!(200.0) - amount!(100.0) - amount!(6.0)
         );
         assert_eq!(
             result_pos_update.unrealised_profit_loss,
-            200.0 - 100.0 - 6.0
+            amount!(200.0) - amount!(100.0) - amount!(6.0)
+        );
+    }
+
+    #[test]
+    fn update_from_market_with_long_position_triggering_stop_loss() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| {
+                Ok(Some({
+                    let mut input_position = position();
+                    input_position.side = Side::Buy;
+                    input_position.protective_exits = ProtectiveExitConfig {
+                        stop_loss: Some(PriceOffset::Percent(0.1)),
+                        ..Default::default()
+                    }
+                    .protective_exits(Side::Buy, 100.0, Utc::now());
+                    input_position
+                }))
+            }),
+            set_open_position: Some(|_| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input MarketEvent below the 90.0 stop loss
+        let mut input_market = market_event_trade(Side::Sell);
+        if let DataKind::Trade(ref mut trade) = input_market.kind {
+            trade.price = 85.0;
+        }
+
+        let result_pos_update = portfolio
+            .update_from_market(&input_market)
+            .unwrap()
+            .unwrap();
+        let updated_position = portfolio.repository.position.unwrap();
+
+        assert_eq!(result_pos_update.exit_trigger, Some(ExitTrigger::StopLoss));
+        assert_eq!(
+            updated_position.protective_exits.unwrap().triggered,
+            Some(ExitTrigger::StopLoss)
+        );
+    }
+
+    #[test]
+    fn update_from_market_with_margined_long_position_breaching_liquidation_price() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| {
+                Ok(Some({
+                    let mut input_position = position();
+                    input_position.margin = Some(
+                        MarginConfig {
+                            leverage: 10.0,
+                            maintenance_margin_rate: 0.005,
+                        }
+                        .margin(Side::Buy, amount!(1.0), amount!(100.0)),
+                    );
+                    input_position
+                }))
+            }),
+            set_open_position: Some(|_| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input MarketEvent below the ~90.45 liquidation price
+        let mut input_market = market_event_trade(Side::Sell);
+        if let DataKind::Trade(ref mut trade) = input_market.kind {
+            trade.price = 90.0;
+        }
+
+        let result_pos_update = portfolio
+            .update_from_market(&input_market)
+            .unwrap()
+            .unwrap();
+        let updated_margin = portfolio.repository.position.unwrap().margin.unwrap();
+
+        assert_eq!(
+            result_pos_update.exit_trigger,
+            Some(ExitTrigger::Liquidation)
+        );
+        assert!(updated_margin.liquidated);
+        assert_eq!(
+            updated_margin.maintenance_margin,
+            amount!(90.0) * amount!(0.005)
+        );
+    }
+
+    #[test]
+    fn update_from_market_with_margined_short_position_realises_funding_in_balance() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| {
+                Ok(Some({
+                    let mut input_position = position();
+                    input_position.side = Side::Sell;
+                    input_position.quantity = -amount!(1.0);
+                    input_position.margin = Some(
+                        MarginConfig {
+                            leverage: 10.0,
+                            maintenance_margin_rate: 0.005,
+                        }
+                        .margin(Side::Sell, -amount!(1.0), amount!(100.0)),
+                    );
+                    input_position
+                }))
+            }),
+            set_open_position: Some(|_| Ok(())),
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: amount!(200.0),
+                    available: amount!(190.0),
+                })
+            }),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Short receives the positive funding rate
+        let mut input_market = market_event_trade(Side::Sell);
+        input_market.kind = DataKind::FundingRate(FundingRate {
+            rate: 0.01,
+            mark_price: 100.0,
+            time: input_market.exchange_time,
+        });
+
+        let result_pos_update = portfolio
+            .update_from_market(&input_market)
+            .unwrap()
+            .unwrap();
+        let updated_balance = portfolio.repository.balance.unwrap();
+
+        assert_eq!(result_pos_update.realised_profit_loss, amount!(1.0));
+        assert_eq!(result_pos_update.exit_trigger, None);
+        assert_eq!(updated_balance.total, amount!(201.0));
+        assert_eq!(updated_balance.available, amount!(191.0));
+    }
+
+    #[test]
+    fn update_from_market_with_equity_snapshots_appends_mark_to_market_equity_point() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| Ok(None)),
+            get_open_positions: Some(|_, _| {
+                Ok(vec![{
+                    let mut open_position = position();
+                    open_position.unrealised_profit_loss = -amount!(25.0);
+                    open_position
+                }])
+            }),
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: amount!(1000.0),
+                    available: amount!(900.0),
+                })
+            }),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+        portfolio.equity_snapshots = Some(EquitySnapshotFrequency::Interval(
+            std::time::Duration::from_secs(60),
+        ));
+
+        // First MarketEvent is due a snapshot, second within the interval is not
+        let input_market = market_event_trade(Side::Buy);
+        portfolio.update_from_market(&input_market).unwrap();
+        portfolio.update_from_market(&input_market).unwrap();
+
+        // Equity = Balance.total + unrealised PnL of every open Position
+        assert_eq!(
+            portfolio.repository.equity_curve,
+            vec![EquityPoint {
+                time: input_market.exchange_time,
+                total: 975.0,
+            }]
         );
     }
 
@@ -782,11 +2151,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Buy;
-                input_position.quantity = 1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = amount!(1.0);
+                input_position.enter_fees_total = amount!(3.0);
+                input_position.current_symbol_price = amount!(100.0);
+                input_position.current_value_gross = amount!(100.0);
+                input_position.unrealised_profit_loss = -amount!(3.0); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -808,14 +2177,20 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 50.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 50.0);
+        assert_eq!(
+            updated_position.current_symbol_price.unwrap(),
+            amount!(50.0)
+        );
+        assert_eq!(updated_position.current_value_gross.unwrap(), amount!(50.0));
         // Unreal PnL Long = current_value_gross - enter_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            50.0 - 100.0 - 6.0
+            amount!(50.0) - amount!(100.0) - amount!(6.0)
+        );
+        assert_eq!(
+            result_pos_update.unrealised_profit_loss,
+            amount!(50.0) - amount!(100.0) - amount!(6.0)
         );
-        assert_eq!(result_pos_update.unrealised_profit_loss, 50.0 - 100.0 - 6.0);
     }
 
     #[test]
@@ -826,11 +2201,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Sell;
-                input_position.quantity = -1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = -amount!(1.0);
+                input_position.enter_fees_total = amount!(3.0);
+                input_position.current_symbol_price = amount!(100.0);
+                input_position.current_value_gross = amount!(100.0);
+                input_position.unrealised_profit_loss = -amount!(3.0); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -853,14 +2228,20 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 50.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 50.0);
+        assert_eq!(
+            updated_position.current_symbol_price.unwrap(),
+            amount!(50.0)
+        );
+        assert_eq!(updated_position.current_value_gross.unwrap(), amount!(50.0));
         // Unreal PnL Short = enter_value_gross - current_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            100.0 - 50.0 - 6.0
+            amount!(100.0) - amount!(50.0) - amount!(6.0)
+        );
+        assert_eq!(
+            result_pos_update.unrealised_profit_loss,
+            amount!(100.0) - amount!(50.0) - amount!(6.0)
         );
-        assert_eq!(result_pos_update.unrealised_profit_loss, 100.0 - 50.0 - 6.0);
     }
 
     #[test]
@@ -871,11 +2252,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Sell;
-                input_position.quantity = -1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = -amount!(1.0);
+                input_position.enter_fees_total = amount!(3.0);
+                input_position.current_symbol_price = amount!(100.0);
+                input_position.current_value_gross = amount!(100.0);
+                input_position.unrealised_profit_loss = -amount!(3.0); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -898,16 +2279,22 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 200.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 200.0);
+        assert_eq!(
+            updated_position.current_symbol_price.unwrap(),
+            amount!(200.0)
+        );
+        assert_eq!(
+            updated_position.current_value_gross.unwrap(),
+            amount!(200.0)
+        );
         // Unreal PnL Short = enter_value_gross - current_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            100.0 - 200.0 - 6.0
+            amount!(100.0) - amount!(200.0) - amount!(6.0)
         );
         assert_eq!(
             result_pos_update.unrealised_profit_loss,
-            100.0 - 200.0 - 6.0
+            amount!(100.0) - amount!(200.0) - amount!(6.0)
         );
     }
 
@@ -919,8 +2306,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 0.0,
+                total: amount!(100.0),
+                available: amount!(0.0),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -941,8 +2328,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 0.0,
+                total: amount!(100.0),
+                available: amount!(0.0),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -963,10 +2350,11 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: amount!(100.0),
+                available: amount!(100.0),
             })
         });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input SignalEvent
@@ -980,6 +2368,373 @@ pub mod tests {
         assert_eq!(actual.decision, Decision::Long)
     }
 
+    #[test]
+    fn generate_order_derives_deterministic_client_order_ids() {
+        let engine_id = Uuid::new_v4();
+        let start_time = Utc::now();
+        let btc_usdt = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+
+        let mut input_signal = signal();
+        input_signal.market_meta.close = 100.0;
+        input_signal
+            .signals
+            .insert(Decision::Long, SignalStrength(1.0));
+
+        let generate_cids = || {
+            let mut portfolio = MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![btc_usdt.clone()])
+                .starting_cash(amount!(1000.0))
+                .repository(InMemoryRepository::<PnLReturnSummary>::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: 100.0,
+                    ..Default::default()
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(())
+                .clock(HistoricalClock::new(start_time))
+                .build_and_init()
+                .unwrap();
+
+            (0..2)
+                .map(|_| {
+                    portfolio
+                        .generate_order(&input_signal)
+                        .unwrap()
+                        .unwrap()
+                        .cid
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let first_run = generate_cids();
+        let second_run = generate_cids();
+
+        // Replaying the same inputs reproduces the same ClientOrderIds
+        assert_eq!(first_run, second_run);
+
+        // Every OrderEvent generated by a Portfolio has a unique ClientOrderId
+        assert_ne!(first_run[0], first_run[1]);
+    }
+
+    #[test]
+    fn build_and_resume_continues_order_sequence_of_previous_run() {
+        let engine_id = Uuid::new_v4();
+        let start_time = Utc::now();
+        let btc_usdt = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+
+        let mut input_signal = signal();
+        input_signal.market_meta.close = 100.0;
+        input_signal
+            .signals
+            .insert(Decision::Long, SignalStrength(1.0));
+
+        let builder = |repository| {
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![btc_usdt.clone()])
+                .starting_cash(amount!(1000.0))
+                .repository(repository)
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: 100.0,
+                    ..Default::default()
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(())
+                .clock(HistoricalClock::new(start_time))
+        };
+
+        // Previous run generated one OrderEvent at the same time
+        let mut previous_run = builder(InMemoryRepository::<PnLReturnSummary>::new())
+            .build_and_init()
+            .unwrap();
+        let previous_cid = previous_run
+            .generate_order(&input_signal)
+            .unwrap()
+            .unwrap()
+            .cid;
+
+        // Persisted state of the previous run
+        let mut repository = InMemoryRepository::<PnLReturnSummary>::new();
+        repository
+            .set_balance(engine_id, previous_run.get_balance(engine_id).unwrap())
+            .unwrap();
+        repository
+            .set_statistics(MarketId::from(&btc_usdt), PnLReturnSummary::default())
+            .unwrap();
+        repository.set_order_sequence(engine_id, 1).unwrap();
+
+        let mut resumed = builder(repository)
+            .build_and_resume(&mut VenueExecution::default())
+            .unwrap();
+        let resumed_cid = resumed.generate_order(&input_signal).unwrap().unwrap().cid;
+
+        assert_ne!(resumed_cid, previous_cid);
+    }
+
+    #[test]
+    fn generate_order_only_consumes_order_sequence_for_returned_orders() {
+        let engine_id = Uuid::new_v4();
+        let start_time = Utc::now();
+        let btc_usdt = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+
+        let mut input_signal = signal();
+        input_signal
+            .signals
+            .insert(Decision::Long, SignalStrength(1.0));
+
+        let build = || {
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![btc_usdt.clone()])
+                .starting_cash(amount!(1000.0))
+                .repository(InMemoryRepository::<PnLReturnSummary>::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: 100.0,
+                    ..Default::default()
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(())
+                .clock(HistoricalClock::new(start_time))
+                .build_and_init()
+                .unwrap()
+        };
+
+        // Allocated quantity rounds to zero, so no OrderEvent is generated
+        let mut portfolio = build();
+        input_signal.market_meta.close = 1_000_000_000.0;
+        assert_eq!(portfolio.generate_order(&input_signal).unwrap(), None);
+        assert_eq!(
+            portfolio.repository.get_order_sequence(engine_id).unwrap(),
+            None
+        );
+
+        // Next OrderEvent has the ClientOrderId of the first OrderEvent of a fresh Portfolio
+        input_signal.market_meta.close = 100.0;
+        let cid = portfolio
+            .generate_order(&input_signal)
+            .unwrap()
+            .unwrap()
+            .cid;
+        let fresh_cid = build().generate_order(&input_signal).unwrap().unwrap().cid;
+
+        assert_eq!(cid, fresh_cid);
+        assert_eq!(
+            portfolio.repository.get_order_sequence(engine_id).unwrap(),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn generate_order_with_target_exposure_moves_open_position_to_target() {
+        struct TestCase {
+            open_quantity: Option<f64>,
+            target_exposure: f64,
+            expected: Option<(Decision, f64)>,
+        }
+
+        let cases = vec![
+            // TC0: No Position & long target enters Long
+            TestCase {
+                open_quantity: None,
+                target_exposure: 0.75,
+                expected: Some((Decision::Long, 0.75)),
+            },
+            // TC1: No Position & short target enters Short
+            TestCase {
+                open_quantity: None,
+                target_exposure: -0.25,
+                expected: Some((Decision::Short, -0.25)),
+            },
+            // TC2: Long Position below target increases Long
+            TestCase {
+                open_quantity: Some(0.5),
+                target_exposure: 0.75,
+                expected: Some((Decision::Long, 0.25)),
+            },
+            // TC3: Long Position above target reduces Long
+            TestCase {
+                open_quantity: Some(1.0),
+                target_exposure: 0.5,
+                expected: Some((Decision::CloseLong, -0.5)),
+            },
+            // TC4: Long Position & flat target exits Long
+            TestCase {
+                open_quantity: Some(1.0),
+                target_exposure: 0.0,
+                expected: Some((Decision::CloseLong, -1.0)),
+            },
+            // TC5: Long Position & short target flips to Short
+            TestCase {
+                open_quantity: Some(1.0),
+                target_exposure: -0.5,
+                expected: Some((Decision::Short, -1.5)),
+            },
+            // TC6: Short Position at target generates no order
+            TestCase {
+                open_quantity: Some(-0.5),
+                target_exposure: -0.5,
+                expected: None,
+            },
+            // TC7: Short Position & long target flips to Long
+            TestCase {
+                open_quantity: Some(-1.0),
+                target_exposure: 0.25,
+                expected: Some((Decision::Long, 1.25)),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let engine_id = Uuid::new_v4();
+            let mut portfolio = MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![])
+                .starting_cash(amount!(1000.0))
+                .repository(InMemoryRepository::<PnLReturnSummary>::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: 100.0,
+                    ..Default::default()
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(())
+                .clock(LiveClock)
+                .build_and_init()
+                .unwrap();
+
+            // Input SignalEvent advising the target exposure, where a full exposure is 1.0
+            let mut input_signal = signal();
+            input_signal.target_exposure = Some(TargetExposure(test.target_exposure));
+
+            if let Some(open_quantity) = test.open_quantity {
+                let mut open_position = position();
+                open_position.position_id = determine_position_id(
+                    engine_id,
+                    &input_signal.exchange,
+                    &input_signal.instrument,
+                );
+                open_position.exchange = input_signal.exchange.clone();
+                open_position.instrument = input_signal.instrument.clone();
+                open_position.side = match open_quantity.is_sign_negative() {
+                    true => Side::Sell,
+                    false => Side::Buy,
+                };
+                open_position.quantity = Amount::from_f64_lossy(open_quantity);
+                portfolio
+                    .repository
+                    .set_open_position(open_position)
+                    .unwrap();
+            }
+
+            let actual = portfolio
+                .generate_order(&input_signal)
+                .unwrap()
+                .map(|order| (order.decision, order.quantity));
+            let expected = test
+                .expected
+                .map(|(decision, quantity)| (decision, Amount::from_f64_lossy(quantity)));
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn generate_reduce_only_order_with_target_exposure_only_reduces_open_position() {
+        struct TestCase {
+            open_quantity: Option<f64>,
+            target_exposure: f64,
+            expected: Option<(Decision, f64)>,
+        }
+
+        let cases = vec![
+            // TC0: No Position to reduce generates no order
+            TestCase {
+                open_quantity: None,
+                target_exposure: 0.75,
+                expected: None,
+            },
+            // TC1: Long Position below target is not increased
+            TestCase {
+                open_quantity: Some(0.5),
+                target_exposure: 0.75,
+                expected: None,
+            },
+            // TC2: Long Position above target reduces Long
+            TestCase {
+                open_quantity: Some(1.0),
+                target_exposure: 0.5,
+                expected: Some((Decision::CloseLong, -0.5)),
+            },
+            // TC3: Long Position & flat target exits Long
+            TestCase {
+                open_quantity: Some(1.0),
+                target_exposure: 0.0,
+                expected: Some((Decision::CloseLong, -1.0)),
+            },
+            // TC4: Long Position & short target only exits Long
+            TestCase {
+                open_quantity: Some(1.0),
+                target_exposure: -0.5,
+                expected: Some((Decision::CloseLong, -1.0)),
+            },
+            // TC5: Short Position above target reduces Short
+            TestCase {
+                open_quantity: Some(-1.0),
+                target_exposure: -0.25,
+                expected: Some((Decision::CloseShort, 0.75)),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let engine_id = Uuid::new_v4();
+            let mut portfolio = MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![])
+                .starting_cash(amount!(1000.0))
+                .repository(InMemoryRepository::<PnLReturnSummary>::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: 100.0,
+                    ..Default::default()
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(())
+                .clock(LiveClock)
+                .build_and_init()
+                .unwrap();
+
+            // Input SignalEvent advising the target exposure, where a full exposure is 1.0
+            let mut input_signal = signal();
+            input_signal.target_exposure = Some(TargetExposure(test.target_exposure));
+
+            if let Some(open_quantity) = test.open_quantity {
+                let mut open_position = position();
+                open_position.position_id = determine_position_id(
+                    engine_id,
+                    &input_signal.exchange,
+                    &input_signal.instrument,
+                );
+                open_position.exchange = input_signal.exchange.clone();
+                open_position.instrument = input_signal.instrument.clone();
+                open_position.side = match open_quantity.is_sign_negative() {
+                    true => Side::Sell,
+                    false => Side::Buy,
+                };
+                open_position.quantity = Amount::from_f64_lossy(open_quantity);
+                portfolio
+                    .repository
+                    .set_open_position(open_position)
+                    .unwrap();
+            }
+
+            let actual = portfolio
+                .generate_reduce_only_order(&input_signal)
+                .unwrap()
+                .map(|order| (order.decision, order.quantity));
+            let expected = test
+                .expected
+                .map(|(decision, quantity)| (decision, Amount::from_f64_lossy(quantity)));
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+
     #[test]
     fn generate_order_short_with_no_position_and_input_net_short_signal() {
         // Build Portfolio
@@ -988,10 +2743,11 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: amount!(100.0),
+                available: amount!(100.0),
             })
         });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input SignalEvent
@@ -1020,10 +2776,11 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: amount!(100.0),
+                available: amount!(100.0),
             })
         });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input SignalEvent
@@ -1052,10 +2809,11 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: amount!(100.0),
+                available: amount!(100.0),
             })
         });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input SignalEvent
@@ -1078,7 +2836,7 @@ pub mod tests {
             Ok(Some({
                 let mut position = position();
                 position.side = Side::Buy;
-                position.quantity = 100.0;
+                position.quantity = amount!(100.0);
                 position
             }))
         });
@@ -1094,7 +2852,7 @@ pub mod tests {
             .unwrap();
 
         assert_eq!(actual.decision, Decision::CloseLong);
-        assert_eq!(actual.quantity, -100.0);
+        assert_eq!(actual.quantity, -amount!(100.0));
         assert_eq!(actual.order_type, OrderType::Market)
     }
 
@@ -1106,7 +2864,7 @@ pub mod tests {
             Ok(Some({
                 let mut position = position();
                 position.side = Side::Sell;
-                position.quantity = -100.0;
+                position.quantity = -amount!(100.0);
                 position
             }))
         });
@@ -1122,7 +2880,7 @@ pub mod tests {
             .unwrap();
 
         assert_eq!(actual.decision, Decision::CloseShort);
-        assert_eq!(actual.quantity, 100.0);
+        assert_eq!(actual.quantity, amount!(100.0));
         assert_eq!(actual.order_type, OrderType::Market)
     }
 
@@ -1148,11 +2906,11 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 200.0,
+                total: amount!(200.0),
+                available: amount!(200.0),
             })
         });
-        mock_repository.remove_position = Some(|_| Ok(None));
+        mock_repository.get_open_position = Some(|_| Ok(None));
         mock_repository.set_open_position = Some(|_| Ok(()));
         mock_repository.set_balance = Some(|_, _| Ok(()));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -1160,24 +2918,111 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = amount!(1.0);
+        input_fill.fill_value_gross = amount!(100.0);
+        input_fill.fees = Fees {
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
+        };
+
+        let result = portfolio.update_from_fill(&input_fill);
+        let updated_repository = portfolio.repository;
+        let entered_position = updated_repository.position.unwrap();
+        let updated_cash = updated_repository.balance.unwrap().available;
+
+        assert!(result.is_ok());
+        assert_eq!(entered_position.side.unwrap(), Side::Buy);
+        assert_eq!(entered_position.enter_value_gross.unwrap(), amount!(100.0));
+        assert_eq!(entered_position.enter_fees_total.unwrap(), amount!(3.0));
+        assert_eq!(updated_cash, amount!(200.0) - amount!(100.0) - amount!(3.0));
+        // cash += enter_value_gross - enter_fees
+    }
+
+    #[test]
+    fn update_from_fill_entering_long_position_with_protective_exits() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: amount!(200.0),
+                    available: amount!(200.0),
+                })
+            }),
+            get_open_position: Some(|_| Ok(None)),
+            set_open_position: Some(|_| Ok(())),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+        portfolio.protective_exits = ProtectiveExitConfig {
+            stop_loss: Some(PriceOffset::Price(10.0)),
+            take_profit: Some(PriceOffset::Percent(0.2)),
+            ..Default::default()
+        };
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = amount!(1.0);
+        input_fill.fill_value_gross = amount!(100.0);
+
+        portfolio.update_from_fill(&input_fill).unwrap();
+        let protective_exits = portfolio
+            .repository
+            .position
+            .unwrap()
+            .protective_exits
+            .unwrap();
+
+        assert_eq!(protective_exits.stop_loss, Some(90.0));
+        assert_eq!(protective_exits.take_profit, Some(120.0));
+        assert_eq!(protective_exits.triggered, None);
+    }
+
+    #[test]
+    fn update_from_fill_entering_margined_long_position() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: amount!(200.0),
+                    available: amount!(200.0),
+                })
+            }),
+            get_open_position: Some(|_| Ok(None)),
+            set_open_position: Some(|_| Ok(())),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+        portfolio.margin = Some(MarginConfig {
+            leverage: 10.0,
+            maintenance_margin_rate: 0.005,
+        });
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = amount!(1.0);
+        input_fill.fill_value_gross = amount!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
-        let result = portfolio.update_from_fill(&input_fill);
+        portfolio.update_from_fill(&input_fill).unwrap();
         let updated_repository = portfolio.repository;
-        let entered_position = updated_repository.position.unwrap();
+        let margin = updated_repository.position.unwrap().margin.unwrap();
         let updated_cash = updated_repository.balance.unwrap().available;
 
-        assert!(result.is_ok());
-        assert_eq!(entered_position.side.unwrap(), Side::Buy);
-        assert_eq!(entered_position.enter_value_gross.unwrap(), 100.0);
-        assert_eq!(entered_position.enter_fees_total.unwrap(), 3.0);
-        assert_eq!(updated_cash, 200.0 - 100.0 - 3.0); // cash += enter_value_gross - enter_fees
+        assert_eq!(margin.initial_margin, amount!(10.0));
+        assert!((margin.liquidation_price.to_f64_lossy() - 100.0 * 0.9 / 0.995).abs() < 1e-9);
+        assert_eq!(updated_cash, amount!(200.0) - amount!(10.0) - amount!(3.0));
+        // cash -= initial_margin + enter_fees
     }
 
     #[test]
@@ -1187,11 +3032,11 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 200.0,
+                total: amount!(200.0),
+                available: amount!(200.0),
             })
         });
-        mock_repository.remove_position = Some(|_| Ok(None));
+        mock_repository.get_open_position = Some(|_| Ok(None));
         mock_repository.set_open_position = Some(|_| Ok(()));
         mock_repository.set_balance = Some(|_, _| Ok(()));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -1199,12 +3044,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = -amount!(1.0);
+        input_fill.fill_value_gross = amount!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1214,9 +3059,10 @@ pub mod tests {
 
         assert!(result.is_ok());
         assert_eq!(entered_position.side.unwrap(), Side::Sell);
-        assert_eq!(entered_position.enter_value_gross.unwrap(), 100.0);
-        assert_eq!(entered_position.enter_fees_total.unwrap(), 3.0);
-        assert_eq!(updated_cash, 200.0 - 100.0 - 3.0); // cash += enter_value_gross - enter_fees
+        assert_eq!(entered_position.enter_value_gross.unwrap(), amount!(100.0));
+        assert_eq!(entered_position.enter_fees_total.unwrap(), amount!(3.0));
+        assert_eq!(updated_cash, amount!(200.0) - amount!(100.0) - amount!(3.0));
+        // cash += enter_value_gross - enter_fees
     }
 
     #[test]
@@ -1226,18 +3072,18 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: amount!(200.0),
+                available: amount!(97.0),
             })
         });
-        mock_repository.remove_position = Some(|_| {
+        mock_repository.get_open_position = Some(|_| {
             Ok({
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Buy;
-                    input_position.quantity = 1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = amount!(1.0);
+                    input_position.enter_fees_total = amount!(3.0);
+                    input_position.enter_value_gross = amount!(100.0);
                     input_position
                 })
             })
@@ -1245,18 +3091,19 @@ pub mod tests {
         mock_repository.get_statistics = Some(|_| Ok(PnLReturnSummary::default()));
         mock_repository.set_statistics = Some(|_, _| Ok(()));
         mock_repository.set_exited_position = Some(|_, _| Ok(()));
+        mock_repository.remove_position = Some(|_| Ok(None));
         mock_repository.set_balance = Some(|_, _| Ok(()));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.quantity = -amount!(1.0);
+        input_fill.fill_value_gross = amount!(200.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1267,9 +3114,18 @@ pub mod tests {
         assert!(result.is_ok());
         // LONG result_profit_loss = exit_value_gross - enter_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (200.0 - 100.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            amount!(97.0)
+                + amount!(100.0)
+                + (amount!(200.0) - amount!(100.0) - amount!(6.0))
+                + amount!(3.0)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (200.0 - 100.0 - 6.0));
+        assert_eq!(
+            updated_value,
+            amount!(200.0) + (amount!(200.0) - amount!(100.0) - amount!(6.0))
+        );
     }
 
     #[test]
@@ -1279,18 +3135,18 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: amount!(200.0),
+                available: amount!(97.0),
             })
         });
-        mock_repository.remove_position = Some(|_| {
+        mock_repository.get_open_position = Some(|_| {
             Ok({
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Buy;
-                    input_position.quantity = 1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = amount!(1.0);
+                    input_position.enter_fees_total = amount!(3.0);
+                    input_position.enter_value_gross = amount!(100.0);
                     input_position
                 })
             })
@@ -1298,18 +3154,19 @@ pub mod tests {
         mock_repository.get_statistics = Some(|_| Ok(PnLReturnSummary::default()));
         mock_repository.set_statistics = Some(|_, _| Ok(()));
         mock_repository.set_exited_position = Some(|_, _| Ok(()));
+        mock_repository.remove_position = Some(|_| Ok(None));
         mock_repository.set_balance = Some(|_, _| Ok(()));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.quantity = -amount!(1.0);
+        input_fill.fill_value_gross = amount!(50.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1320,9 +3177,18 @@ pub mod tests {
         assert!(result.is_ok());
         // LONG result_profit_loss = exit_value_gross - enter_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (50.0 - 100.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            amount!(97.0)
+                + amount!(100.0)
+                + (amount!(50.0) - amount!(100.0) - amount!(6.0))
+                + amount!(3.0)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (50.0 - 100.0 - 6.0));
+        assert_eq!(
+            updated_value,
+            amount!(200.0) + (amount!(50.0) - amount!(100.0) - amount!(6.0))
+        );
     }
 
     #[test]
@@ -1332,18 +3198,18 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: amount!(200.0),
+                available: amount!(97.0),
             })
         });
-        mock_repository.remove_position = Some(|_| {
+        mock_repository.get_open_position = Some(|_| {
             Ok({
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Sell;
-                    input_position.quantity = -1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = -amount!(1.0);
+                    input_position.enter_fees_total = amount!(3.0);
+                    input_position.enter_value_gross = amount!(100.0);
                     input_position
                 })
             })
@@ -1351,18 +3217,19 @@ pub mod tests {
         mock_repository.get_statistics = Some(|_| Ok(PnLReturnSummary::default()));
         mock_repository.set_statistics = Some(|_, _| Ok(()));
         mock_repository.set_exited_position = Some(|_, _| Ok(()));
+        mock_repository.remove_position = Some(|_| Ok(None));
         mock_repository.set_balance = Some(|_, _| Ok(()));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.quantity = amount!(1.0);
+        input_fill.fill_value_gross = amount!(50.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1373,9 +3240,18 @@ pub mod tests {
         assert!(result.is_ok());
         // SHORT result_profit_loss = enter_value_gross - exit_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (100.0 - 50.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            amount!(97.0)
+                + amount!(100.0)
+                + (amount!(100.0) - amount!(50.0) - amount!(6.0))
+                + amount!(3.0)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (100.0 - 50.0 - 6.0));
+        assert_eq!(
+            updated_value,
+            amount!(200.0) + (amount!(100.0) - amount!(50.0) - amount!(6.0))
+        );
     }
 
     #[test]
@@ -1385,18 +3261,18 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: amount!(200.0),
+                available: amount!(97.0),
             })
         });
-        mock_repository.remove_position = Some(|_| {
+        mock_repository.get_open_position = Some(|_| {
             Ok({
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Sell;
-                    input_position.quantity = -1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = -amount!(1.0);
+                    input_position.enter_fees_total = amount!(3.0);
+                    input_position.enter_value_gross = amount!(100.0);
                     input_position
                 })
             })
@@ -1404,18 +3280,19 @@ pub mod tests {
         mock_repository.get_statistics = Some(|_| Ok(PnLReturnSummary::default()));
         mock_repository.set_statistics = Some(|_, _| Ok(()));
         mock_repository.set_exited_position = Some(|_, _| Ok(()));
+        mock_repository.remove_position = Some(|_| Ok(None));
         mock_repository.set_balance = Some(|_, _| Ok(()));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 150.0;
+        input_fill.quantity = amount!(1.0);
+        input_fill.fill_value_gross = amount!(150.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1426,9 +3303,295 @@ pub mod tests {
         assert!(result.is_ok());
         // SHORT result_profit_loss = enter_value_gross - exit_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (100.0 - 150.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            amount!(97.0)
+                + amount!(100.0)
+                + (amount!(100.0) - amount!(150.0) - amount!(6.0))
+                + amount!(3.0)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (100.0 - 150.0 - 6.0));
+        assert_eq!(
+            updated_value,
+            amount!(200.0) + (amount!(100.0) - amount!(150.0) - amount!(6.0))
+        );
+    }
+
+    #[test]
+    fn update_from_fill_exiting_long_position_with_short_entry_fill_of_open_quantity() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: amount!(200.0),
+                available: amount!(97.0),
+            })
+        });
+        mock_repository.get_open_position = Some(|_| {
+            Ok({
+                Some({
+                    let mut input_position = position();
+                    input_position.side = Side::Buy;
+                    input_position.quantity = amount!(1.0);
+                    input_position.enter_fees_total = amount!(3.0);
+                    input_position.enter_value_gross = amount!(100.0);
+                    input_position
+                })
+            })
+        });
+        mock_repository.get_statistics = Some(|_| Ok(PnLReturnSummary::default()));
+        mock_repository.set_statistics = Some(|_, _| Ok(()));
+        mock_repository.set_exited_position = Some(|_, _| Ok(()));
+        mock_repository.remove_position = Some(|_| Ok(None));
+        mock_repository.set_balance = Some(|_, _| Ok(()));
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input FillEvent is a Short entry of exactly the open quantity
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Short;
+        input_fill.quantity = -amount!(1.0);
+        input_fill.fill_value_gross = amount!(200.0);
+        input_fill.fees = Fees {
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
+        };
+
+        let result = portfolio.update_from_fill(&input_fill).unwrap();
+        let updated_repository = portfolio.repository;
+        let updated_cash = updated_repository.balance.unwrap().available;
+        let updated_value = updated_repository.balance.unwrap().total;
+
+        assert!(matches!(result[0], Event::PositionExit(_)));
+        assert!(matches!(result[1], Event::Balance(_)));
+        // Exited identically to a CloseLong FillEvent of the same quantity
+        assert_eq!(
+            updated_cash,
+            amount!(97.0)
+                + amount!(100.0)
+                + (amount!(200.0) - amount!(100.0) - amount!(6.0))
+                + amount!(3.0)
+        );
+        assert_eq!(
+            updated_value,
+            amount!(200.0) + (amount!(200.0) - amount!(100.0) - amount!(6.0))
+        );
+    }
+
+    #[test]
+    fn update_from_fill_partially_reducing_long_position() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: amount!(200.0),
+                    available: amount!(97.0),
+                })
+            }),
+            get_open_position: Some(|_| {
+                Ok({
+                    Some({
+                        let mut input_position = position();
+                        input_position.side = Side::Buy;
+                        input_position.quantity = amount!(2.0);
+                        input_position.enter_fees_total = amount!(2.0);
+                        input_position.enter_avg_price_gross = amount!(100.0);
+                        input_position.enter_value_gross = amount!(200.0);
+                        input_position
+                    })
+                })
+            }),
+            set_open_position: Some(|_| Ok(())),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseLong;
+        input_fill.quantity = -amount!(1.0);
+        input_fill.fill_value_gross = amount!(150.0);
+        input_fill.fees = Fees {
+            exchange: amount!(1.0),
+            slippage: amount!(0.0),
+            network: amount!(0.0),
+        };
+
+        let result = portfolio.update_from_fill(&input_fill).unwrap();
+        let updated_repository = portfolio.repository;
+        let updated_cash = updated_repository.balance.unwrap().available;
+        let updated_value = updated_repository.balance.unwrap().total;
+
+        assert!(matches!(result[0], Event::PositionUpdate(_)));
+        assert!(matches!(result[1], Event::Balance(_)));
+        // tranche_profit_loss = tranche_exit_value - tranche_enter_value - tranche_fees
+        // cash += tranche_enter_value + tranche_enter_fees + tranche_profit_loss
+        assert_eq!(
+            updated_cash,
+            amount!(97.0)
+                + amount!(100.0)
+                + amount!(1.0)
+                + (amount!(150.0) - amount!(100.0) - amount!(2.0))
+        );
+        // value += tranche_profit_loss
+        assert_eq!(
+            updated_value,
+            amount!(200.0) + (amount!(150.0) - amount!(100.0) - amount!(2.0))
+        );
+    }
+
+    #[test]
+    fn update_from_fill_flipping_long_position_to_short() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: amount!(200.0),
+                    available: amount!(97.0),
+                })
+            }),
+            get_open_position: Some(|_| {
+                Ok({
+                    Some({
+                        let mut input_position = position();
+                        input_position.side = Side::Buy;
+                        input_position.quantity = amount!(1.0);
+                        input_position.enter_fees_total = amount!(3.0);
+                        input_position.enter_value_gross = amount!(100.0);
+                        input_position
+                    })
+                })
+            }),
+            get_statistics: Some(|_| Ok(PnLReturnSummary::default())),
+            set_statistics: Some(|_, _| Ok(())),
+            set_exited_position: Some(|_, _| Ok(())),
+            remove_position: Some(|_| Ok(None)),
+            set_open_position: Some(|_| Ok(())),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Short;
+        input_fill.quantity = -amount!(2.0);
+        input_fill.fill_value_gross = amount!(400.0);
+        input_fill.fees = Fees {
+            exchange: amount!(2.0),
+            slippage: amount!(0.0),
+            network: amount!(0.0),
+        };
+
+        let result = portfolio.update_from_fill(&input_fill).unwrap();
+        let updated_repository = portfolio.repository;
+        let updated_cash = updated_repository.balance.unwrap().available;
+        let updated_value = updated_repository.balance.unwrap().total;
+
+        assert!(matches!(result[0], Event::PositionExit(_)));
+        assert!(
+            matches!(&result[1], Event::PositionNew(position) if position.quantity == -amount!(1.0))
+        );
+        // exit_profit_loss = exit_value_gross - enter_value_gross - total_fees
+        // cash += enter_value_gross + exit_profit_loss + enter_fees_total - new_enter_value_gross - new_enter_fees
+        assert_eq!(
+            updated_cash,
+            amount!(97.0)
+                + amount!(100.0)
+                + (amount!(200.0) - amount!(100.0) - amount!(4.0))
+                + amount!(3.0)
+                - amount!(200.0)
+                - amount!(1.0)
+        );
+        // value += exit_profit_loss
+        assert_eq!(
+            updated_value,
+            amount!(200.0) + (amount!(200.0) - amount!(100.0) - amount!(4.0))
+        );
+    }
+
+    #[test]
+    fn update_from_fill_flipping_position_with_exit_fill_and_return_err() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: amount!(200.0),
+                    available: amount!(97.0),
+                })
+            }),
+            get_open_position: Some(|_| Ok(Some(position()))),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseLong;
+        input_fill.quantity = -amount!(2.0);
+
+        assert!(matches!(
+            portfolio.update_from_fill(&input_fill),
+            Err(PortfolioError::CannotFlipPositionWithExitFill)
+        ));
+    }
+
+    #[test]
+    fn update_from_fill_with_invalid_fill_leaves_open_position_and_balance_untouched() {
+        let engine_id = Uuid::new_v4();
+        let eth_usdt = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![eth_usdt.clone()])
+            .starting_cash(amount!(1000.0))
+            .repository(InMemoryRepository::<PnLReturnSummary>::new())
+            .allocation_manager(DefaultAllocator::default())
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+            .clock(LiveClock)
+            .build_and_init()
+            .unwrap();
+
+        // Enter 1.0 eth for 100.0 usdt
+        let enter_fill = FillEvent {
+            decision: Decision::Long,
+            ..fill_event()
+        };
+        portfolio.update_from_fill(&enter_fill).unwrap();
+
+        let position_id =
+            determine_position_id(engine_id, &eth_usdt.exchange, &eth_usdt.instrument);
+        let position_prior = portfolio.get_open_position(&position_id).unwrap();
+        let balance_prior = portfolio.get_balance(engine_id).unwrap();
+        assert!(position_prior.is_some());
+
+        // Invalid FillEvent: CloseLong that would flip the open Position to short
+        let invalid_fill = FillEvent {
+            decision: Decision::CloseLong,
+            quantity: -amount!(2.0),
+            fill_value_gross: amount!(200.0),
+            ..enter_fill
+        };
+        assert!(matches!(
+            portfolio.update_from_fill(&invalid_fill),
+            Err(PortfolioError::CannotFlipPositionWithExitFill)
+        ));
+
+        assert_eq!(
+            portfolio.get_open_position(&position_id).unwrap(),
+            position_prior
+        );
+        assert_eq!(portfolio.get_balance(engine_id).unwrap(), balance_prior);
+        assert!(portfolio
+            .get_exited_positions(engine_id)
+            .unwrap()
+            .is_empty());
     }
 
     #[test]
@@ -1589,4 +3752,454 @@ pub mod tests {
 
         assert_eq!(actual, None);
     }
+
+    fn resume_portfolio(
+        repository: InMemoryRepository<PnLReturnSummary>,
+        engine_id: Uuid,
+        markets: Vec<Market>,
+    ) -> Result<
+        MetaPortfolio<
+            InMemoryRepository<PnLReturnSummary>,
+            DefaultAllocator,
+            DefaultRisk,
+            PnLReturnSummary,
+            LiveClock,
+        >,
+        PortfolioError,
+    > {
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(markets)
+            .repository(repository)
+            .allocation_manager(DefaultAllocator::default())
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+            .clock(LiveClock)
+            .build_and_resume(&mut VenueExecution::default())
+    }
+
+    #[test]
+    fn update_from_fill_settles_in_quote_currency_and_values_balance_in_reporting_currency() {
+        let engine_id = Uuid::new_v4();
+        let eth_btc = Market::new("binance", ("eth", "btc", InstrumentKind::Spot));
+
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![eth_btc.clone()])
+            .starting_cash(amount!(1000.0))
+            .repository(InMemoryRepository::<PnLReturnSummary>::new())
+            .allocation_manager(DefaultAllocator::default())
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+            .clock(LiveClock)
+            .reporting_currency(Symbol::new("usdt"))
+            .build_and_init()
+            .unwrap();
+
+        // Statistics of exited Positions are keyed by the MarketId of their FillEvent
+        portfolio
+            .set_statistics(
+                MarketId::new(&eth_btc.exchange, &eth_btc.instrument),
+                PnLReturnSummary::default(),
+            )
+            .unwrap();
+
+        // Conversion rate BTC/USDT @ 20,000 derived from a MarketEvent
+        let mut btc_usdt = market_event_trade(Side::Buy);
+        btc_usdt.instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        if let DataKind::Trade(ref mut trade) = btc_usdt.kind {
+            trade.price = 20_000.0;
+        }
+        portfolio.update_from_market(&btc_usdt).unwrap();
+
+        // Enter 1.0 eth for 0.05 btc
+        let enter_fill = FillEvent {
+            exchange: eth_btc.exchange.clone(),
+            instrument: eth_btc.instrument.clone(),
+            decision: Decision::Long,
+            quantity: amount!(1.0),
+            fill_value_gross: amount!(0.05),
+            ..fill_event()
+        };
+        portfolio.update_from_fill(&enter_fill).unwrap();
+
+        let balance = portfolio.get_balance(engine_id).unwrap();
+        assert_eq!(balance.total, amount!(1000.0));
+        assert!((balance.available.to_f64_lossy() - 0.0).abs() < 1e-9);
+
+        // Exit 1.0 eth for 0.06 btc
+        let exit_fill = FillEvent {
+            decision: Decision::CloseLong,
+            quantity: -amount!(1.0),
+            fill_value_gross: amount!(0.06),
+            ..enter_fill
+        };
+        let events = portfolio.update_from_fill(&exit_fill).unwrap();
+
+        // Profit of 0.01 btc is settled in btc, & valued at 200.0 usdt
+        let mut symbol_balances = portfolio.get_symbol_balances(engine_id).unwrap();
+        symbol_balances.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        assert_eq!(symbol_balances.len(), 2);
+        assert_eq!(symbol_balances[0].symbol, Symbol::new("btc"));
+        assert!((symbol_balances[0].balance.total.to_f64_lossy() - 0.01).abs() < 1e-9);
+        assert!((symbol_balances[0].balance.available.to_f64_lossy() - 0.01).abs() < 1e-9);
+        assert_eq!(symbol_balances[1].balance.total, amount!(1000.0));
+
+        let balance = portfolio.get_balance(engine_id).unwrap();
+        assert!((balance.total.to_f64_lossy() - 1200.0).abs() < 1e-6);
+        assert!((balance.available.to_f64_lossy() - 1200.0).abs() < 1e-6);
+        assert_eq!(events.last(), Some(&Event::Balance(balance)));
+    }
+
+    #[test]
+    fn bootstrap_repository_seeds_starting_symbol_balance_per_quote_asset() {
+        let engine_id = Uuid::new_v4();
+        let eth_btc = Market::new("binance", ("eth", "btc", InstrumentKind::Spot));
+        let sol_eth = Market::new("binance", ("sol", "eth", InstrumentKind::Spot));
+
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![eth_btc, sol_eth])
+            .starting_cash(amount!(2000.0))
+            .starting_balances(HashMap::from([
+                (Symbol::new("usdt"), amount!(1000.0)),
+                (Symbol::new("btc"), amount!(0.05)),
+            ]))
+            .repository(InMemoryRepository::<PnLReturnSummary>::new())
+            .allocation_manager(DefaultAllocator::default())
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+            .clock(LiveClock)
+            .reporting_currency(Symbol::new("usdt"))
+            .build_and_init()
+            .unwrap();
+
+        let mut symbol_balances = portfolio
+            .get_symbol_balances(engine_id)
+            .unwrap()
+            .into_iter()
+            .map(|symbol_balance| (symbol_balance.symbol, symbol_balance.balance.available))
+            .collect::<Vec<_>>();
+        symbol_balances.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(
+            symbol_balances,
+            vec![
+                (Symbol::new("btc"), amount!(0.05)),
+                (Symbol::new("eth"), Amount::ZERO),
+                (Symbol::new("usdt"), amount!(1000.0)),
+            ]
+        );
+        assert_eq!(
+            portfolio.get_balance(engine_id).unwrap().total,
+            amount!(2000.0)
+        );
+    }
+
+    #[test]
+    fn generate_order_gates_entries_on_available_balance_of_the_quote_asset() {
+        let eth_usdt = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+        let eth_btc = Market::new("binance", ("eth", "btc", InstrumentKind::Spot));
+
+        // Plenty of usdt, but no btc to enter eth_btc Positions with
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .markets(vec![eth_usdt.clone(), eth_btc.clone()])
+            .starting_cash(amount!(10_000.0))
+            .repository(InMemoryRepository::<PnLReturnSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+                ..Default::default()
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+            .clock(LiveClock)
+            .reporting_currency(Symbol::new("usdt"))
+            .build_and_init()
+            .unwrap();
+
+        // Conversion rate BTC/USDT @ 20,000 derived from a MarketEvent
+        let mut btc_usdt = market_event_trade(Side::Buy);
+        btc_usdt.instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        if let DataKind::Trade(ref mut trade) = btc_usdt.kind {
+            trade.price = 20_000.0;
+        }
+        portfolio.update_from_market(&btc_usdt).unwrap();
+
+        let mut signal = signal();
+        signal.signals = HashMap::from([(Decision::Long, SignalStrength(1.0))]);
+
+        // Entry quoted in btc is not generated
+        signal.exchange = eth_btc.exchange;
+        signal.instrument = eth_btc.instrument;
+        signal.market_meta.close = 0.05;
+        assert_eq!(portfolio.generate_order(&signal).unwrap(), None);
+
+        // Entry quoted in usdt is sized on the available usdt balance
+        signal.exchange = eth_usdt.exchange;
+        signal.instrument = eth_usdt.instrument;
+        signal.market_meta.close = 1000.0;
+        let order = portfolio.generate_order(&signal).unwrap().unwrap();
+        assert_eq!(order.quantity, amount!(0.1));
+
+        let state = portfolio.portfolio_state(&Symbol::new("usdt")).unwrap();
+        assert_eq!(state.balance.available, amount!(10_000.0));
+        let state = portfolio.portfolio_state(&Symbol::new("btc")).unwrap();
+        assert_eq!(state.balance.available, Amount::ZERO);
+    }
+
+    #[test]
+    fn generate_order_without_conversion_rate_to_reporting_currency_and_return_err() {
+        let eth_btc = Market::new("binance", ("eth", "btc", InstrumentKind::Spot));
+
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .markets(vec![eth_btc.clone()])
+            .starting_cash(amount!(1000.0))
+            .starting_balances(HashMap::from([
+                (Symbol::new("usdt"), amount!(1000.0)),
+                (Symbol::new("btc"), amount!(1.0)),
+            ]))
+            .repository(InMemoryRepository::<PnLReturnSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+                ..Default::default()
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+            .clock(LiveClock)
+            .reporting_currency(Symbol::new("usdt"))
+            .build_and_init()
+            .unwrap();
+
+        let mut signal = signal();
+        signal.exchange = eth_btc.exchange;
+        signal.instrument = eth_btc.instrument;
+        signal.market_meta.close = 0.05;
+        signal.signals = HashMap::from([(Decision::Long, SignalStrength(1.0))]);
+
+        assert!(matches!(
+            portfolio.generate_order(&signal),
+            Err(PortfolioError::ConversionRateNotPresent { .. })
+        ));
+    }
+
+    #[test]
+    fn update_from_fill_without_conversion_rate_to_reporting_currency_and_return_err() {
+        let engine_id = Uuid::new_v4();
+        let eth_btc = Market::new("binance", ("eth", "btc", InstrumentKind::Spot));
+
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![eth_btc.clone()])
+            .starting_cash(amount!(1000.0))
+            .repository(InMemoryRepository::<PnLReturnSummary>::new())
+            .allocation_manager(DefaultAllocator::default())
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+            .clock(LiveClock)
+            .reporting_currency(Symbol::new("usdt"))
+            .build_and_init()
+            .unwrap();
+
+        let enter_fill = FillEvent {
+            exchange: eth_btc.exchange.clone(),
+            instrument: eth_btc.instrument.clone(),
+            decision: Decision::Long,
+            quantity: amount!(1.0),
+            fill_value_gross: amount!(0.05),
+            ..fill_event()
+        };
+
+        let balance_prior = portfolio.get_balance(engine_id).unwrap();
+        let mut symbol_balances_prior = portfolio.get_symbol_balances(engine_id).unwrap();
+
+        assert!(matches!(
+            portfolio.update_from_fill(&enter_fill),
+            Err(PortfolioError::ConversionRateNotPresent { .. })
+        ));
+
+        // Nothing is booked if the FillEvent cannot be valued
+        let position_id = determine_position_id(engine_id, &eth_btc.exchange, &eth_btc.instrument);
+        assert_eq!(portfolio.get_open_position(&position_id).unwrap(), None);
+        assert_eq!(portfolio.get_balance(engine_id).unwrap(), balance_prior);
+
+        let mut symbol_balances = portfolio.get_symbol_balances(engine_id).unwrap();
+        symbol_balances.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        symbol_balances_prior.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        assert_eq!(symbol_balances, symbol_balances_prior);
+    }
+
+    #[test]
+    fn build_and_resume_loads_persisted_state_and_initialises_new_market_statistics() {
+        let engine_id = Uuid::new_v4();
+        let eth = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+        let btc = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+
+        // Persist the state of a previous run that only traded eth
+        let balance = Balance {
+            time: Utc::now(),
+            total: amount!(1200.0),
+            available: amount!(1100.0),
+        };
+        let open_position = Position {
+            position_id: determine_position_id(engine_id, &eth.exchange, &eth.instrument),
+            ..position()
+        };
+        let mut repository = InMemoryRepository::new();
+        repository.set_balance(engine_id, balance).unwrap();
+        repository
+            .set_statistics(MarketId::from(&eth), PnLReturnSummary::default())
+            .unwrap();
+        repository.set_open_position(open_position.clone()).unwrap();
+
+        let mut portfolio =
+            resume_portfolio(repository, engine_id, vec![eth.clone(), btc.clone()]).unwrap();
+
+        assert_eq!(portfolio.get_balance(engine_id).unwrap(), balance);
+        assert_eq!(
+            portfolio
+                .get_open_positions(engine_id, [&eth, &btc].into_iter())
+                .unwrap(),
+            vec![open_position]
+        );
+        assert!(portfolio.get_statistics(&MarketId::from(&btc)).is_ok());
+    }
+
+    #[test]
+    fn build_and_resume_fails_without_persisted_state() {
+        let engine_id = Uuid::new_v4();
+        let eth = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+        let actual = resume_portfolio(InMemoryRepository::new(), engine_id, vec![eth]);
+
+        assert!(
+            matches!(actual, Err(PortfolioError::ResumeStateNotPresent(id)) if id == engine_id)
+        );
+    }
+
+    #[derive(Default)]
+    struct VenueExecution {
+        quantity: Option<Amount>,
+        assets: Option<HashMap<Symbol, Amount>>,
+    }
+
+    impl ExecutionClient for VenueExecution {
+        fn generate_fill(
+            &mut self,
+            _: &OrderEvent,
+        ) -> Result<Option<FillEvent>, crate::execution::error::ExecutionError> {
+            Ok(None)
+        }
+
+        fn fetch_position_quantity(
+            &mut self,
+            _: &Market,
+        ) -> Result<Option<Amount>, crate::execution::error::ExecutionError> {
+            Ok(self.quantity)
+        }
+
+        fn fetch_asset_quantities(
+            &mut self,
+        ) -> Result<Option<HashMap<Symbol, Amount>>, crate::execution::error::ExecutionError>
+        {
+            Ok(self.assets.clone())
+        }
+    }
+
+    #[test]
+    fn reconcile_open_position_quantity_with_execution_venue() {
+        let engine_id = Uuid::new_v4();
+        let eth = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+        let mut repository = InMemoryRepository::new();
+        repository
+            .set_balance(engine_id, Balance::default())
+            .unwrap();
+        repository
+            .set_open_position(Position {
+                position_id: determine_position_id(engine_id, &eth.exchange, &eth.instrument),
+                quantity: amount!(1.0),
+                ..position()
+            })
+            .unwrap();
+        let mut portfolio = resume_portfolio(repository, engine_id, vec![eth.clone()]).unwrap();
+
+        // Venue that cannot report it's Position quantity is not reconciled
+        let mut execution = VenueExecution::default();
+        assert!(portfolio.reconcile(&eth, &mut execution).is_ok());
+
+        // Venue quantity matching the open Position reconciles
+        let mut execution = VenueExecution {
+            quantity: Some(amount!(1.0)),
+            ..Default::default()
+        };
+        assert!(portfolio.reconcile(&eth, &mut execution).is_ok());
+
+        // Venue quantity differing from the open Position is a break
+        let mut execution = VenueExecution {
+            quantity: Some(amount!(0.5)),
+            ..Default::default()
+        };
+        assert!(matches!(
+            portfolio.reconcile(&eth, &mut execution),
+            Err(PortfolioError::ReconciliationBreak {
+                portfolio_quantity,
+                venue_quantity,
+                ..
+            }) if portfolio_quantity == amount!(1.0) && venue_quantity == amount!(0.5)
+        ));
+    }
+
+    #[test]
+    fn build_and_resume_reconciles_with_execution_venue_before_trading() {
+        let engine_id = Uuid::new_v4();
+        let eth = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+        let repository = || {
+            let mut repository = InMemoryRepository::<PnLReturnSummary>::new();
+            repository
+                .set_balance(engine_id, Balance::default())
+                .unwrap();
+            repository
+                .set_open_position(Position {
+                    position_id: determine_position_id(engine_id, &eth.exchange, &eth.instrument),
+                    quantity: amount!(1.0),
+                    ..position()
+                })
+                .unwrap();
+            repository
+        };
+        let resume = |execution: &mut VenueExecution| {
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![eth.clone()])
+                .repository(repository())
+                .allocation_manager(DefaultAllocator::default())
+                .risk_manager(DefaultRisk {})
+                .statistic_config(())
+                .clock(LiveClock)
+                .build_and_resume(execution)
+        };
+
+        // Venue Position in a Market that is not configured is reported, but does not fail
+        let mut execution = VenueExecution {
+            quantity: Some(amount!(1.0)),
+            assets: Some(HashMap::from([
+                (Symbol::new("eth"), amount!(1.0)),
+                (Symbol::new("btc"), amount!(0.5)),
+            ])),
+        };
+        assert!(resume(&mut execution).is_ok());
+
+        // Venue quantity differing from the resumed open Position fails before trading
+        let mut execution = VenueExecution {
+            quantity: Some(amount!(0.5)),
+            ..Default::default()
+        };
+        assert!(matches!(
+            resume(&mut execution),
+            Err(PortfolioError::ReconciliationBreak { .. })
+        ));
+    }
 }
@@ -1,10 +1,22 @@
 use crate::{
     execution::{FeeAmount, Fees, FillEvent},
-    portfolio::{error::PortfolioError, Balance},
+    portfolio::{
+        error::PortfolioError,
+        margin::Margin,
+        protection::{ExitTrigger, ProtectiveExits},
+        Balance,
+    },
     strategy::Decision,
 };
-use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::funding::FundingRate,
+};
+use barter_integration::model::{
+    amount::{Amount, AmountExt},
+    instrument::Instrument,
+    Exchange, Side,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
@@ -23,6 +35,19 @@ pub trait PositionUpdater {
     fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<PositionUpdate>;
 }
 
+/// Adds to, or partially reduces, an open [`Position`].
+pub trait PositionScaler {
+    /// Increases an open [`Position`] using an entry [`FillEvent`] in the same direction, updating
+    /// the weighted average entry price. Returns a [`PositionUpdate`] that communicates the open
+    /// [`Position`]'s change in state.
+    fn increase(&mut self, fill: &FillEvent) -> Result<PositionUpdate, PortfolioError>;
+
+    /// Partially reduces an open [`Position`] using a [`FillEvent`] in the opposite direction
+    /// that is smaller than the open quantity, realising the P&L of the reduced tranche. Returns
+    /// a [`PositionUpdate`] that communicates the open [`Position`]'s change in state.
+    fn reduce(&mut self, fill: &FillEvent) -> Result<PositionUpdate, PortfolioError>;
+}
+
 /// Exits an open [`Position`].
 pub trait PositionExiter {
     /// Exits an open [`Position`], given the input Portfolio equity & the [`FillEvent`] returned
@@ -30,6 +55,23 @@ pub trait PositionExiter {
     fn exit(&mut self, balance: Balance, fill: &FillEvent) -> Result<PositionExit, PortfolioError>;
 }
 
+/// Relative tolerance used when comparing a [`FillEvent`] quantity to an open [`Position`]
+/// quantity, so that fills aggregated from many partial fills still exit the [`Position`].
+pub(crate) const QUANTITY_TOLERANCE: f64 = 1e-9;
+
+/// Effect a [`FillEvent`] has on an open [`Position`] for the same market.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum FillImpact {
+    /// Adds to the open [`Position`] in the same direction.
+    Increase,
+    /// Partially reduces the open [`Position`].
+    Reduce,
+    /// Exits the entire open [`Position`].
+    Exit,
+    /// Exits the entire open [`Position`] & enters a new one on the opposite side.
+    Flip,
+}
+
 /// Communicates a String represents a unique [`Position`] identifier.
 pub type PositionId = String;
 
@@ -64,8 +106,9 @@ pub struct Position {
     /// - Side::Sell considered synonymous with Short.
     pub side: Side,
 
-    /// +ve or -ve quantity of symbol contracts opened.
-    pub quantity: f64,
+    /// +ve or -ve quantity of symbol contracts open. Once exited, the quantity that was open at
+    /// the point of exiting.
+    pub quantity: Amount,
 
     /// All fees types incurred from entering a [`Position`], and their associated [`FeeAmount`].
     pub enter_fees: Fees,
@@ -73,11 +116,15 @@ pub struct Position {
     /// Total of enter_fees incurred. Sum of every [`FeeAmount`] in [`Fees`] when entering a [`Position`].
     pub enter_fees_total: FeeAmount,
 
-    /// Enter average price excluding the entry_fees_total.
-    pub enter_avg_price_gross: f64,
+    /// Portion of the enter_fees_total attributed to tranches already realised by partial exits.
+    #[serde(default)]
+    pub enter_fees_realised: FeeAmount,
 
-    /// abs(Quantity) * enter_avg_price_gross.
-    pub enter_value_gross: f64,
+    /// Weighted average enter price of the open quantity, excluding the entry_fees_total.
+    pub enter_avg_price_gross: Amount,
+
+    /// Sum of abs(Quantity) * enter price for every entry [`FillEvent`].
+    pub enter_value_gross: Amount,
 
     /// All fees types incurred from exiting a [`Position`], and their associated [`FeeAmount`].
     pub exit_fees: Fees,
@@ -85,23 +132,31 @@ pub struct Position {
     /// Total of exit_fees incurred. Sum of every [`FeeAmount`] in [`Fees`] when entering a [`Position`].
     pub exit_fees_total: FeeAmount,
 
-    /// Exit average price excluding the exit_fees_total.
-    pub exit_avg_price_gross: f64,
+    /// Weighted average exit price of every exit [`FillEvent`], excluding the exit_fees_total.
+    pub exit_avg_price_gross: Amount,
 
-    /// abs(Quantity) * exit_avg_price_gross.
-    pub exit_value_gross: f64,
+    /// Sum of abs(Quantity) * exit price for every exit [`FillEvent`].
+    pub exit_value_gross: Amount,
 
     /// Symbol current close price.
-    pub current_symbol_price: f64,
+    pub current_symbol_price: Amount,
 
     /// abs(Quantity) * current_symbol_price.
-    pub current_value_gross: f64,
+    pub current_value_gross: Amount,
 
     /// Unrealised P&L whilst the [`Position`] is open.
-    pub unrealised_profit_loss: f64,
+    pub unrealised_profit_loss: Amount,
 
-    /// Realised P&L after the [`Position`] has closed.
-    pub realised_profit_loss: f64,
+    /// Realised P&L of the tranches exited so far, and of the entire [`Position`] once closed.
+    pub realised_profit_loss: Amount,
+
+    /// Protective exit levels (eg/ stop loss) evaluated every time the [`Position`] is updated.
+    #[serde(default)]
+    pub protective_exits: ProtectiveExits,
+
+    /// Margin state of a [`Position`] entered in margin-aware mode, or None for cash spot.
+    #[serde(default)]
+    pub margin: Option<Margin>,
 }
 
 impl PositionEnterer for Position {
@@ -120,7 +175,7 @@ impl PositionEnterer for Position {
         let enter_avg_price_gross = Position::calculate_avg_price_gross(fill);
 
         // Unreal profit & loss
-        let unrealised_profit_loss = -enter_fees_total * 2.0;
+        let unrealised_profit_loss = -enter_fees_total * Amount::from(2);
 
         Ok(Position {
             position_id: determine_position_id(engine_id, &fill.exchange, &fill.instrument),
@@ -131,16 +186,19 @@ impl PositionEnterer for Position {
             quantity: fill.quantity,
             enter_fees: fill.fees,
             enter_fees_total,
+            enter_fees_realised: Amount::ZERO,
             enter_avg_price_gross,
             enter_value_gross: fill.fill_value_gross,
             exit_fees: Fees::default(),
-            exit_fees_total: 0.0,
-            exit_avg_price_gross: 0.0,
-            exit_value_gross: 0.0,
+            exit_fees_total: Amount::ZERO,
+            exit_avg_price_gross: Amount::ZERO,
+            exit_value_gross: Amount::ZERO,
             current_symbol_price: enter_avg_price_gross,
             current_value_gross: fill.fill_value_gross,
             unrealised_profit_loss,
-            realised_profit_loss: 0.0,
+            realised_profit_loss: Amount::ZERO,
+            protective_exits: ProtectiveExits::default(),
+            margin: None,
         })
     }
 }
@@ -148,14 +206,19 @@ impl PositionEnterer for Position {
 impl PositionUpdater for Position {
     fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<PositionUpdate> {
         // Determine close from MarketEvent
-        let close = match &market.kind {
+        let close_f64 = match &market.kind {
             DataKind::Trade(trade) => trade.price,
             DataKind::Candle(candle) => candle.close,
             DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price(),
             DataKind::OrderBook(book) => book.volume_weighed_mid_price()?,
             DataKind::Liquidation(_) => return None,
+            DataKind::FundingRate(funding) => {
+                return self.apply_funding(market.exchange_time, funding)
+            }
         };
 
+        let close = Amount::from_f64_lossy(close_f64);
+
         self.meta.update_time = market.exchange_time;
 
         self.current_symbol_price = close;
@@ -166,8 +229,78 @@ impl PositionUpdater for Position {
         // Unreal profit & loss
         self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
 
+        // Evaluate protective exits using the latest close
+        let protective_exit_trigger =
+            self.protective_exits
+                .update(self.side, close_f64, market.exchange_time);
+
+        // Breaching the liquidation price of a margined Position takes priority
+        let (side, quantity) = (self.side, self.quantity);
+        let liquidated = self
+            .margin
+            .as_mut()
+            .is_some_and(|margin| margin.update_price(side, quantity, close));
+        let exit_trigger = match liquidated {
+            true => Some(ExitTrigger::Liquidation),
+            false => protective_exit_trigger,
+        };
+
         // Return a PositionUpdate event that communicates the change in state
-        Some(PositionUpdate::from(self))
+        Some(PositionUpdate {
+            exit_trigger,
+            ..PositionUpdate::from(self)
+        })
+    }
+}
+
+impl PositionScaler for Position {
+    fn increase(&mut self, fill: &FillEvent) -> Result<PositionUpdate, PortfolioError> {
+        if Position::parse_entry_side(fill)? != self.side {
+            return Err(PortfolioError::ParseEntrySide);
+        }
+
+        // Enter value & weighted average price of the open quantity
+        let enter_value_open = self.enter_value_open() + fill.fill_value_gross;
+        self.quantity += fill.quantity;
+        self.enter_avg_price_gross = enter_value_open / self.quantity.abs();
+        self.enter_value_gross += fill.fill_value_gross;
+
+        // Enter fees
+        self.enter_fees += fill.fees;
+        self.enter_fees_total += fill.fees.calculate_total_fees();
+
+        self.meta.update_time = fill.time;
+        self.update_open_value();
+
+        Ok(PositionUpdate::from(self))
+    }
+
+    fn reduce(&mut self, fill: &FillEvent) -> Result<PositionUpdate, PortfolioError> {
+        if self.determine_fill_impact(fill) != FillImpact::Reduce {
+            return Err(PortfolioError::CannotReducePosition);
+        }
+
+        // Attribute the open enter value & enter fees to the reduced tranche
+        let tranche_ratio = fill.quantity.abs() / self.quantity.abs();
+        let tranche_enter_value = self.enter_value_open() * tranche_ratio;
+        let tranche_enter_fees = self.enter_fees_open() * tranche_ratio;
+        let tranche_fees = tranche_enter_fees + fill.fees.calculate_total_fees();
+
+        // Tranche profit & loss
+        self.realised_profit_loss += match self.side {
+            Side::Buy => fill.fill_value_gross - tranche_enter_value - tranche_fees,
+            Side::Sell => tranche_enter_value - fill.fill_value_gross - tranche_fees,
+        };
+        self.enter_fees_realised += tranche_enter_fees;
+        self.apply_exit_fill(fill);
+
+        // Weighted average enter price of the remaining open quantity is unchanged
+        self.quantity += fill.quantity;
+
+        self.meta.update_time = fill.time;
+        self.update_open_value();
+
+        Ok(PositionUpdate::from(self))
     }
 }
 
@@ -181,20 +314,17 @@ impl PositionExiter for Position {
             return Err(PortfolioError::CannotExitPositionWithEntryFill);
         }
 
-        // Exit fees
-        self.exit_fees = fill.fees;
-        self.exit_fees_total = fill.fees.calculate_total_fees();
+        // Exit fees, value & price
+        self.apply_exit_fill(fill);
+        self.enter_fees_realised = self.enter_fees_total;
 
-        // Exit value & price
-        self.exit_value_gross = fill.fill_value_gross;
-        self.exit_avg_price_gross = Position::calculate_avg_price_gross(fill);
-
-        // Result profit & loss
+        // Result profit & loss, including any tranches realised by partial exits
+        let realised_profit_loss_prior = self.realised_profit_loss;
         self.realised_profit_loss = self.calculate_realised_profit_loss();
         self.unrealised_profit_loss = self.realised_profit_loss;
 
         // Metadata
-        balance.total += self.realised_profit_loss;
+        balance.total += self.realised_profit_loss - realised_profit_loss_prior;
         self.meta.update_time = fill.time;
         self.meta.exit_balance = Some(balance);
 
@@ -210,7 +340,7 @@ impl Position {
 
     /// Calculates the [`Position::enter_avg_price_gross`] or [`Position::exit_avg_price_gross`] of
     /// a [`FillEvent`].
-    pub fn calculate_avg_price_gross(fill: &FillEvent) -> f64 {
+    pub fn calculate_avg_price_gross(fill: &FillEvent) -> Amount {
         (fill.fill_value_gross / fill.quantity).abs()
     }
 
@@ -226,6 +356,140 @@ impl Position {
         }
     }
 
+    /// Determine the [`FillImpact`] of a [`FillEvent`] for the same market as this open
+    /// [`Position`].
+    pub fn determine_fill_impact(&self, fill: &FillEvent) -> FillImpact {
+        if fill.quantity.is_sign_positive() == self.quantity.is_sign_positive() {
+            return FillImpact::Increase;
+        }
+
+        let open_quantity = self.quantity.abs();
+        let quantity_difference = fill.quantity.abs() - open_quantity;
+        if quantity_difference.abs() <= open_quantity * Amount::from_f64_lossy(QUANTITY_TOLERANCE) {
+            FillImpact::Exit
+        } else if quantity_difference < Amount::ZERO {
+            FillImpact::Reduce
+        } else {
+            FillImpact::Flip
+        }
+    }
+
+    /// Splits a [`FillEvent`] with a [`FillImpact::Flip`] into the exit [`FillEvent`] that exits
+    /// this open [`Position`], and the entry [`FillEvent`] of the remaining quantity that enters
+    /// a new [`Position`] on the opposite side. Fill value & fees are attributed pro-rata.
+    pub fn split_flip_fill(&self, fill: &FillEvent) -> (FillEvent, FillEvent) {
+        let exit_ratio = self.quantity.abs() / fill.quantity.abs();
+        let enter_ratio = Amount::ONE - exit_ratio;
+
+        let exit_fill = FillEvent {
+            decision: self.determine_exit_decision(),
+            quantity: -self.quantity,
+            fill_value_gross: fill.fill_value_gross * exit_ratio,
+            fees: fill.fees.scale(exit_ratio),
+            ..fill.clone()
+        };
+
+        let enter_fill = FillEvent {
+            decision: match self.side {
+                Side::Buy => Decision::Short,
+                Side::Sell => Decision::Long,
+            },
+            quantity: fill.quantity + self.quantity,
+            fill_value_gross: fill.fill_value_gross * enter_ratio,
+            fees: fill.fees.scale(enter_ratio),
+            ..fill.clone()
+        };
+
+        (exit_fill, enter_fill)
+    }
+
+    /// Enter value of the open quantity, ie/ abs(Quantity) * enter_avg_price_gross.
+    pub fn enter_value_open(&self) -> Amount {
+        self.quantity.abs() * self.enter_avg_price_gross
+    }
+
+    /// Enter fees attributed to the open quantity that have not been realised by a partial exit.
+    pub fn enter_fees_open(&self) -> FeeAmount {
+        self.enter_fees_total - self.enter_fees_realised
+    }
+
+    /// Portfolio [`Balance`] committed to the open quantity, ie/ it's initial margin if margined,
+    /// otherwise it's enter value, plus the enter fees attributed to it.
+    pub fn committed_balance_open(&self) -> Amount {
+        let committed = match &self.margin {
+            Some(margin) => margin.initial_margin,
+            None => self.enter_value_open(),
+        };
+
+        committed + self.enter_fees_open()
+    }
+
+    /// Returns a copy of the [`Position`] with every monetary value (prices, values, fees & P&L)
+    /// converted at the provided rate, eg/ from the quote currency of it's [`Instrument`] into a
+    /// Portfolio reporting currency.
+    pub fn converted(&self, rate: f64) -> Position {
+        let rate = Amount::from_f64_lossy(rate);
+        Position {
+            enter_fees: self.enter_fees.scale(rate),
+            enter_fees_total: self.enter_fees_total * rate,
+            enter_fees_realised: self.enter_fees_realised * rate,
+            enter_avg_price_gross: self.enter_avg_price_gross * rate,
+            enter_value_gross: self.enter_value_gross * rate,
+            exit_fees: self.exit_fees.scale(rate),
+            exit_fees_total: self.exit_fees_total * rate,
+            exit_avg_price_gross: self.exit_avg_price_gross * rate,
+            exit_value_gross: self.exit_value_gross * rate,
+            current_symbol_price: self.current_symbol_price * rate,
+            current_value_gross: self.current_value_gross * rate,
+            unrealised_profit_loss: self.unrealised_profit_loss * rate,
+            realised_profit_loss: self.realised_profit_loss * rate,
+            ..self.clone()
+        }
+    }
+
+    /// Realise a [`FundingRate`] settlement of a margined [`Position`], returning a
+    /// [`PositionUpdate`] that communicates the change in state. Cash spot Positions do not
+    /// accrue funding.
+    fn apply_funding(
+        &mut self,
+        time: DateTime<Utc>,
+        funding: &FundingRate,
+    ) -> Option<PositionUpdate> {
+        let (side, quantity) = (self.side, self.quantity);
+        let funding_profit_loss = self.margin.as_mut()?.apply_funding(side, quantity, funding);
+
+        self.realised_profit_loss += funding_profit_loss;
+        self.meta.update_time = time;
+
+        Some(PositionUpdate::from(self))
+    }
+
+    /// Accumulate the fees, value & weighted average price of an exit [`FillEvent`].
+    fn apply_exit_fill(&mut self, fill: &FillEvent) {
+        let exit_quantity_prior = match self.exit_avg_price_gross > Amount::ZERO {
+            true => self.exit_value_gross / self.exit_avg_price_gross,
+            false => Amount::ZERO,
+        };
+
+        self.exit_fees += fill.fees;
+        self.exit_fees_total += fill.fees.calculate_total_fees();
+        self.exit_value_gross += fill.fill_value_gross;
+        self.exit_avg_price_gross =
+            self.exit_value_gross / (exit_quantity_prior + fill.quantity.abs());
+    }
+
+    /// Update the current value & unrealised P&L of the open quantity.
+    fn update_open_value(&mut self) {
+        self.current_value_gross = self.current_symbol_price * self.quantity.abs();
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+
+        let (side, quantity, enter_avg_price_gross) =
+            (self.side, self.quantity, self.enter_avg_price_gross);
+        if let Some(margin) = self.margin.as_mut() {
+            margin.update_open(side, quantity, enter_avg_price_gross);
+        }
+    }
+
     /// Determines the [`Decision`] required to exit this [`Side`] (Buy or Sell) [`Position`].
     pub fn determine_exit_decision(&self) -> Decision {
         match self.side {
@@ -234,30 +498,46 @@ impl Position {
         }
     }
 
-    /// Calculate the approximate [`Position::unrealised_profit_loss`] of a [`Position`].
-    pub fn calculate_unrealised_profit_loss(&self) -> f64 {
-        let approx_total_fees = self.enter_fees_total * 2.0;
-
-        match self.side {
-            Side::Buy => self.current_value_gross - self.enter_value_gross - approx_total_fees,
-            Side::Sell => self.enter_value_gross - self.current_value_gross - approx_total_fees,
+    /// Re-arms the [`ProtectiveExits`] & liquidation of this [`Position`] after the exit they
+    /// triggered failed, so the next [`MarketEvent`] evaluates them again.
+    pub fn rearm_exit_triggers(&mut self) {
+        self.protective_exits.rearm();
+        if let Some(margin) = self.margin.as_mut() {
+            margin.liquidated = false;
         }
     }
 
-    /// Calculate the exact [`Position::realised_profit_loss`] of a [`Position`].
-    pub fn calculate_realised_profit_loss(&self) -> f64 {
-        let total_fees = self.enter_fees_total + self.exit_fees_total;
+    /// Calculate the approximate [`Position::unrealised_profit_loss`] of the open quantity of a
+    /// [`Position`].
+    pub fn calculate_unrealised_profit_loss(&self) -> Amount {
+        let approx_total_fees = self.enter_fees_open() * Amount::from(2);
+        let enter_value_open = self.enter_value_open();
 
         match self.side {
-            Side::Buy => self.exit_value_gross - self.enter_value_gross - total_fees,
-            Side::Sell => self.enter_value_gross - self.exit_value_gross - total_fees,
+            Side::Buy => self.current_value_gross - enter_value_open - approx_total_fees,
+            Side::Sell => enter_value_open - self.current_value_gross - approx_total_fees,
         }
     }
 
+    /// Calculate the exact [`Position::realised_profit_loss`] of a closed [`Position`], including
+    /// any funding realised whilst it was open.
+    pub fn calculate_realised_profit_loss(&self) -> Amount {
+        let total_fees = self.enter_fees_total + self.exit_fees_total;
+        let funding_profit_loss = self
+            .margin
+            .map_or(Amount::ZERO, |margin| margin.funding_profit_loss);
+
+        funding_profit_loss
+            + match self.side {
+                Side::Buy => self.exit_value_gross - self.enter_value_gross - total_fees,
+                Side::Sell => self.enter_value_gross - self.exit_value_gross - total_fees,
+            }
+    }
+
     /// Calculate the PnL return of a closed [`Position`] - assumed [`Position::realised_profit_loss`] is
     /// appropriately calculated.
     pub fn calculate_profit_loss_return(&self) -> f64 {
-        self.realised_profit_loss / self.enter_value_gross
+        self.realised_profit_loss.to_f64_lossy() / self.enter_value_gross.to_f64_lossy()
     }
 }
 
@@ -269,19 +549,22 @@ pub struct PositionBuilder {
     pub instrument: Option<Instrument>,
     pub meta: Option<PositionMeta>,
     pub side: Option<Side>,
-    pub quantity: Option<f64>,
+    pub quantity: Option<Amount>,
     pub enter_fees: Option<Fees>,
     pub enter_fees_total: Option<FeeAmount>,
-    pub enter_avg_price_gross: Option<f64>,
-    pub enter_value_gross: Option<f64>,
+    pub enter_fees_realised: Option<FeeAmount>,
+    pub enter_avg_price_gross: Option<Amount>,
+    pub enter_value_gross: Option<Amount>,
     pub exit_fees: Option<Fees>,
     pub exit_fees_total: Option<FeeAmount>,
-    pub exit_avg_price_gross: Option<f64>,
-    pub exit_value_gross: Option<f64>,
-    pub current_symbol_price: Option<f64>,
-    pub current_value_gross: Option<f64>,
-    pub unrealised_profit_loss: Option<f64>,
-    pub realised_profit_loss: Option<f64>,
+    pub exit_avg_price_gross: Option<Amount>,
+    pub exit_value_gross: Option<Amount>,
+    pub current_symbol_price: Option<Amount>,
+    pub current_value_gross: Option<Amount>,
+    pub unrealised_profit_loss: Option<Amount>,
+    pub realised_profit_loss: Option<Amount>,
+    pub protective_exits: Option<ProtectiveExits>,
+    pub margin: Option<Margin>,
 }
 
 impl PositionBuilder {
@@ -324,7 +607,7 @@ impl PositionBuilder {
         }
     }
 
-    pub fn quantity(self, value: f64) -> Self {
+    pub fn quantity(self, value: Amount) -> Self {
         Self {
             quantity: Some(value),
             ..self
@@ -345,14 +628,21 @@ impl PositionBuilder {
         }
     }
 
-    pub fn enter_avg_price_gross(self, value: f64) -> Self {
+    pub fn enter_fees_realised(self, value: FeeAmount) -> Self {
+        Self {
+            enter_fees_realised: Some(value),
+            ..self
+        }
+    }
+
+    pub fn enter_avg_price_gross(self, value: Amount) -> Self {
         Self {
             enter_avg_price_gross: Some(value),
             ..self
         }
     }
 
-    pub fn enter_value_gross(self, value: f64) -> Self {
+    pub fn enter_value_gross(self, value: Amount) -> Self {
         Self {
             enter_value_gross: Some(value),
             ..self
@@ -373,48 +663,62 @@ impl PositionBuilder {
         }
     }
 
-    pub fn exit_avg_price_gross(self, value: f64) -> Self {
+    pub fn exit_avg_price_gross(self, value: Amount) -> Self {
         Self {
             exit_avg_price_gross: Some(value),
             ..self
         }
     }
 
-    pub fn exit_value_gross(self, value: f64) -> Self {
+    pub fn exit_value_gross(self, value: Amount) -> Self {
         Self {
             exit_value_gross: Some(value),
             ..self
         }
     }
 
-    pub fn current_symbol_price(self, value: f64) -> Self {
+    pub fn current_symbol_price(self, value: Amount) -> Self {
         Self {
             current_symbol_price: Some(value),
             ..self
         }
     }
 
-    pub fn current_value_gross(self, value: f64) -> Self {
+    pub fn current_value_gross(self, value: Amount) -> Self {
         Self {
             current_value_gross: Some(value),
             ..self
         }
     }
 
-    pub fn unrealised_profit_loss(self, value: f64) -> Self {
+    pub fn unrealised_profit_loss(self, value: Amount) -> Self {
         Self {
             unrealised_profit_loss: Some(value),
             ..self
         }
     }
 
-    pub fn realised_profit_loss(self, value: f64) -> Self {
+    pub fn realised_profit_loss(self, value: Amount) -> Self {
         Self {
             realised_profit_loss: Some(value),
             ..self
         }
     }
 
+    pub fn protective_exits(self, value: ProtectiveExits) -> Self {
+        Self {
+            protective_exits: Some(value),
+            ..self
+        }
+    }
+
+    pub fn margin(self, value: Margin) -> Self {
+        Self {
+            margin: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<Position, PortfolioError> {
         Ok(Position {
             position_id: self
@@ -437,6 +741,8 @@ impl PositionBuilder {
             enter_fees_total: self
                 .enter_fees_total
                 .ok_or(PortfolioError::BuilderIncomplete("enter_fees_total"))?,
+            // Positions without partial exits have not realised any enter fees
+            enter_fees_realised: self.enter_fees_realised.unwrap_or_default(),
             enter_avg_price_gross: self
                 .enter_avg_price_gross
                 .ok_or(PortfolioError::BuilderIncomplete("enter_avg_price_gross"))?,
@@ -467,13 +773,15 @@ impl PositionBuilder {
             realised_profit_loss: self
                 .realised_profit_loss
                 .ok_or(PortfolioError::BuilderIncomplete("realised_profit_loss"))?,
+            protective_exits: self.protective_exits.unwrap_or_default(),
+            margin: self.margin,
         })
     }
 }
 
 /// Metadata detailing the trace UUIDs & timestamps associated with entering, updating & exiting
 /// a [`Position`].
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct PositionMeta {
     /// [`FillEvent`] timestamp that triggered the entering of this [`Position`].
     pub enter_time: DateTime<Utc>,
@@ -485,29 +793,27 @@ pub struct PositionMeta {
     pub exit_balance: Option<Balance>,
 }
 
-impl Default for PositionMeta {
-    fn default() -> Self {
-        Self {
-            enter_time: Utc::now(),
-            update_time: Utc::now(),
-            exit_balance: None,
-        }
-    }
-}
-
-/// [`Position`] update event. Occurs as a result of receiving new [`MarketEvent`] data.
+/// [`Position`] update event. Occurs as a result of receiving new [`MarketEvent`] data, or a
+/// [`FillEvent`] that increases or partially reduces the [`Position`].
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct PositionUpdate {
     /// Unique identifier for a [`Position`], generated from an exchange, symbol, and enter_time.
     pub position_id: String,
     /// Event timestamp of the last event to trigger a [`Position`] update.
     pub update_time: DateTime<Utc>,
+    /// +ve or -ve quantity of symbol contracts open.
+    pub quantity: Amount,
     /// Symbol current close price.
-    pub current_symbol_price: f64,
+    pub current_symbol_price: Amount,
     /// abs(Quantity) * current_symbol_price.
-    pub current_value_gross: f64,
+    pub current_value_gross: Amount,
     /// Unrealised P&L whilst the [`Position`] is open.
-    pub unrealised_profit_loss: f64,
+    pub unrealised_profit_loss: Amount,
+    /// Realised P&L of the tranches exited by partial exits so far.
+    pub realised_profit_loss: Amount,
+    /// Protective exit triggered by this update, in which case the [`Position`] should be exited.
+    #[serde(default)]
+    pub exit_trigger: Option<ExitTrigger>,
 }
 
 impl From<&mut Position> for PositionUpdate {
@@ -515,9 +821,12 @@ impl From<&mut Position> for PositionUpdate {
         Self {
             position_id: updated_position.position_id.clone(),
             update_time: updated_position.meta.update_time,
+            quantity: updated_position.quantity,
             current_symbol_price: updated_position.current_symbol_price,
             current_value_gross: updated_position.current_value_gross,
             unrealised_profit_loss: updated_position.unrealised_profit_loss,
+            realised_profit_loss: updated_position.realised_profit_loss,
+            exit_trigger: None,
         }
     }
 }
@@ -541,13 +850,13 @@ pub struct PositionExit {
     pub exit_fees_total: FeeAmount,
 
     /// Exit average price excluding the exit_fees_total.
-    pub exit_avg_price_gross: f64,
+    pub exit_avg_price_gross: Amount,
 
     /// abs(Quantity) * exit_avg_price_gross.
-    pub exit_value_gross: f64,
+    pub exit_value_gross: Amount,
 
     /// Realised P&L after the [`Position`] has closed.
-    pub realised_profit_loss: f64,
+    pub realised_profit_loss: Amount,
 }
 
 impl TryFrom<&mut Position> for PositionExit {
@@ -573,26 +882,29 @@ impl TryFrom<&mut Position> for PositionExit {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_util::{fill_event, market_event_trade, position};
-    use barter_integration::model::Side;
+    use crate::{
+        portfolio::margin::MarginConfig,
+        test_util::{fill_event, market_event_trade, position},
+    };
+    use barter_integration::{amount, model::Side};
 
     #[test]
     fn enter_new_position_with_long_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = amount!(1.0);
+        input_fill.fill_value_gross = amount!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         let position = Position::enter(Uuid::new_v4(), &input_fill).unwrap();
 
         assert_eq!(position.side, Side::Buy);
         assert_eq!(position.quantity, input_fill.quantity);
-        assert_eq!(position.enter_fees_total, 3.0);
+        assert_eq!(position.enter_fees_total, amount!(3.0));
         assert_eq!(position.enter_fees.exchange, input_fill.fees.exchange);
         assert_eq!(position.enter_fees.slippage, input_fill.fees.slippage);
         assert_eq!(position.enter_fees.network, input_fill.fees.network);
@@ -601,35 +913,35 @@ mod tests {
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.enter_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.exit_fees_total, 0.0);
-        assert_eq!(position.exit_avg_price_gross, 0.0);
-        assert_eq!(position.exit_value_gross, 0.0);
+        assert_eq!(position.exit_fees_total, amount!(0.0));
+        assert_eq!(position.exit_avg_price_gross, amount!(0.0));
+        assert_eq!(position.exit_value_gross, amount!(0.0));
         assert_eq!(
             position.current_symbol_price,
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.current_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.unrealised_profit_loss, -6.0); // -2 * enter_fees_total
-        assert_eq!(position.realised_profit_loss, 0.0);
+        assert_eq!(position.unrealised_profit_loss, -amount!(6.0)); // -2 * enter_fees_total
+        assert_eq!(position.realised_profit_loss, amount!(0.0));
     }
 
     #[test]
     fn enter_new_position_with_short_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = -amount!(1.0);
+        input_fill.fill_value_gross = amount!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         let position = Position::enter(Uuid::new_v4(), &input_fill).unwrap();
 
         assert_eq!(position.side, Side::Sell);
         assert_eq!(position.quantity, input_fill.quantity);
-        assert_eq!(position.enter_fees_total, 3.0);
+        assert_eq!(position.enter_fees_total, amount!(3.0));
         assert_eq!(position.enter_fees.exchange, input_fill.fees.exchange);
         assert_eq!(position.enter_fees.slippage, input_fill.fees.slippage);
         assert_eq!(position.enter_fees.network, input_fill.fees.network);
@@ -638,28 +950,28 @@ mod tests {
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.enter_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.exit_fees_total, 0.0);
-        assert_eq!(position.exit_avg_price_gross, 0.0);
-        assert_eq!(position.exit_value_gross, 0.0);
+        assert_eq!(position.exit_fees_total, amount!(0.0));
+        assert_eq!(position.exit_avg_price_gross, amount!(0.0));
+        assert_eq!(position.exit_value_gross, amount!(0.0));
         assert_eq!(
             position.current_symbol_price,
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.current_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.unrealised_profit_loss, -6.0); // -2 * enter_fees_total
-        assert_eq!(position.realised_profit_loss, 0.0);
+        assert_eq!(position.unrealised_profit_loss, -amount!(6.0)); // -2 * enter_fees_total
+        assert_eq!(position.realised_profit_loss, amount!(0.0));
     }
 
     #[test]
     fn enter_new_position_and_return_err_with_close_long_decision_provided() -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = -amount!(1.0);
+        input_fill.fill_value_gross = amount!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
@@ -676,12 +988,12 @@ mod tests {
     {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = amount!(1.0);
+        input_fill.fill_value_gross = amount!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
@@ -698,12 +1010,12 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = -amount!(1.0);
+        input_fill.fill_value_gross = amount!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
@@ -720,12 +1032,12 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = amount!(1.0);
+        input_fill.fill_value_gross = amount!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
@@ -742,18 +1054,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = amount!(1.0);
+        position.enter_fees_total = amount!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = amount!(100.0);
+        position.enter_value_gross = amount!(100.0);
+        position.current_symbol_price = amount!(100.0);
+        position.current_value_gross = amount!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * -amount!(2.0);
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Buy);
@@ -769,20 +1081,20 @@ mod tests {
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, amount!(1.0));
+        assert_eq!(position.enter_fees_total, amount!(3.0));
+        assert_eq!(position.enter_fees.exchange, amount!(1.0));
+        assert_eq!(position.enter_fees.slippage, amount!(1.0));
+        assert_eq!(position.enter_fees.network, amount!(1.0));
+        assert_eq!(position.enter_avg_price_gross, amount!(100.0));
+        assert_eq!(position.enter_value_gross, amount!(100.0));
 
         // Assert updated fields are correct
-        let close = match &input_market.kind {
+        let close = Amount::from_f64_lossy(match &input_market.kind {
             DataKind::Trade(trade) => trade.price,
             DataKind::Candle(candle) => candle.close,
             _ => todo!(),
-        };
+        });
         assert_eq!(position.current_symbol_price, close);
         assert_eq!(
             position.current_value_gross,
@@ -790,7 +1102,10 @@ mod tests {
         );
 
         // current_value_gross - enter_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (200.0 - 100.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (amount!(200.0) - amount!(100.0) - amount!(6.0))
+        );
     }
 
     #[test]
@@ -798,18 +1113,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = amount!(1.0);
+        position.enter_fees_total = amount!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = amount!(100.0);
+        position.enter_value_gross = amount!(100.0);
+        position.current_symbol_price = amount!(100.0);
+        position.current_value_gross = amount!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * -amount!(2.0);
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Sell);
@@ -826,20 +1141,20 @@ mod tests {
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, amount!(1.0));
+        assert_eq!(position.enter_fees_total, amount!(3.0));
+        assert_eq!(position.enter_fees.exchange, amount!(1.0));
+        assert_eq!(position.enter_fees.slippage, amount!(1.0));
+        assert_eq!(position.enter_fees.network, amount!(1.0));
+        assert_eq!(position.enter_avg_price_gross, amount!(100.0));
+        assert_eq!(position.enter_value_gross, amount!(100.0));
 
         // Assert updated fields are correct
-        let close = match &input_market.kind {
+        let close = Amount::from_f64_lossy(match &input_market.kind {
             DataKind::Trade(trade) => trade.price,
             DataKind::Candle(candle) => candle.close,
             _ => todo!(),
-        };
+        });
         assert_eq!(position.current_symbol_price, close);
         assert_eq!(
             position.current_value_gross,
@@ -847,7 +1162,10 @@ mod tests {
         );
 
         // current_value_gross - enter_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (50.0 - 100.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (amount!(50.0) - amount!(100.0) - amount!(6.0))
+        );
     }
 
     #[test]
@@ -855,18 +1173,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = -amount!(1.0);
+        position.enter_fees_total = amount!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = amount!(100.0);
+        position.enter_value_gross = amount!(100.0);
+        position.current_symbol_price = amount!(100.0);
+        position.current_value_gross = amount!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * -amount!(2.0);
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Buy);
@@ -883,20 +1201,20 @@ mod tests {
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, -amount!(1.0));
+        assert_eq!(position.enter_fees_total, amount!(3.0));
+        assert_eq!(position.enter_fees.exchange, amount!(1.0));
+        assert_eq!(position.enter_fees.slippage, amount!(1.0));
+        assert_eq!(position.enter_fees.network, amount!(1.0));
+        assert_eq!(position.enter_avg_price_gross, amount!(100.0));
+        assert_eq!(position.enter_value_gross, amount!(100.0));
 
         // Assert updated fields are correct
-        let close = match &input_market.kind {
+        let close = Amount::from_f64_lossy(match &input_market.kind {
             DataKind::Trade(trade) => trade.price,
             DataKind::Candle(candle) => candle.close,
             _ => todo!(),
-        };
+        });
         assert_eq!(position.current_symbol_price, close);
         assert_eq!(
             position.current_value_gross,
@@ -904,7 +1222,10 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 50.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (amount!(100.0) - amount!(50.0) - amount!(6.0))
+        );
     }
 
     #[test]
@@ -912,18 +1233,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = -amount!(1.0);
+        position.enter_fees_total = amount!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = amount!(100.0);
+        position.enter_value_gross = amount!(100.0);
+        position.current_symbol_price = amount!(100.0);
+        position.current_value_gross = amount!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * -amount!(2.0);
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Sell);
@@ -940,20 +1261,20 @@ mod tests {
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, -amount!(1.0));
+        assert_eq!(position.enter_fees_total, amount!(3.0));
+        assert_eq!(position.enter_fees.exchange, amount!(1.0));
+        assert_eq!(position.enter_fees.slippage, amount!(1.0));
+        assert_eq!(position.enter_fees.network, amount!(1.0));
+        assert_eq!(position.enter_avg_price_gross, amount!(100.0));
+        assert_eq!(position.enter_value_gross, amount!(100.0));
 
         // Assert updated fields are correct
-        let close = match &input_market.kind {
+        let close = Amount::from_f64_lossy(match &input_market.kind {
             DataKind::Trade(trade) => trade.price,
             DataKind::Candle(candle) => candle.close,
             _ => todo!(),
-        };
+        });
         assert_eq!(position.current_symbol_price, close);
         assert_eq!(
             position.current_value_gross,
@@ -961,7 +1282,10 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 200.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (amount!(100.0) - amount!(200.0) - amount!(6.0))
+        );
     }
 
     #[test]
@@ -969,35 +1293,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = amount!(1.0);
+        position.enter_fees_total = amount!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = amount!(100.0);
+        position.enter_value_gross = amount!(100.0);
+        position.current_symbol_price = amount!(100.0);
+        position.current_value_gross = amount!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * -amount!(2.0);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: amount!(10000.0),
+            available: amount!(10000.0),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = amount!(200.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         // Exit Position
@@ -1005,19 +1329,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, amount!(1.0));
+        assert_eq!(position.enter_fees_total, amount!(3.0));
+        assert_eq!(position.enter_fees.exchange, amount!(1.0));
+        assert_eq!(position.enter_fees.slippage, amount!(1.0));
+        assert_eq!(position.enter_fees.network, amount!(1.0));
+        assert_eq!(position.enter_avg_price_gross, amount!(100.0));
+        assert_eq!(position.enter_value_gross, amount!(100.0));
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, amount!(3.0));
+        assert_eq!(position.exit_fees.exchange, amount!(1.0));
+        assert_eq!(position.exit_fees.slippage, amount!(1.0));
+        assert_eq!(position.exit_fees.network, amount!(1.0));
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1025,50 +1349,94 @@ mod tests {
         );
 
         // exit_value_gross - enter_value_gross - total_fees
-        assert_eq!(position.realised_profit_loss, (200.0 - 100.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (200.0 - 100.0 - 6.0));
+        assert_eq!(
+            position.realised_profit_loss,
+            (amount!(200.0) - amount!(100.0) - amount!(6.0))
+        );
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (amount!(200.0) - amount!(100.0) - amount!(6.0))
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (200.0 - 100.0 - 6.0)
+            current_balance.total + (amount!(200.0) - amount!(100.0) - amount!(6.0))
         )
     }
 
+    #[test]
+    fn exit_margined_long_position_realises_funding_profit_loss() {
+        // Initial margined Position
+        let mut position = position();
+        position.margin = Some(
+            MarginConfig {
+                leverage: 10.0,
+                maintenance_margin_rate: 0.005,
+            }
+            .margin(Side::Buy, amount!(1.0), amount!(100.0)),
+        );
+
+        // Long pays the positive funding rate
+        let mut funding_event = market_event_trade(Side::Buy);
+        funding_event.kind = DataKind::FundingRate(FundingRate {
+            rate: 0.001,
+            mark_price: 100.0,
+            time: funding_event.exchange_time,
+        });
+        let position_update = position.update(&funding_event).unwrap();
+        assert_eq!(position_update.realised_profit_loss, -amount!(0.1));
+        assert_eq!(position_update.exit_trigger, None);
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseLong;
+        input_fill.quantity = -amount!(1.0);
+        input_fill.fill_value_gross = amount!(110.0);
+
+        // Exit Position
+        position.exit(Balance::default(), &input_fill).unwrap();
+
+        // exit_value_gross - enter_value_gross - total_fees + funding
+        assert!(
+            (position.realised_profit_loss.to_f64_lossy() - (110.0 - 100.0 - 0.1)).abs() < 1e-9
+        );
+    }
+
     #[test]
     fn exit_long_position_with_negative_real_pnl() {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = amount!(1.0);
+        position.enter_fees_total = amount!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = amount!(100.0);
+        position.enter_value_gross = amount!(100.0);
+        position.current_symbol_price = amount!(100.0);
+        position.current_value_gross = amount!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * -amount!(2.0);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: amount!(10000.0),
+            available: amount!(10000.0),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.fill_value_gross = amount!(50.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         // Exit Position
@@ -1076,19 +1444,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, amount!(1.0));
+        assert_eq!(position.enter_fees_total, amount!(3.0));
+        assert_eq!(position.enter_fees.exchange, amount!(1.0));
+        assert_eq!(position.enter_fees.slippage, amount!(1.0));
+        assert_eq!(position.enter_fees.network, amount!(1.0));
+        assert_eq!(position.enter_avg_price_gross, amount!(100.0));
+        assert_eq!(position.enter_value_gross, amount!(100.0));
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, amount!(3.0));
+        assert_eq!(position.exit_fees.exchange, amount!(1.0));
+        assert_eq!(position.exit_fees.slippage, amount!(1.0));
+        assert_eq!(position.exit_fees.network, amount!(1.0));
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1096,13 +1464,19 @@ mod tests {
         );
 
         // exit_value_gross - enter_value_gross - total_fees
-        assert_eq!(position.realised_profit_loss, (50.0 - 100.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (50.0 - 100.0 - 6.0));
+        assert_eq!(
+            position.realised_profit_loss,
+            (amount!(50.0) - amount!(100.0) - amount!(6.0))
+        );
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (amount!(50.0) - amount!(100.0) - amount!(6.0))
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (50.0 - 100.0 - 6.0)
+            current_balance.total + (amount!(50.0) - amount!(100.0) - amount!(6.0))
         )
     }
 
@@ -1111,35 +1485,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = -amount!(1.0);
+        position.enter_fees_total = amount!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = amount!(100.0);
+        position.enter_value_gross = amount!(100.0);
+        position.current_symbol_price = amount!(100.0);
+        position.current_value_gross = amount!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * -amount!(2.0);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: amount!(10000.0),
+            available: amount!(10000.0),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.fill_value_gross = amount!(50.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         // Exit Position
@@ -1147,19 +1521,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, -amount!(1.0));
+        assert_eq!(position.enter_fees_total, amount!(3.0));
+        assert_eq!(position.enter_fees.exchange, amount!(1.0));
+        assert_eq!(position.enter_fees.slippage, amount!(1.0));
+        assert_eq!(position.enter_fees.network, amount!(1.0));
+        assert_eq!(position.enter_avg_price_gross, amount!(100.0));
+        assert_eq!(position.enter_value_gross, amount!(100.0));
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, amount!(3.0));
+        assert_eq!(position.exit_fees.exchange, amount!(1.0));
+        assert_eq!(position.exit_fees.slippage, amount!(1.0));
+        assert_eq!(position.exit_fees.network, amount!(1.0));
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1167,13 +1541,19 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.realised_profit_loss, (100.0 - 50.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 50.0 - 6.0));
+        assert_eq!(
+            position.realised_profit_loss,
+            (amount!(100.0) - amount!(50.0) - amount!(6.0))
+        );
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (amount!(100.0) - amount!(50.0) - amount!(6.0))
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (100.0 - 50.0 - 6.0)
+            current_balance.total + (amount!(100.0) - amount!(50.0) - amount!(6.0))
         )
     }
 
@@ -1182,35 +1562,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = -amount!(1.0);
+        position.enter_fees_total = amount!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = amount!(100.0);
+        position.enter_value_gross = amount!(100.0);
+        position.current_symbol_price = amount!(100.0);
+        position.current_value_gross = amount!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * -amount!(2.0);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: amount!(10000.0),
+            available: amount!(10000.0),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = amount!(200.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         // Exit Position
@@ -1218,19 +1598,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, -amount!(1.0));
+        assert_eq!(position.enter_fees_total, amount!(3.0));
+        assert_eq!(position.enter_fees.exchange, amount!(1.0));
+        assert_eq!(position.enter_fees.slippage, amount!(1.0));
+        assert_eq!(position.enter_fees.network, amount!(1.0));
+        assert_eq!(position.enter_avg_price_gross, amount!(100.0));
+        assert_eq!(position.enter_value_gross, amount!(100.0));
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, amount!(3.0));
+        assert_eq!(position.exit_fees.exchange, amount!(1.0));
+        assert_eq!(position.exit_fees.slippage, amount!(1.0));
+        assert_eq!(position.exit_fees.network, amount!(1.0));
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1238,13 +1618,19 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.realised_profit_loss, (100.0 - 200.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 200.0 - 6.0));
+        assert_eq!(
+            position.realised_profit_loss,
+            (amount!(100.0) - amount!(200.0) - amount!(6.0))
+        );
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (amount!(100.0) - amount!(200.0) - amount!(6.0))
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (100.0 - 200.0 - 6.0)
+            current_balance.total + (amount!(100.0) - amount!(200.0) - amount!(6.0))
         )
     }
 
@@ -1253,35 +1639,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = -amount!(1.0);
+        position.enter_fees_total = amount!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = amount!(100.0);
+        position.enter_value_gross = amount!(100.0);
+        position.current_symbol_price = amount!(100.0);
+        position.current_value_gross = amount!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * -amount!(2.0);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: amount!(10000.0),
+            available: amount!(10000.0),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
         input_fill.quantity = position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = amount!(200.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         // Exit Position
@@ -1299,35 +1685,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = -amount!(1.0);
+        position.enter_fees_total = amount!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = amount!(100.0);
+        position.enter_value_gross = amount!(100.0);
+        position.current_symbol_price = amount!(100.0);
+        position.current_value_gross = amount!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * -amount!(2.0);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: amount!(10000.0),
+            available: amount!(10000.0),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = amount!(200.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: amount!(1.0),
+            slippage: amount!(1.0),
+            network: amount!(1.0),
         };
 
         // Exit Position
@@ -1343,30 +1729,30 @@ mod tests {
     #[test]
     fn calculate_avg_price_gross_correctly_with_positive_quantity() {
         let mut input_fill = fill_event();
-        input_fill.fill_value_gross = 1000.0;
-        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = amount!(1000.0);
+        input_fill.quantity = amount!(1.0);
 
         let actual = Position::calculate_avg_price_gross(&input_fill);
 
-        assert_eq!(actual, 1000.0)
+        assert_eq!(actual, amount!(1000.0))
     }
 
     #[test]
     fn calculate_avg_price_gross_correctly_with_negative_quantity() {
         let mut input_fill = fill_event();
-        input_fill.fill_value_gross = 1000.0;
-        input_fill.quantity = -1.0;
+        input_fill.fill_value_gross = amount!(1000.0);
+        input_fill.quantity = -amount!(1.0);
 
         let actual = Position::calculate_avg_price_gross(&input_fill);
 
-        assert_eq!(actual, 1000.0)
+        assert_eq!(actual, amount!(1000.0))
     }
 
     #[test]
     fn parse_entry_side_as_long_with_positive_quantity_long_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = 1.0;
+        input_fill.quantity = amount!(1.0);
 
         let actual = Position::parse_entry_side(&input_fill).unwrap();
 
@@ -1377,7 +1763,7 @@ mod tests {
     fn parse_entry_side_as_short_with_negative_quantity_short_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = -1.0;
+        input_fill.quantity = -amount!(1.0);
 
         let actual = Position::parse_entry_side(&input_fill).unwrap();
 
@@ -1388,7 +1774,7 @@ mod tests {
     fn parse_entry_side_and_return_err_with_close_long_decision_provided() -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
+        input_fill.quantity = -amount!(1.0);
 
         if let Err(_) = Position::parse_entry_side(&input_fill) {
             Ok(())
@@ -1403,7 +1789,7 @@ mod tests {
     fn parse_entry_side_and_return_err_with_close_short_decision_provided() -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
+        input_fill.quantity = amount!(1.0);
 
         if let Err(_) = Position::parse_entry_side(&input_fill) {
             Ok(())
@@ -1419,7 +1805,7 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = -1.0;
+        input_fill.quantity = -amount!(1.0);
 
         if let Err(_) = Position::parse_entry_side(&input_fill) {
             Ok(())
@@ -1435,7 +1821,7 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = 1.0;
+        input_fill.quantity = amount!(1.0);
 
         if let Err(_) = Position::parse_entry_side(&input_fill) {
             Ok(())
@@ -1450,31 +1836,31 @@ mod tests {
     fn calculate_unreal_profit_loss() {
         let mut long_win = position(); // Expected PnL = +8.0
         long_win.side = Side::Buy;
-        long_win.enter_value_gross = 100.0;
-        long_win.enter_fees_total = 1.0;
-        long_win.current_value_gross = 110.0;
+        long_win.enter_value_gross = amount!(100.0);
+        long_win.enter_fees_total = amount!(1.0);
+        long_win.current_value_gross = amount!(110.0);
 
         let mut long_lose = position(); // Expected PnL = -12.0
         long_lose.side = Side::Buy;
-        long_lose.enter_value_gross = 100.0;
-        long_lose.enter_fees_total = 1.0;
-        long_lose.current_value_gross = 90.0;
+        long_lose.enter_value_gross = amount!(100.0);
+        long_lose.enter_fees_total = amount!(1.0);
+        long_lose.current_value_gross = amount!(90.0);
 
         let mut short_win = position(); // Expected PnL = +8.0
         short_win.side = Side::Sell;
-        short_win.enter_value_gross = 100.0;
-        short_win.enter_fees_total = 1.0;
-        short_win.current_value_gross = 90.0;
+        short_win.enter_value_gross = amount!(100.0);
+        short_win.enter_fees_total = amount!(1.0);
+        short_win.current_value_gross = amount!(90.0);
 
         let mut short_lose = position(); // Expected PnL = -12.0
         short_lose.side = Side::Sell;
-        short_lose.enter_value_gross = 100.0;
-        short_lose.enter_fees_total = 1.0;
-        short_lose.current_value_gross = 110.0;
+        short_lose.enter_value_gross = amount!(100.0);
+        short_lose.enter_fees_total = amount!(1.0);
+        short_lose.current_value_gross = amount!(110.0);
 
         let inputs = vec![long_win, long_lose, short_win, short_lose];
 
-        let expected_pnl = vec![8.0, -12.0, 8.0, -12.0];
+        let expected_pnl = vec![amount!(8.0), -amount!(12.0), amount!(8.0), -amount!(12.0)];
 
         for (position, expected) in inputs.into_iter().zip(expected_pnl.into_iter()) {
             let actual = position.calculate_unrealised_profit_loss();
@@ -1486,35 +1872,35 @@ mod tests {
     fn calculate_realised_profit_loss() {
         let mut long_win = position(); // Expected PnL = +18.0
         long_win.side = Side::Buy;
-        long_win.enter_value_gross = 100.0;
-        long_win.enter_fees_total = 1.0;
-        long_win.exit_value_gross = 120.0;
-        long_win.exit_fees_total = 1.0;
+        long_win.enter_value_gross = amount!(100.0);
+        long_win.enter_fees_total = amount!(1.0);
+        long_win.exit_value_gross = amount!(120.0);
+        long_win.exit_fees_total = amount!(1.0);
 
         let mut long_lose = position(); // Expected PnL = -22.0
         long_lose.side = Side::Buy;
-        long_lose.enter_value_gross = 100.0;
-        long_lose.enter_fees_total = 1.0;
-        long_lose.exit_value_gross = 80.0;
-        long_lose.exit_fees_total = 1.0;
+        long_lose.enter_value_gross = amount!(100.0);
+        long_lose.enter_fees_total = amount!(1.0);
+        long_lose.exit_value_gross = amount!(80.0);
+        long_lose.exit_fees_total = amount!(1.0);
 
         let mut short_win = position(); // Expected PnL = +18.0
         short_win.side = Side::Sell;
-        short_win.enter_value_gross = 100.0;
-        short_win.enter_fees_total = 1.0;
-        short_win.exit_value_gross = 80.0;
-        short_win.exit_fees_total = 1.0;
+        short_win.enter_value_gross = amount!(100.0);
+        short_win.enter_fees_total = amount!(1.0);
+        short_win.exit_value_gross = amount!(80.0);
+        short_win.exit_fees_total = amount!(1.0);
 
         let mut short_lose = position(); // Expected PnL = -22.0
         short_lose.side = Side::Sell;
-        short_lose.enter_value_gross = 100.0;
-        short_lose.enter_fees_total = 1.0;
-        short_lose.exit_value_gross = 120.0;
-        short_lose.exit_fees_total = 1.0;
+        short_lose.enter_value_gross = amount!(100.0);
+        short_lose.enter_fees_total = amount!(1.0);
+        short_lose.exit_value_gross = amount!(120.0);
+        short_lose.exit_fees_total = amount!(1.0);
 
         let inputs = vec![long_win, long_lose, short_win, short_lose];
 
-        let expected_pnl = vec![18.0, -22.0, 18.0, -22.0];
+        let expected_pnl = vec![amount!(18.0), -amount!(22.0), amount!(18.0), -amount!(22.0)];
 
         for (position, expected) in inputs.into_iter().zip(expected_pnl.into_iter()) {
             let actual = position.calculate_realised_profit_loss();
@@ -1526,23 +1912,23 @@ mod tests {
     fn calculate_profit_loss_return() {
         let mut long_win = position(); // Expected Return = 0.08
         long_win.side = Side::Buy;
-        long_win.enter_value_gross = 100.0;
-        long_win.realised_profit_loss = 8.0;
+        long_win.enter_value_gross = amount!(100.0);
+        long_win.realised_profit_loss = amount!(8.0);
 
         let mut long_lose = position(); // Expected Return = -0.12
         long_lose.side = Side::Buy;
-        long_lose.enter_value_gross = 100.0;
-        long_lose.realised_profit_loss = -12.0;
+        long_lose.enter_value_gross = amount!(100.0);
+        long_lose.realised_profit_loss = -amount!(12.0);
 
         let mut short_win = position(); // Expected Return = 0.08
         short_win.side = Side::Sell;
-        short_win.enter_value_gross = 100.0;
-        short_win.realised_profit_loss = 8.0;
+        short_win.enter_value_gross = amount!(100.0);
+        short_win.realised_profit_loss = amount!(8.0);
 
         let mut short_lose = position(); // Expected Return = -0.12
         short_lose.side = Side::Sell;
-        short_lose.enter_value_gross = 100.0;
-        short_lose.realised_profit_loss = -12.0;
+        short_lose.enter_value_gross = amount!(100.0);
+        short_lose.realised_profit_loss = -amount!(12.0);
 
         let inputs = vec![long_win, long_lose, short_win, short_lose];
 
@@ -1566,12 +1952,199 @@ mod tests {
         assert_eq!(position.determine_exit_decision(), Decision::CloseShort);
     }
 
+    #[test]
+    fn increase_long_position_updates_weighted_average_enter_price() {
+        let mut position = position();
+        position.quantity = amount!(1.0);
+        position.enter_avg_price_gross = amount!(100.0);
+        position.enter_value_gross = amount!(100.0);
+        position.enter_fees_total = amount!(1.0);
+        position.current_symbol_price = amount!(100.0);
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = amount!(1.0);
+        input_fill.fill_value_gross = amount!(200.0);
+        input_fill.fees = Fees {
+            exchange: amount!(1.0),
+            slippage: amount!(0.0),
+            network: amount!(0.0),
+        };
+
+        let update = position.increase(&input_fill).unwrap();
+
+        assert_eq!(position.quantity, amount!(2.0));
+        assert_eq!(position.enter_avg_price_gross, amount!(150.0));
+        assert_eq!(position.enter_value_gross, amount!(300.0));
+        assert_eq!(position.enter_fees_total, amount!(2.0));
+        assert_eq!(position.current_value_gross, amount!(200.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            amount!(200.0) - amount!(300.0) - amount!(4.0)
+        );
+        assert_eq!(update.quantity, amount!(2.0));
+        assert_eq!(update.realised_profit_loss, amount!(0.0));
+    }
+
+    #[test]
+    fn increase_long_position_with_short_entry_fill_and_return_err() {
+        let mut position = position();
+        position.side = Side::Buy;
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Short;
+        input_fill.quantity = amount!(1.0);
+
+        assert!(position.increase(&input_fill).is_err());
+    }
+
+    #[test]
+    fn reduce_long_position_realises_tranche_profit_loss_then_exit_realises_remainder() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = amount!(2.0);
+        position.enter_avg_price_gross = amount!(100.0);
+        position.enter_value_gross = amount!(200.0);
+        position.enter_fees_total = amount!(2.0);
+        position.current_symbol_price = amount!(100.0);
+
+        // Partially reduce half of the Position
+        let mut reduce_fill = fill_event();
+        reduce_fill.decision = Decision::CloseLong;
+        reduce_fill.quantity = -amount!(1.0);
+        reduce_fill.fill_value_gross = amount!(150.0);
+        reduce_fill.fees = Fees {
+            exchange: amount!(1.0),
+            slippage: amount!(0.0),
+            network: amount!(0.0),
+        };
+
+        let update = position.reduce(&reduce_fill).unwrap();
+
+        // tranche_exit_value - tranche_enter_value - tranche_enter_fees - tranche_exit_fees
+        assert_eq!(
+            position.realised_profit_loss,
+            amount!(150.0) - amount!(100.0) - amount!(1.0) - amount!(1.0)
+        );
+        assert_eq!(update.realised_profit_loss, position.realised_profit_loss);
+        assert_eq!(position.quantity, amount!(1.0));
+        assert_eq!(position.enter_avg_price_gross, amount!(100.0));
+        assert_eq!(position.enter_fees_realised, amount!(1.0));
+        assert_eq!(position.exit_value_gross, amount!(150.0));
+        assert_eq!(position.exit_avg_price_gross, amount!(150.0));
+        assert_eq!(position.current_value_gross, amount!(100.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            amount!(100.0) - amount!(100.0) - amount!(2.0)
+        );
+
+        // Exit the remaining half of the Position
+        let mut exit_fill = fill_event();
+        exit_fill.decision = Decision::CloseLong;
+        exit_fill.quantity = -amount!(1.0);
+        exit_fill.fill_value_gross = amount!(50.0);
+        exit_fill.fees = Fees {
+            exchange: amount!(1.0),
+            slippage: amount!(0.0),
+            network: amount!(0.0),
+        };
+
+        let current_balance = Balance {
+            time: Utc::now(),
+            total: amount!(10000.0),
+            available: amount!(10000.0),
+        };
+
+        let exit = position.exit(current_balance, &exit_fill).unwrap();
+
+        // Total exit_value_gross - enter_value_gross - total_fees across every tranche
+        assert_eq!(
+            position.realised_profit_loss,
+            amount!(200.0) - amount!(200.0) - amount!(4.0)
+        );
+        assert_eq!(position.exit_avg_price_gross, amount!(100.0));
+        assert_eq!(position.exit_fees_total, amount!(2.0));
+
+        // Exit Balance only includes the PnL of the final tranche
+        assert_eq!(
+            exit.exit_balance.total,
+            amount!(10000.0) + (amount!(50.0) - amount!(100.0) - amount!(1.0) - amount!(1.0))
+        );
+    }
+
+    #[test]
+    fn reduce_position_with_fill_exceeding_quantity_and_return_err() {
+        let mut position = position();
+        position.quantity = amount!(1.0);
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseLong;
+        input_fill.quantity = -amount!(2.0);
+
+        assert!(position.reduce(&input_fill).is_err());
+    }
+
+    #[test]
+    fn determine_fill_impact_for_long_position() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = amount!(1.0);
+
+        let cases = vec![
+            (1.0, FillImpact::Increase),
+            (-0.5, FillImpact::Reduce),
+            (-1.0, FillImpact::Exit),
+            (-(0.1 + 0.2) / 0.3, FillImpact::Exit),
+            (-2.0, FillImpact::Flip),
+        ];
+
+        for (index, (quantity, expected)) in cases.into_iter().enumerate() {
+            let mut input_fill = fill_event();
+            input_fill.quantity = Amount::from_f64_lossy(quantity);
+            assert_eq!(
+                position.determine_fill_impact(&input_fill),
+                expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn split_flip_fill_of_long_position_pro_rata() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = amount!(1.0);
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Short;
+        input_fill.quantity = -amount!(2.0);
+        input_fill.fill_value_gross = amount!(200.0);
+        input_fill.fees = Fees {
+            exchange: amount!(2.0),
+            slippage: amount!(0.0),
+            network: amount!(0.0),
+        };
+
+        let (exit_fill, enter_fill) = position.split_flip_fill(&input_fill);
+
+        assert_eq!(exit_fill.decision, Decision::CloseLong);
+        assert_eq!(exit_fill.quantity, -amount!(1.0));
+        assert_eq!(exit_fill.fill_value_gross, amount!(100.0));
+        assert_eq!(exit_fill.fees.exchange, amount!(1.0));
+
+        assert_eq!(enter_fill.decision, Decision::Short);
+        assert_eq!(enter_fill.quantity, -amount!(1.0));
+        assert_eq!(enter_fill.fill_value_gross, amount!(100.0));
+        assert_eq!(enter_fill.fees.exchange, amount!(1.0));
+    }
+
     #[test]
     fn position_update_from_position() {
         let mut input_position = position();
-        input_position.current_symbol_price = 100.0;
-        input_position.current_value_gross = 200.0;
-        input_position.unrealised_profit_loss = 150.0;
+        input_position.current_symbol_price = amount!(100.0);
+        input_position.current_value_gross = amount!(200.0);
+        input_position.unrealised_profit_loss = amount!(150.0);
 
         let actual_update = PositionUpdate::from(&mut input_position);
 
@@ -1597,19 +2170,19 @@ mod tests {
         exited_position.meta.update_time = time;
         exited_position.meta.exit_balance = Some(Balance {
             time,
-            total: 0.0,
-            available: 0.0,
+            total: amount!(0.0),
+            available: amount!(0.0),
         });
 
         exited_position.exit_fees = Fees {
-            exchange: 0.0,
-            slippage: 0.0,
-            network: 0.0,
+            exchange: amount!(0.0),
+            slippage: amount!(0.0),
+            network: amount!(0.0),
         };
-        exited_position.exit_fees_total = 0.0;
-        exited_position.exit_avg_price_gross = 100.0;
-        exited_position.exit_value_gross = 100.0;
-        exited_position.realised_profit_loss = 100.0;
+        exited_position.exit_fees_total = amount!(0.0);
+        exited_position.exit_avg_price_gross = amount!(100.0);
+        exited_position.exit_value_gross = amount!(100.0);
+        exited_position.realised_profit_loss = amount!(100.0);
 
         let actual_exit = PositionExit::try_from(&mut exited_position).unwrap();
 
@@ -1640,4 +2213,42 @@ mod tests {
 
         assert!(PositionExit::try_from(&mut exited_position).is_err());
     }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn decimal_amounts_accumulate_fees_and_profit_loss_exactly() {
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = amount!(0.1);
+        input_fill.fill_value_gross = amount!(0.3);
+        input_fill.fees = Fees {
+            exchange: amount!(0.1),
+            slippage: amount!(0.0),
+            network: amount!(0.0),
+        };
+
+        // Enter & increase the Position with ten identical fills
+        let mut position = Position::enter(Uuid::new_v4(), &input_fill).unwrap();
+        for _ in 0..9 {
+            position.increase(&input_fill).unwrap();
+        }
+
+        assert_eq!(position.quantity, amount!(1.0));
+        assert_eq!(position.enter_value_gross, amount!(3.0));
+        assert_eq!(position.enter_fees_total, amount!(1.0));
+
+        // Exit the entire Position
+        let exit_fill = FillEvent {
+            decision: Decision::CloseLong,
+            quantity: -amount!(1.0),
+            fill_value_gross: amount!(3.3),
+            ..input_fill
+        };
+        let balance = Balance::new(Utc::now(), amount!(100.0), amount!(100.0));
+        let exit = position.exit(balance, &exit_fill).unwrap();
+
+        // Realised P&L = 3.3 - 3.0 - (1.0 + 0.1) = -0.8, with no rounding error
+        assert_eq!(exit.realised_profit_loss, -amount!(0.8));
+        assert_eq!(exit.exit_balance.total, amount!(99.2));
+    }
 }
@@ -0,0 +1,306 @@
+use barter_integration::model::Side;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Distance from a reference price, either as an absolute price or as a percentage of the
+/// reference price.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum PriceOffset {
+    /// Absolute price distance (eg/ 50.0 for $50).
+    Price(f64),
+    /// Percentage of the reference price in decimal form (eg/ 0.05 for 5%).
+    Percent(f64),
+}
+
+impl PriceOffset {
+    /// Calculates the absolute price distance of the [`PriceOffset`] from the reference price.
+    pub fn distance(&self, reference_price: f64) -> f64 {
+        match self {
+            PriceOffset::Price(distance) => *distance,
+            PriceOffset::Percent(percent) => reference_price * percent,
+        }
+    }
+}
+
+/// Configuration of the protective exits applied to every
+/// [`Position`](crate::portfolio::position::Position) when it is entered.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct ProtectiveExitConfig {
+    /// Fixed stop loss, offset against the enter price.
+    pub stop_loss: Option<PriceOffset>,
+    /// Fixed take profit, offset in favour of the enter price.
+    pub take_profit: Option<PriceOffset>,
+    /// Stop loss that trails the best price seen since entry by the offset.
+    pub trailing_stop: Option<PriceOffset>,
+    /// Maximum duration a [`Position`](crate::portfolio::position::Position) may be held before
+    /// it is exited.
+    pub max_holding_period: Option<Duration>,
+}
+
+impl ProtectiveExitConfig {
+    /// Derives the [`ProtectiveExits`] of a [`Position`](crate::portfolio::position::Position)
+    /// on the provided [`Side`], entered at the provided price & time.
+    pub fn protective_exits(
+        &self,
+        side: Side,
+        enter_price: f64,
+        enter_time: DateTime<Utc>,
+    ) -> ProtectiveExits {
+        // Offset a price against (-ve direction) or in favour of (+ve direction) the Position
+        let offset = |offset: PriceOffset, direction: f64| {
+            let distance = offset.distance(enter_price) * direction;
+            match side {
+                Side::Buy => enter_price + distance,
+                Side::Sell => enter_price - distance,
+            }
+        };
+
+        ProtectiveExits {
+            stop_loss: self.stop_loss.map(|stop_loss| offset(stop_loss, -1.0)),
+            take_profit: self.take_profit.map(|take_profit| offset(take_profit, 1.0)),
+            trailing_stop: self.trailing_stop,
+            trailing_stop_price: self
+                .trailing_stop
+                .map(|trailing_stop| offset(trailing_stop, -1.0)),
+            expiry_time: self.max_holding_period.and_then(|max_holding_period| {
+                chrono::Duration::from_std(max_holding_period)
+                    .ok()
+                    .map(|max_holding_period| enter_time + max_holding_period)
+            }),
+            triggered: None,
+        }
+    }
+}
+
+/// Protective exit levels of an open [`Position`](crate::portfolio::position::Position),
+/// derived from a [`ProtectiveExitConfig`] when it was entered.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct ProtectiveExits {
+    /// Price at which the Position is exited to limit losses.
+    pub stop_loss: Option<f64>,
+    /// Price at which the Position is exited to realise profits.
+    pub take_profit: Option<f64>,
+    /// Offset the trailing_stop_price trails the best price seen since entry by.
+    pub trailing_stop: Option<PriceOffset>,
+    /// Current trailing stop price, which only ever moves in favour of the Position.
+    pub trailing_stop_price: Option<f64>,
+    /// Time after which the Position is exited due to it's maximum holding period.
+    pub expiry_time: Option<DateTime<Utc>>,
+    /// [`ExitTrigger`] that was triggered, if any. Each Position is only triggered once, unless
+    /// re-armed (see [`ProtectiveExits::rearm`]) because the exit it triggered failed.
+    pub triggered: Option<ExitTrigger>,
+}
+
+/// Protective exit that triggered the exit of an open
+/// [`Position`](crate::portfolio::position::Position).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum ExitTrigger {
+    StopLoss,
+    TakeProfit,
+    TrailingStop,
+    MaxHoldingPeriod,
+    /// Liquidation price of a margined Position was breached.
+    Liquidation,
+}
+
+impl ProtectiveExits {
+    /// Updates the [`ProtectiveExits`] of a Position on the provided [`Side`] using the latest
+    /// price & time, returning the [`ExitTrigger`] if one is newly triggered.
+    pub fn update(&mut self, side: Side, price: f64, time: DateTime<Utc>) -> Option<ExitTrigger> {
+        if self.triggered.is_some() {
+            return None;
+        }
+
+        // Ratchet the trailing stop price in favour of the Position
+        if let (Some(trailing_stop), Some(trailing_stop_price)) =
+            (self.trailing_stop, self.trailing_stop_price.as_mut())
+        {
+            let distance = trailing_stop.distance(price);
+            *trailing_stop_price = match side {
+                Side::Buy => trailing_stop_price.max(price - distance),
+                Side::Sell => trailing_stop_price.min(price + distance),
+            };
+        }
+
+        // Determine if the price is at or beyond a level against (or in favour of) the Position
+        let against = |level: f64| match side {
+            Side::Buy => price <= level,
+            Side::Sell => price >= level,
+        };
+        let in_favour = |level: f64| match side {
+            Side::Buy => price >= level,
+            Side::Sell => price <= level,
+        };
+
+        self.triggered = if self.stop_loss.is_some_and(against) {
+            Some(ExitTrigger::StopLoss)
+        } else if self.trailing_stop_price.is_some_and(against) {
+            Some(ExitTrigger::TrailingStop)
+        } else if self.take_profit.is_some_and(in_favour) {
+            Some(ExitTrigger::TakeProfit)
+        } else if self.expiry_time.is_some_and(|expiry| time >= expiry) {
+            Some(ExitTrigger::MaxHoldingPeriod)
+        } else {
+            None
+        };
+
+        self.triggered
+    }
+
+    /// Re-arms the [`ProtectiveExits`] after the exit they triggered failed, so the next update
+    /// at or beyond a level triggers them again.
+    pub fn rearm(&mut self) {
+        self.triggered = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn protective_exits_derived_from_config_for_long_and_short() {
+        let config = ProtectiveExitConfig {
+            stop_loss: Some(PriceOffset::Percent(0.1)),
+            take_profit: Some(PriceOffset::Price(20.0)),
+            trailing_stop: Some(PriceOffset::Price(5.0)),
+            max_holding_period: Some(Duration::from_secs(60)),
+        };
+        let enter_time = Utc::now();
+
+        let long = config.protective_exits(Side::Buy, 100.0, enter_time);
+        assert_eq!(long.stop_loss, Some(90.0));
+        assert_eq!(long.take_profit, Some(120.0));
+        assert_eq!(long.trailing_stop_price, Some(95.0));
+        assert_eq!(
+            long.expiry_time,
+            Some(enter_time + chrono::Duration::seconds(60))
+        );
+
+        let short = config.protective_exits(Side::Sell, 100.0, enter_time);
+        assert_eq!(short.stop_loss, Some(110.0));
+        assert_eq!(short.take_profit, Some(80.0));
+        assert_eq!(short.trailing_stop_price, Some(105.0));
+    }
+
+    #[test]
+    fn trailing_stop_ratchets_in_favour_of_long_position_and_triggers_once() {
+        let config = ProtectiveExitConfig {
+            trailing_stop: Some(PriceOffset::Percent(0.1)),
+            ..Default::default()
+        };
+        let time = Utc::now();
+        let mut exits = config.protective_exits(Side::Buy, 100.0, time);
+
+        // Price rising ratchets the trailing stop up, but falling does not move it down
+        assert_eq!(exits.update(Side::Buy, 200.0, time), None);
+        assert_eq!(exits.trailing_stop_price, Some(180.0));
+        assert_eq!(exits.update(Side::Buy, 190.0, time), None);
+        assert_eq!(exits.trailing_stop_price, Some(180.0));
+
+        // Price falling to the trailing stop triggers it, but only once
+        assert_eq!(
+            exits.update(Side::Buy, 180.0, time),
+            Some(ExitTrigger::TrailingStop)
+        );
+        assert_eq!(exits.update(Side::Buy, 170.0, time), None);
+        assert_eq!(exits.triggered, Some(ExitTrigger::TrailingStop));
+    }
+
+    #[test]
+    fn rearmed_stop_loss_triggers_again_while_still_breached() {
+        let config = ProtectiveExitConfig {
+            stop_loss: Some(PriceOffset::Price(10.0)),
+            ..Default::default()
+        };
+        let time = Utc::now();
+        let mut exits = config.protective_exits(Side::Buy, 100.0, time);
+
+        assert_eq!(
+            exits.update(Side::Buy, 80.0, time),
+            Some(ExitTrigger::StopLoss)
+        );
+        assert_eq!(exits.update(Side::Buy, 80.0, time), None);
+
+        // Re-arming after the triggered exit failed allows the breached stop loss to trigger again
+        exits.rearm();
+        assert_eq!(exits.triggered, None);
+        assert_eq!(
+            exits.update(Side::Buy, 80.0, time),
+            Some(ExitTrigger::StopLoss)
+        );
+    }
+
+    #[test]
+    fn protective_exits_trigger_for_short_position() {
+        struct TestCase {
+            config: ProtectiveExitConfig,
+            price: f64,
+            elapsed_secs: i64,
+            expected: Option<ExitTrigger>,
+        }
+
+        let stop_loss = ProtectiveExitConfig {
+            stop_loss: Some(PriceOffset::Price(10.0)),
+            ..Default::default()
+        };
+        let take_profit = ProtectiveExitConfig {
+            take_profit: Some(PriceOffset::Price(10.0)),
+            ..Default::default()
+        };
+        let max_holding_period = ProtectiveExitConfig {
+            max_holding_period: Some(Duration::from_secs(60)),
+            ..Default::default()
+        };
+
+        let cases = vec![
+            TestCase {
+                // TC0: Price rising to the stop loss triggers it
+                config: stop_loss,
+                price: 110.0,
+                elapsed_secs: 0,
+                expected: Some(ExitTrigger::StopLoss),
+            },
+            TestCase {
+                // TC1: Price falling does not trigger the stop loss
+                config: stop_loss,
+                price: 90.0,
+                elapsed_secs: 0,
+                expected: None,
+            },
+            TestCase {
+                // TC2: Price falling to the take profit triggers it
+                config: take_profit,
+                price: 90.0,
+                elapsed_secs: 0,
+                expected: Some(ExitTrigger::TakeProfit),
+            },
+            TestCase {
+                // TC3: Holding period shorter than the maximum does not trigger
+                config: max_holding_period,
+                price: 100.0,
+                elapsed_secs: 59,
+                expected: None,
+            },
+            TestCase {
+                // TC4: Holding period reaching the maximum triggers
+                config: max_holding_period,
+                price: 100.0,
+                elapsed_secs: 60,
+                expected: Some(ExitTrigger::MaxHoldingPeriod),
+            },
+        ];
+
+        let enter_time = Utc::now();
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut exits = test.config.protective_exits(Side::Sell, 100.0, enter_time);
+            let actual = exits.update(
+                Side::Sell,
+                test.price,
+                enter_time + chrono::Duration::seconds(test.elapsed_secs),
+            );
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -2,27 +2,34 @@ use crate::{
     portfolio::{
         position::{determine_position_id, Position, PositionId},
         repository::{
-            determine_exited_positions_id, error::RepositoryError, BalanceHandler, PositionHandler,
-            StatisticHandler,
+            determine_equity_curve_id, determine_exited_positions_id, determine_order_sequence_id,
+            determine_strategy_snapshot_id, error::RepositoryError, BalanceHandler, EquityCurveId,
+            EquityHandler, OrderSequenceHandler, OrderSequenceId, PositionHandler,
+            StatisticHandler, StrategySnapshotHandler, StrategySnapshotId,
         },
-        Balance, BalanceId,
+        Balance, BalanceId, SymbolBalance,
     },
-    statistic::summary::PositionSummariser,
+    statistic::{metric::EquityPoint, summary::PositionSummariser},
 };
-use barter_integration::model::{Market, MarketId};
+use barter_integration::model::{instrument::symbol::Symbol, Market, MarketId};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-/// In-Memory repository for Proof Of Concepts. Implements [`PositionHandler`], [`BalanceHandler`]
-/// & [`StatisticHandler`]. Used by a Proof Of Concept Portfolio implementation to
-/// save the current equity, available cash, Positions, and market pair statistics.
+/// In-Memory repository for Proof Of Concepts. Implements [`PositionHandler`], [`BalanceHandler`],
+/// [`StatisticHandler`], [`StrategySnapshotHandler`], [`EquityHandler`] & [`OrderSequenceHandler`].
+/// Used by a Proof Of Concept Portfolio implementation to save the current equity, available cash,
+/// Positions, equity curve, order sequence number, and market pair statistics.
 /// **Careful in production - no fault tolerant guarantees!**
 #[derive(Debug, Default)]
 pub struct InMemoryRepository<Statistic: PositionSummariser> {
     open_positions: HashMap<PositionId, Position>,
     closed_positions: HashMap<String, Vec<Position>>,
     current_balances: HashMap<BalanceId, Balance>,
+    symbol_balances: HashMap<BalanceId, HashMap<Symbol, SymbolBalance>>,
     statistics: HashMap<MarketId, Statistic>,
+    strategy_snapshots: HashMap<StrategySnapshotId, serde_json::Value>,
+    equity_curves: HashMap<EquityCurveId, Vec<EquityPoint>>,
+    order_sequences: HashMap<OrderSequenceId, u64>,
 }
 
 impl<Statistic: PositionSummariser> PositionHandler for InMemoryRepository<Statistic> {
@@ -103,6 +110,29 @@ impl<Statistic: PositionSummariser> BalanceHandler for InMemoryRepository<Statis
             .copied()
             .ok_or(RepositoryError::ExpectedDataNotPresentError)
     }
+
+    fn set_symbol_balance(
+        &mut self,
+        engine_id: Uuid,
+        balance: SymbolBalance,
+    ) -> Result<(), RepositoryError> {
+        self.symbol_balances
+            .entry(SymbolBalance::symbol_balances_id(engine_id))
+            .or_default()
+            .insert(balance.symbol.clone(), balance);
+        Ok(())
+    }
+
+    fn get_symbol_balances(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<SymbolBalance>, RepositoryError> {
+        Ok(self
+            .symbol_balances
+            .get(&SymbolBalance::symbol_balances_id(engine_id))
+            .map(|balances| balances.values().cloned().collect())
+            .unwrap_or_default())
+    }
 }
 
 impl<Statistic: PositionSummariser> StatisticHandler<Statistic> for InMemoryRepository<Statistic> {
@@ -123,6 +153,73 @@ impl<Statistic: PositionSummariser> StatisticHandler<Statistic> for InMemoryRepo
     }
 }
 
+impl<Statistic: PositionSummariser> StrategySnapshotHandler for InMemoryRepository<Statistic> {
+    fn set_strategy_snapshot(
+        &mut self,
+        engine_id: Uuid,
+        market_id: &MarketId,
+        snapshot: serde_json::Value,
+    ) -> Result<(), RepositoryError> {
+        self.strategy_snapshots.insert(
+            determine_strategy_snapshot_id(engine_id, market_id),
+            snapshot,
+        );
+        Ok(())
+    }
+
+    fn get_strategy_snapshot(
+        &mut self,
+        engine_id: Uuid,
+        market_id: &MarketId,
+    ) -> Result<Option<serde_json::Value>, RepositoryError> {
+        Ok(self
+            .strategy_snapshots
+            .get(&determine_strategy_snapshot_id(engine_id, market_id))
+            .cloned())
+    }
+}
+
+impl<Statistic: PositionSummariser> EquityHandler for InMemoryRepository<Statistic> {
+    fn append_equity_point(
+        &mut self,
+        engine_id: Uuid,
+        equity_point: EquityPoint,
+    ) -> Result<(), RepositoryError> {
+        self.equity_curves
+            .entry(determine_equity_curve_id(engine_id))
+            .or_default()
+            .push(equity_point);
+        Ok(())
+    }
+
+    fn get_equity_curve(&mut self, engine_id: Uuid) -> Result<Vec<EquityPoint>, RepositoryError> {
+        Ok(self
+            .equity_curves
+            .get(&determine_equity_curve_id(engine_id))
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+impl<Statistic: PositionSummariser> OrderSequenceHandler for InMemoryRepository<Statistic> {
+    fn set_order_sequence(
+        &mut self,
+        engine_id: Uuid,
+        sequence: u64,
+    ) -> Result<(), RepositoryError> {
+        self.order_sequences
+            .insert(determine_order_sequence_id(engine_id), sequence);
+        Ok(())
+    }
+
+    fn get_order_sequence(&mut self, engine_id: Uuid) -> Result<Option<u64>, RepositoryError> {
+        Ok(self
+            .order_sequences
+            .get(&determine_order_sequence_id(engine_id))
+            .copied())
+    }
+}
+
 impl<Statistic: PositionSummariser> InMemoryRepository<Statistic> {
     /// Constructs a new [`InMemoryRepository`] component.
     pub fn new() -> Self {
@@ -130,7 +227,11 @@ impl<Statistic: PositionSummariser> InMemoryRepository<Statistic> {
             open_positions: HashMap::new(),
             closed_positions: HashMap::new(),
             current_balances: HashMap::new(),
+            symbol_balances: HashMap::new(),
             statistics: HashMap::new(),
+            strategy_snapshots: HashMap::new(),
+            equity_curves: HashMap::new(),
+            order_sequences: HashMap::new(),
         }
     }
 }
@@ -1,7 +1,10 @@
-use crate::portfolio::{
-    position::{Position, PositionId},
-    repository::error::RepositoryError,
-    Balance,
+use crate::{
+    portfolio::{
+        position::{Position, PositionId},
+        repository::error::RepositoryError,
+        Balance, SymbolBalance,
+    },
+    statistic::metric::EquityPoint,
 };
 use barter_integration::model::{Market, MarketId};
 use uuid::Uuid;
@@ -56,6 +59,17 @@ pub trait BalanceHandler {
     fn set_balance(&mut self, engine_id: Uuid, balance: Balance) -> Result<(), RepositoryError>;
     /// Get the Portfolio [`Balance`] using the engine_id provided.
     fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError>;
+    /// Upsert the Portfolio [`SymbolBalance`] of an asset at the engine_id.
+    fn set_symbol_balance(
+        &mut self,
+        engine_id: Uuid,
+        balance: SymbolBalance,
+    ) -> Result<(), RepositoryError>;
+    /// Get the Portfolio [`SymbolBalance`] of every asset held using the engine_id provided.
+    fn get_symbol_balances(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<SymbolBalance>, RepositoryError>;
 }
 
 /// Handles the reading & writing of a Portfolio's statistics for each of it's
@@ -71,6 +85,50 @@ pub trait StatisticHandler<Statistic> {
     fn get_statistics(&mut self, market_id: &MarketId) -> Result<Statistic, RepositoryError>;
 }
 
+/// Handles the reading & writing of Strategy state snapshots to/from the persistence layer, where
+/// each Strategy is identified by the engine_id & the [`MarketId`] it trades.
+pub trait StrategySnapshotHandler {
+    /// Upsert the Strategy snapshot at the engine_id & [`MarketId`] provided.
+    fn set_strategy_snapshot(
+        &mut self,
+        engine_id: Uuid,
+        market_id: &MarketId,
+        snapshot: serde_json::Value,
+    ) -> Result<(), RepositoryError>;
+    /// Get the Strategy snapshot at the engine_id & [`MarketId`] provided, if one has been saved.
+    fn get_strategy_snapshot(
+        &mut self,
+        engine_id: Uuid,
+        market_id: &MarketId,
+    ) -> Result<Option<serde_json::Value>, RepositoryError>;
+}
+
+/// Handles the reading & writing of a Portfolio's mark-to-market equity curve to/from the
+/// persistence layer.
+pub trait EquityHandler {
+    /// Append a mark-to-market [`EquityPoint`] to the Portfolio's equity curve.
+    fn append_equity_point(
+        &mut self,
+        engine_id: Uuid,
+        equity_point: EquityPoint,
+    ) -> Result<(), RepositoryError>;
+    /// Get every [`EquityPoint`] of the Portfolio's equity curve, in chronological order, using
+    /// the engine_id provided.
+    fn get_equity_curve(&mut self, engine_id: Uuid) -> Result<Vec<EquityPoint>, RepositoryError>;
+}
+
+/// Handles the reading & writing of the sequence number a Portfolio derives it's deterministic
+/// [`ClientOrderId`](barter_execution::model::ClientOrderId)s from to/from the persistence layer,
+/// so a resumed Portfolio never reuses the ClientOrderIds of a previous run.
+pub trait OrderSequenceHandler {
+    /// Upsert the order sequence number of the Portfolio at the engine_id.
+    fn set_order_sequence(&mut self, engine_id: Uuid, sequence: u64)
+        -> Result<(), RepositoryError>;
+    /// Get the order sequence number of the Portfolio using the engine_id provided, if one has
+    /// been saved.
+    fn get_order_sequence(&mut self, engine_id: Uuid) -> Result<Option<u64>, RepositoryError>;
+}
+
 /// Communicates a String represents a unique identifier for all a Portfolio's exited [`Position`]s.
 /// Used to append new exited [`Position`]s to the entry in the [`PositionHandler`].
 pub type ExitedPositionsId = String;
@@ -79,3 +137,31 @@ pub type ExitedPositionsId = String;
 pub fn determine_exited_positions_id(engine_id: Uuid) -> ExitedPositionsId {
     format!("positions_exited_{}", engine_id)
 }
+
+/// Communicates a String represents a unique identifier for the state snapshot of a Strategy
+/// trading a market.
+pub type StrategySnapshotId = String;
+
+/// Returns the unique identifier for the state snapshot of a Strategy, given an engine_id &
+/// the [`MarketId`] it trades.
+pub fn determine_strategy_snapshot_id(engine_id: Uuid, market_id: &MarketId) -> StrategySnapshotId {
+    format!("strategy_snapshot_{}_{}", engine_id, market_id)
+}
+
+/// Communicates a String represents a unique identifier for a Portfolio's mark-to-market equity
+/// curve.
+pub type EquityCurveId = String;
+
+/// Returns the unique identifier for a Portfolio's mark-to-market equity curve, given an
+/// engine_id.
+pub fn determine_equity_curve_id(engine_id: Uuid) -> EquityCurveId {
+    format!("equity_curve_{}", engine_id)
+}
+
+/// Communicates a String represents a unique identifier for a Portfolio's order sequence number.
+pub type OrderSequenceId = String;
+
+/// Returns the unique identifier for a Portfolio's order sequence number, given an engine_id.
+pub fn determine_order_sequence_id(engine_id: Uuid) -> OrderSequenceId {
+    format!("order_sequence_{}", engine_id)
+}
@@ -3,12 +3,13 @@ use crate::{
         error::PortfolioError,
         position::{determine_position_id, Position, PositionId},
         repository::{
-            determine_exited_positions_id, error::RepositoryError, BalanceHandler, PositionHandler,
-            StatisticHandler,
+            determine_equity_curve_id, determine_exited_positions_id, determine_order_sequence_id,
+            determine_strategy_snapshot_id, error::RepositoryError, BalanceHandler, EquityHandler,
+            OrderSequenceHandler, PositionHandler, StatisticHandler, StrategySnapshotHandler,
         },
-        Balance,
+        Balance, SymbolBalance,
     },
-    statistic::summary::PositionSummariser,
+    statistic::{metric::EquityPoint, summary::PositionSummariser},
 };
 use barter_integration::model::{Market, MarketId};
 use redis::{Commands, Connection, ErrorKind};
@@ -52,12 +53,15 @@ where
         &mut self,
         position_id: &PositionId,
     ) -> Result<Option<Position>, RepositoryError> {
-        let position_value: String = self
+        let position_value: Option<String> = self
             .conn
             .get(position_id)
             .map_err(|_| RepositoryError::ReadError)?;
 
-        Ok(Some(serde_json::from_str::<Position>(&position_value)?))
+        position_value
+            .map(|position| serde_json::from_str::<Position>(&position))
+            .transpose()
+            .map_err(RepositoryError::JsonSerDeError)
     }
 
     fn get_open_positions<'a, Markets: Iterator<Item = &'a Market>>(
@@ -84,7 +88,7 @@ where
         let position = self.get_open_position(position_id)?;
 
         self.conn
-            .del(position_id)
+            .del::<_, ()>(position_id)
             .map_err(|_| RepositoryError::DeleteError)?;
 
         Ok(position)
@@ -132,11 +136,41 @@ where
     fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError> {
         let balance_value: String = self
             .conn
-            .get(Balance::balance_id(engine_id))
-            .map_err(|_| RepositoryError::ReadError)?;
+            .get::<_, Option<String>>(Balance::balance_id(engine_id))
+            .map_err(|_| RepositoryError::ReadError)?
+            .ok_or(RepositoryError::ExpectedDataNotPresentError)?;
 
         Ok(serde_json::from_str::<Balance>(&balance_value)?)
     }
+
+    fn set_symbol_balance(
+        &mut self,
+        engine_id: Uuid,
+        balance: SymbolBalance,
+    ) -> Result<(), RepositoryError> {
+        let balance_string = serde_json::to_string(&balance)?;
+
+        self.conn
+            .hset(
+                SymbolBalance::symbol_balances_id(engine_id),
+                balance.symbol.to_string(),
+                balance_string,
+            )
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn get_symbol_balances(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<SymbolBalance>, RepositoryError> {
+        self.conn
+            .hvals::<_, Vec<String>>(SymbolBalance::symbol_balances_id(engine_id))
+            .map_err(|_| RepositoryError::ReadError)?
+            .iter()
+            .map(|balance| serde_json::from_str::<SymbolBalance>(balance))
+            .collect::<Result<Vec<SymbolBalance>, serde_json::Error>>()
+            .map_err(RepositoryError::JsonSerDeError)
+    }
 }
 
 impl<Statistic> StatisticHandler<Statistic> for RedisRepository<Statistic>
@@ -156,13 +190,95 @@ where
     fn get_statistics(&mut self, market_id: &MarketId) -> Result<Statistic, RepositoryError> {
         let statistics: String = self
             .conn
-            .get(&market_id.0)
-            .map_err(|_| RepositoryError::ReadError)?;
+            .get::<_, Option<String>>(&market_id.0)
+            .map_err(|_| RepositoryError::ReadError)?
+            .ok_or(RepositoryError::ExpectedDataNotPresentError)?;
 
         serde_json::from_str(&statistics).map_err(RepositoryError::JsonSerDeError)
     }
 }
 
+impl<Statistic> StrategySnapshotHandler for RedisRepository<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn set_strategy_snapshot(
+        &mut self,
+        engine_id: Uuid,
+        market_id: &MarketId,
+        snapshot: serde_json::Value,
+    ) -> Result<(), RepositoryError> {
+        self.conn
+            .set(
+                determine_strategy_snapshot_id(engine_id, market_id),
+                serde_json::to_string(&snapshot)?,
+            )
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn get_strategy_snapshot(
+        &mut self,
+        engine_id: Uuid,
+        market_id: &MarketId,
+    ) -> Result<Option<serde_json::Value>, RepositoryError> {
+        self.conn
+            .get::<_, Option<String>>(determine_strategy_snapshot_id(engine_id, market_id))
+            .map_err(|_| RepositoryError::ReadError)?
+            .map(|snapshot| serde_json::from_str(&snapshot))
+            .transpose()
+            .map_err(RepositoryError::JsonSerDeError)
+    }
+}
+
+impl<Statistic> EquityHandler for RedisRepository<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn append_equity_point(
+        &mut self,
+        engine_id: Uuid,
+        equity_point: EquityPoint,
+    ) -> Result<(), RepositoryError> {
+        self.conn
+            .rpush(
+                determine_equity_curve_id(engine_id),
+                serde_json::to_string(&equity_point)?,
+            )
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn get_equity_curve(&mut self, engine_id: Uuid) -> Result<Vec<EquityPoint>, RepositoryError> {
+        self.conn
+            .lrange::<_, Vec<String>>(determine_equity_curve_id(engine_id), 0, -1)
+            .map_err(|_| RepositoryError::ReadError)?
+            .iter()
+            .map(|equity_point| serde_json::from_str::<EquityPoint>(equity_point))
+            .collect::<Result<Vec<EquityPoint>, serde_json::Error>>()
+            .map_err(RepositoryError::JsonSerDeError)
+    }
+}
+
+impl<Statistic> OrderSequenceHandler for RedisRepository<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn set_order_sequence(
+        &mut self,
+        engine_id: Uuid,
+        sequence: u64,
+    ) -> Result<(), RepositoryError> {
+        self.conn
+            .set(determine_order_sequence_id(engine_id), sequence)
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn get_order_sequence(&mut self, engine_id: Uuid) -> Result<Option<u64>, RepositoryError> {
+        self.conn
+            .get::<_, Option<u64>>(determine_order_sequence_id(engine_id))
+            .map_err(|_| RepositoryError::ReadError)
+    }
+}
+
 impl<Statistic: PositionSummariser> Debug for RedisRepository<Statistic>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
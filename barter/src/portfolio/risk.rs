@@ -1,6 +1,14 @@
+use crate::portfolio::{position::Position, Balance, OrderEvent, OrderType};
+use barter_integration::model::{
+    amount::AmountExt, instrument::Instrument, Exchange, MarketId, Side,
+};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
-
-use crate::portfolio::{OrderEvent, OrderType};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+use tracing::warn;
 
 /// Evaluates the risk associated with an [`OrderEvent`] to determine if it should be actioned. It
 /// can also amend the order (eg/ [`OrderType`]) to better fit the risk strategy required for
@@ -8,9 +16,56 @@ use crate::portfolio::{OrderEvent, OrderType};
 pub trait OrderEvaluator {
     const DEFAULT_ORDER_TYPE: OrderType;
 
-    /// May return an amended [`OrderEvent`] if the associated risk is appropriate. Returns `None`
-    /// if the risk is too high.
-    fn evaluate_order(&self, order: OrderEvent) -> Option<OrderEvent>;
+    /// May return an amended [`OrderEvent`] if the associated risk is appropriate given the
+    /// current [`PortfolioState`]. Returns `None` if the risk is too high.
+    fn evaluate_order(&mut self, order: OrderEvent, state: &PortfolioState) -> Option<OrderEvent>;
+}
+
+/// Snapshot of the Portfolio state an [`OrderEvaluator`] evaluates an [`OrderEvent`] against.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct PortfolioState {
+    pub balance: Balance,
+    pub open_positions: Vec<Position>,
+}
+
+impl PortfolioState {
+    /// Total [`Balance`] plus the unrealised profit & loss of every open [`Position`].
+    pub fn equity(&self) -> f64 {
+        self.balance.total.to_f64_lossy()
+            + self
+                .open_positions
+                .iter()
+                .map(|position| position.unrealised_profit_loss.to_f64_lossy())
+                .sum::<f64>()
+    }
+
+    /// Sum of the absolute current value of every open [`Position`].
+    pub fn gross_exposure(&self) -> f64 {
+        self.open_positions
+            .iter()
+            .map(|position| position.current_value_gross.abs().to_f64_lossy())
+            .sum()
+    }
+
+    /// Sum of the current value of every open [`Position`], where short [`Position`]s are -ve.
+    pub fn net_exposure(&self) -> f64 {
+        self.open_positions.iter().map(signed_notional).sum()
+    }
+
+    /// Returns the open [`Position`] for the provided [`Exchange`] & [`Instrument`], if any.
+    pub fn open_position(&self, exchange: &Exchange, instrument: &Instrument) -> Option<&Position> {
+        self.open_positions
+            .iter()
+            .find(|position| &position.exchange == exchange && &position.instrument == instrument)
+    }
+}
+
+/// Current value of a [`Position`], where short [`Position`]s are -ve.
+fn signed_notional(position: &Position) -> f64 {
+    match position.side {
+        Side::Buy => position.current_value_gross.abs().to_f64_lossy(),
+        Side::Sell => -position.current_value_gross.abs().to_f64_lossy(),
+    }
 }
 
 /// Default risk manager that implements [`OrderEvaluator`].
@@ -20,7 +75,7 @@ pub struct DefaultRisk {}
 impl OrderEvaluator for DefaultRisk {
     const DEFAULT_ORDER_TYPE: OrderType = OrderType::Market;
 
-    fn evaluate_order(&self, mut order: OrderEvent) -> Option<OrderEvent> {
+    fn evaluate_order(&mut self, mut order: OrderEvent, _: &PortfolioState) -> Option<OrderEvent> {
         if self.risk_too_high(&order) {
             return None;
         }
@@ -34,3 +89,480 @@ impl DefaultRisk {
         false
     }
 }
+
+/// Limits enforced by the [`LimitRisk`] manager. Any limit that is `None` is not enforced.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct RiskLimits {
+    /// Maximum absolute notional value of the [`Position`] in any one market.
+    pub max_position_notional: Option<f64>,
+    /// Maximum sum of the absolute notional value of every open [`Position`].
+    pub max_gross_exposure: Option<f64>,
+    /// Maximum absolute sum of the notional value of every open [`Position`], where short
+    /// [`Position`]s are -ve.
+    pub max_net_exposure: Option<f64>,
+    /// Maximum number of open [`Position`]s.
+    pub max_open_positions: Option<usize>,
+    /// Maximum loss of equity since the start of the (UTC) day before new entries are halted for
+    /// the remainder of that day.
+    pub max_daily_loss: Option<f64>,
+    /// Maximum fall in equity from it's peak, in decimal form (eg/ 0.2 for 20%), before new
+    /// entries are halted permanently.
+    pub max_drawdown: Option<f64>,
+    /// Maximum rate [`OrderEvent`]s may be generated for any one market.
+    pub order_rate_limit: Option<OrderRateLimit>,
+}
+
+/// Maximum number of [`OrderEvent`]s allowed for a market within a rolling interval.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OrderRateLimit {
+    pub max_orders: usize,
+    pub interval: Duration,
+}
+
+/// [`RiskLimits`] limit an [`OrderEvent`] was rejected for breaching.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum RiskBreach {
+    MaxPositionNotional,
+    MaxGrossExposure,
+    MaxNetExposure,
+    MaxOpenPositions,
+    MaxDailyLoss,
+    MaxDrawdown,
+    OrderRateLimit,
+}
+
+/// Risk manager that implements [`OrderEvaluator`] by enforcing configurable [`RiskLimits`].
+///
+/// Exit [`OrderEvent`]s reduce risk, so are only subject to the [`OrderRateLimit`]. The daily
+/// loss & drawdown kill-switches track the Portfolio equity observed each time an
+/// [`OrderEvent`] is evaluated.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LimitRisk {
+    limits: RiskLimits,
+    /// Current (UTC) day, and the equity observed at the start of it.
+    day_start: Option<(NaiveDate, f64)>,
+    /// Daily loss kill-switch, halting new entries until the next (UTC) day.
+    daily_halt: bool,
+    /// Highest equity observed.
+    peak_equity: Option<f64>,
+    /// Drawdown kill-switch, halting new entries permanently.
+    drawdown_halt: bool,
+    /// Times of the [`OrderEvent`]s generated for each market within the [`OrderRateLimit`]
+    /// interval.
+    order_times: HashMap<MarketId, VecDeque<DateTime<Utc>>>,
+}
+
+impl OrderEvaluator for LimitRisk {
+    const DEFAULT_ORDER_TYPE: OrderType = OrderType::Market;
+
+    fn evaluate_order(&mut self, order: OrderEvent, state: &PortfolioState) -> Option<OrderEvent> {
+        match self.check_limits(&order, state) {
+            None => {
+                self.record_order(&order);
+                Some(order)
+            }
+            Some(breach) => {
+                warn!(
+                    exchange = ?order.exchange,
+                    instrument = ?order.instrument,
+                    decision = ?order.decision,
+                    ?breach,
+                    "OrderEvent rejected for breaching RiskLimits"
+                );
+                None
+            }
+        }
+    }
+}
+
+impl LimitRisk {
+    /// Constructs a new [`LimitRisk`] manager that enforces the provided [`RiskLimits`].
+    pub fn new(limits: RiskLimits) -> Self {
+        Self {
+            limits,
+            day_start: None,
+            daily_halt: false,
+            peak_equity: None,
+            drawdown_halt: false,
+            order_times: HashMap::new(),
+        }
+    }
+
+    /// Determines if either the daily loss or drawdown kill-switch is halting new entries.
+    pub fn is_halted(&self) -> bool {
+        self.daily_halt || self.drawdown_halt
+    }
+
+    /// Determines the first [`RiskLimits`] limit the [`OrderEvent`] breaches, if any.
+    pub fn check_limits(
+        &mut self,
+        order: &OrderEvent,
+        state: &PortfolioState,
+    ) -> Option<RiskBreach> {
+        self.update_kill_switches(order.time, state.equity());
+
+        if self.order_rate_limited(order) {
+            return Some(RiskBreach::OrderRateLimit);
+        }
+
+        if order.decision.is_exit() {
+            return None;
+        }
+
+        if self.drawdown_halt {
+            return Some(RiskBreach::MaxDrawdown);
+        }
+
+        if self.daily_halt {
+            return Some(RiskBreach::MaxDailyLoss);
+        }
+
+        // Determine the signed notional value of the entry OrderEvent
+        let order_notional = order.quantity.abs().to_f64_lossy() * order.market_meta.close;
+        let order_notional = match order.decision.order_side() {
+            Side::Buy => order_notional,
+            Side::Sell => -order_notional,
+        };
+
+        let position = state.open_position(&order.exchange, &order.instrument);
+
+        if let Some(max_open_positions) = self.limits.max_open_positions {
+            if position.is_none() && state.open_positions.len() >= max_open_positions {
+                return Some(RiskBreach::MaxOpenPositions);
+            }
+        }
+
+        if let Some(max_position_notional) = self.limits.max_position_notional {
+            let position_notional = position.map(signed_notional).unwrap_or_default();
+            if (position_notional + order_notional).abs() > max_position_notional {
+                return Some(RiskBreach::MaxPositionNotional);
+            }
+        }
+
+        if let Some(max_gross_exposure) = self.limits.max_gross_exposure {
+            if state.gross_exposure() + order_notional.abs() > max_gross_exposure {
+                return Some(RiskBreach::MaxGrossExposure);
+            }
+        }
+
+        if let Some(max_net_exposure) = self.limits.max_net_exposure {
+            if (state.net_exposure() + order_notional).abs() > max_net_exposure {
+                return Some(RiskBreach::MaxNetExposure);
+            }
+        }
+
+        None
+    }
+
+    /// Update the daily loss & drawdown kill-switches using the latest Portfolio equity.
+    fn update_kill_switches(&mut self, time: DateTime<Utc>, equity: f64) {
+        // Reset the daily loss kill-switch at the start of every (UTC) day
+        let today = time.date_naive();
+        let day_start_equity = match self.day_start {
+            Some((day, day_start_equity)) if day == today => day_start_equity,
+            _ => {
+                self.day_start = Some((today, equity));
+                self.daily_halt = false;
+                equity
+            }
+        };
+
+        if let Some(max_daily_loss) = self.limits.max_daily_loss {
+            if day_start_equity - equity >= max_daily_loss {
+                self.daily_halt = true;
+            }
+        }
+
+        let peak_equity = self.peak_equity.map_or(equity, |peak| peak.max(equity));
+        self.peak_equity = Some(peak_equity);
+
+        if let Some(max_drawdown) = self.limits.max_drawdown {
+            if peak_equity > 0.0 && (peak_equity - equity) / peak_equity >= max_drawdown {
+                self.drawdown_halt = true;
+            }
+        }
+    }
+
+    /// Determines if the [`OrderRateLimit`] for the [`OrderEvent`] market has been reached.
+    fn order_rate_limited(&mut self, order: &OrderEvent) -> bool {
+        let Some(rate_limit) = self.limits.order_rate_limit else {
+            return false;
+        };
+
+        let Some(order_times) = self
+            .order_times
+            .get_mut(&MarketId::new(&order.exchange, &order.instrument))
+        else {
+            return false;
+        };
+
+        // Discard OrderEvent times that have fallen out of the rolling interval
+        let interval =
+            chrono::Duration::from_std(rate_limit.interval).unwrap_or(chrono::Duration::MAX);
+        while order_times
+            .front()
+            .is_some_and(|time| order.time.signed_duration_since(*time) >= interval)
+        {
+            order_times.pop_front();
+        }
+
+        order_times.len() >= rate_limit.max_orders
+    }
+
+    /// Record the time of an accepted [`OrderEvent`] for the [`OrderRateLimit`].
+    fn record_order(&mut self, order: &OrderEvent) {
+        if self.limits.order_rate_limit.is_some() {
+            self.order_times
+                .entry(MarketId::new(&order.exchange, &order.instrument))
+                .or_default()
+                .push_back(order.time);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        strategy::Decision,
+        test_util::{order_event, position},
+    };
+    use barter_integration::{
+        amount,
+        model::{amount::Amount, instrument::kind::InstrumentKind},
+    };
+
+    fn state(total: Amount, open_positions: Vec<Position>) -> PortfolioState {
+        PortfolioState {
+            balance: Balance {
+                time: Utc::now(),
+                total,
+                available: total,
+            },
+            open_positions,
+        }
+    }
+
+    fn open_position(
+        instrument: (&str, &str),
+        side: Side,
+        current_value_gross: Amount,
+    ) -> Position {
+        Position {
+            instrument: Instrument::from((instrument.0, instrument.1, InstrumentKind::Spot)),
+            side,
+            current_value_gross,
+            unrealised_profit_loss: amount!(0.0),
+            ..position()
+        }
+    }
+
+    fn entry_order(decision: Decision, notional: f64) -> OrderEvent {
+        let mut order = order_event();
+        order.decision = decision;
+        order.market_meta.close = 100.0;
+        order.quantity = Amount::from_f64_lossy(match decision.order_side() {
+            Side::Buy => notional / 100.0,
+            Side::Sell => -notional / 100.0,
+        });
+        order
+    }
+
+    #[test]
+    fn check_limits_of_entry_orders_against_portfolio_state() {
+        struct TestCase {
+            limits: RiskLimits,
+            state: PortfolioState,
+            order: OrderEvent,
+            expected: Option<RiskBreach>,
+        }
+
+        let eth_long = open_position(("eth", "usdt"), Side::Buy, amount!(500.0));
+        let btc_short = open_position(("btc", "usdt"), Side::Sell, amount!(300.0));
+
+        let cases = vec![
+            TestCase {
+                // TC0: No limits configured
+                limits: RiskLimits::default(),
+                state: state(amount!(1000.0), vec![eth_long.clone()]),
+                order: entry_order(Decision::Long, 10_000.0),
+                expected: None,
+            },
+            TestCase {
+                // TC1: Max open Positions reached
+                limits: RiskLimits {
+                    max_open_positions: Some(1),
+                    ..Default::default()
+                },
+                state: state(amount!(1000.0), vec![btc_short.clone()]),
+                order: entry_order(Decision::Long, 100.0),
+                expected: Some(RiskBreach::MaxOpenPositions),
+            },
+            TestCase {
+                // TC2: Existing Position plus order exceeds max Position notional
+                limits: RiskLimits {
+                    max_position_notional: Some(550.0),
+                    ..Default::default()
+                },
+                state: state(amount!(1000.0), vec![eth_long.clone()]),
+                order: entry_order(Decision::Long, 100.0),
+                expected: Some(RiskBreach::MaxPositionNotional),
+            },
+            TestCase {
+                // TC3: Gross exposure plus order exceeds max gross exposure
+                limits: RiskLimits {
+                    max_gross_exposure: Some(850.0),
+                    ..Default::default()
+                },
+                state: state(amount!(1000.0), vec![btc_short.clone()]),
+                order: entry_order(Decision::Long, 600.0),
+                expected: Some(RiskBreach::MaxGrossExposure),
+            },
+            TestCase {
+                // TC4: Net exposure reduced by the order is within max net exposure
+                limits: RiskLimits {
+                    max_net_exposure: Some(300.0),
+                    ..Default::default()
+                },
+                state: state(amount!(1000.0), vec![eth_long.clone(), btc_short]),
+                order: entry_order(Decision::Short, 100.0),
+                expected: None,
+            },
+            TestCase {
+                // TC5: Net exposure increased by the order exceeds max net exposure
+                limits: RiskLimits {
+                    max_net_exposure: Some(300.0),
+                    ..Default::default()
+                },
+                state: state(amount!(1000.0), vec![eth_long.clone()]),
+                order: entry_order(Decision::Long, 100.0),
+                expected: Some(RiskBreach::MaxNetExposure),
+            },
+            TestCase {
+                // TC6: Exit order is not subject to exposure limits
+                limits: RiskLimits {
+                    max_open_positions: Some(0),
+                    max_position_notional: Some(0.0),
+                    max_gross_exposure: Some(0.0),
+                    max_net_exposure: Some(0.0),
+                    ..Default::default()
+                },
+                state: state(amount!(1000.0), vec![eth_long]),
+                order: entry_order(Decision::CloseLong, 500.0),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut risk = LimitRisk::new(test.limits);
+            let actual = risk.check_limits(&test.order, &test.state);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn daily_loss_kill_switch_halts_entries_until_the_next_day() {
+        let mut risk = LimitRisk::new(RiskLimits {
+            max_daily_loss: Some(100.0),
+            ..Default::default()
+        });
+
+        let mut order = entry_order(Decision::Long, 100.0);
+        let day_start = order.time;
+
+        // Equity at the start of the day is 1000.0
+        assert!(risk
+            .evaluate_order(order.clone(), &state(amount!(1000.0), vec![]))
+            .is_some());
+
+        // Equity falls by the max daily loss, so entries are halted but exits are not
+        assert!(risk
+            .evaluate_order(order.clone(), &state(amount!(900.0), vec![]))
+            .is_none());
+        assert!(risk.is_halted());
+        assert!(risk
+            .evaluate_order(
+                entry_order(Decision::CloseLong, 100.0),
+                &state(amount!(950.0), vec![])
+            )
+            .is_some());
+
+        // Equity recovering does not reset the kill-switch during the same day
+        assert!(risk
+            .evaluate_order(order.clone(), &state(amount!(1000.0), vec![]))
+            .is_none());
+
+        // Kill-switch resets on the next day
+        order.time = day_start + chrono::Duration::days(1);
+        assert!(risk
+            .evaluate_order(order, &state(amount!(900.0), vec![]))
+            .is_some());
+        assert!(!risk.is_halted());
+    }
+
+    #[test]
+    fn drawdown_kill_switch_halts_entries_permanently() {
+        let mut risk = LimitRisk::new(RiskLimits {
+            max_drawdown: Some(0.2),
+            ..Default::default()
+        });
+
+        let mut order = entry_order(Decision::Short, 100.0);
+
+        // Equity peaks at 1000.0 then falls by 10%, which is within the max drawdown
+        assert!(risk
+            .evaluate_order(order.clone(), &state(amount!(1000.0), vec![]))
+            .is_some());
+        assert!(risk
+            .evaluate_order(order.clone(), &state(amount!(900.0), vec![]))
+            .is_some());
+
+        // Unrealised losses count towards the drawdown
+        let mut losing_position = position();
+        losing_position.unrealised_profit_loss = -amount!(100.0);
+        assert_eq!(
+            risk.check_limits(&order, &state(amount!(900.0), vec![losing_position])),
+            Some(RiskBreach::MaxDrawdown)
+        );
+
+        // Kill-switch does not reset on the next day, or when equity recovers
+        order.time += chrono::Duration::days(1);
+        assert!(risk
+            .evaluate_order(order, &state(amount!(1000.0), vec![]))
+            .is_none());
+    }
+
+    #[test]
+    fn order_rate_limit_is_enforced_per_market_within_rolling_interval() {
+        let mut risk = LimitRisk::new(RiskLimits {
+            order_rate_limit: Some(OrderRateLimit {
+                max_orders: 2,
+                interval: Duration::from_secs(60),
+            }),
+            ..Default::default()
+        });
+        let state = state(amount!(1000.0), vec![]);
+
+        let mut order = entry_order(Decision::Long, 100.0);
+        let start = order.time;
+
+        // Two OrderEvents are accepted within the interval, but a third is rejected
+        assert!(risk.evaluate_order(order.clone(), &state).is_some());
+        order.time = start + chrono::Duration::seconds(30);
+        assert!(risk.evaluate_order(order.clone(), &state).is_some());
+        order.time = start + chrono::Duration::seconds(59);
+        assert_eq!(
+            risk.check_limits(&order, &state),
+            Some(RiskBreach::OrderRateLimit)
+        );
+
+        // A different market has it's own rate limit
+        let mut other_market = order.clone();
+        other_market.instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        assert!(risk.evaluate_order(other_market, &state).is_some());
+
+        // Once the first OrderEvent falls out of the interval another is accepted
+        order.time = start + chrono::Duration::seconds(60);
+        assert!(risk.evaluate_order(order, &state).is_some());
+    }
+}
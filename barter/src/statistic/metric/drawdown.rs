@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 /// period. Drawdown is a measure of downside volatility.
 ///
 /// See documentation: <https://www.investopedia.com/terms/d/drawdown.asp>
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct Drawdown {
     pub equity_range: Range,
     pub drawdown: f64,
@@ -21,20 +21,10 @@ pub struct Drawdown {
     pub duration: Duration,
 }
 
-impl Default for Drawdown {
-    fn default() -> Self {
-        Self {
-            equity_range: Default::default(),
-            drawdown: 0.0,
-            start_time: Utc::now(),
-            duration: Duration::zero(),
-        }
-    }
-}
-
 impl Drawdown {
-    /// Initialises a new [`Drawdown`] using the starting equity as the first peak.
-    pub fn init(starting_equity: f64) -> Self {
+    /// Initialises a new [`Drawdown`] using the starting equity at the start time of the trading
+    /// session as the first peak.
+    pub fn init(starting_equity: f64, start_time: DateTime<Utc>) -> Self {
         Self {
             equity_range: Range {
                 activated: true,
@@ -42,7 +32,7 @@ impl Drawdown {
                 low: starting_equity,
             },
             drawdown: 0.0,
-            start_time: Utc::now(),
+            start_time,
             duration: Duration::zero(),
         }
     }
@@ -2,6 +2,7 @@ use crate::{
     portfolio::{position::Position, Balance},
     statistic::summary::PositionSummariser,
 };
+use barter_integration::model::amount::AmountExt;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -9,26 +10,17 @@ pub mod drawdown;
 pub mod ratio;
 
 /// Total equity at a point in time - equates to [`Balance.total`](Balance).
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct EquityPoint {
     pub time: DateTime<Utc>,
     pub total: f64,
 }
 
-impl Default for EquityPoint {
-    fn default() -> Self {
-        Self {
-            time: Utc::now(),
-            total: 0.0,
-        }
-    }
-}
-
 impl From<Balance> for EquityPoint {
     fn from(balance: Balance) -> Self {
         Self {
             time: balance.time,
-            total: balance.total,
+            total: balance.total.to_f64_lossy(),
         }
     }
 }
@@ -40,11 +32,11 @@ impl PositionSummariser for EquityPoint {
             None => {
                 // Position is not exited, so simulate
                 self.time = position.meta.update_time;
-                self.total += position.unrealised_profit_loss;
+                self.total += position.unrealised_profit_loss.to_f64_lossy();
             }
             Some(exit_balance) => {
                 self.time = exit_balance.time;
-                self.total += position.realised_profit_loss;
+                self.total += position.realised_profit_loss.to_f64_lossy();
             }
         }
     }
@@ -54,6 +46,7 @@ impl PositionSummariser for EquityPoint {
 mod tests {
     use super::*;
     use crate::test_util::position;
+    use barter_integration::{amount, model::amount::Amount};
     use chrono::Duration;
     use std::ops::Add;
 
@@ -63,10 +56,10 @@ mod tests {
             let mut position = position();
             position.meta.exit_balance = Some(Balance {
                 time: exit_time,
-                total: 100.0,
-                available: 100.0,
+                total: amount!(100.0),
+                available: amount!(100.0),
             });
-            position.realised_profit_loss = result_pnl;
+            position.realised_profit_loss = Amount::from_f64_lossy(result_pnl);
             position
         }
 
@@ -77,7 +70,7 @@ mod tests {
             let mut position = position();
             position.meta.exit_balance = None;
             position.meta.update_time = last_update_time;
-            position.unrealised_profit_loss = unreal_pnl;
+            position.unrealised_profit_loss = Amount::from_f64_lossy(unreal_pnl);
             position
         }
 
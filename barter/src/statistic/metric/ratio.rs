@@ -1,4 +1,4 @@
-use crate::statistic::summary::pnl::PnLReturnSummary;
+use crate::statistic::summary::pnl::{EquityReturnSummary, PnLReturnSummary};
 use serde::{Deserialize, Serialize};
 
 pub trait Ratio {
@@ -52,6 +52,22 @@ impl SharpeRatio {
             }
         };
     }
+
+    /// Updates using the returns between the mark-to-market [`EquityPoint`](super::EquityPoint)s
+    /// of the Portfolio equity curve, with each equity return taking the place of a trade.
+    pub fn update_from_equity(&mut self, equity_returns: &EquityReturnSummary) {
+        // Update Equity Returns Per Day
+        self.trades_per_day = equity_returns.returns_per_day;
+
+        // Calculate Sharpe Ratio Per Equity Return
+        self.sharpe_ratio_per_trade = match equity_returns.total.dispersion.std_dev == 0.0 {
+            true => 0.0,
+            false => {
+                (equity_returns.total.mean - self.risk_free_return)
+                    / equity_returns.total.dispersion.std_dev
+            }
+        };
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
@@ -93,6 +109,22 @@ impl SortinoRatio {
             }
         };
     }
+
+    /// Updates using the returns between the mark-to-market [`EquityPoint`](super::EquityPoint)s
+    /// of the Portfolio equity curve, with each equity return taking the place of a trade.
+    pub fn update_from_equity(&mut self, equity_returns: &EquityReturnSummary) {
+        // Update Equity Returns Per Day
+        self.trades_per_day = equity_returns.returns_per_day;
+
+        // Calculate Sortino Ratio Per Equity Return
+        self.sortino_ratio_per_trade = match equity_returns.losses.dispersion.std_dev == 0.0 {
+            true => 0.0,
+            false => {
+                (equity_returns.total.mean - self.risk_free_return)
+                    / equity_returns.losses.dispersion.std_dev
+            }
+        };
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
@@ -147,7 +179,7 @@ mod tests {
     use crate::statistic::summary::pnl::PnLReturnSummary;
 
     fn sharpe_ratio_input(count: u64, mean: f64, std_dev: f64) -> PnLReturnSummary {
-        let mut pnl_returns = PnLReturnSummary::new();
+        let mut pnl_returns = PnLReturnSummary::default();
         pnl_returns.total.count = count;
         pnl_returns.total.mean = mean;
         pnl_returns.total.dispersion.std_dev = std_dev;
@@ -155,7 +187,7 @@ mod tests {
     }
 
     fn sortino_update_input(count: u64, mean: f64, loss_std_dev: f64) -> PnLReturnSummary {
-        let mut pnl_returns = PnLReturnSummary::new();
+        let mut pnl_returns = PnLReturnSummary::default();
         pnl_returns.total.count = count;
         pnl_returns.total.mean = mean;
         pnl_returns.losses.dispersion.std_dev = loss_std_dev;
@@ -163,7 +195,7 @@ mod tests {
     }
 
     fn calmar_ratio_returns_input(count: u64, mean: f64) -> PnLReturnSummary {
-        let mut pnl_returns = PnLReturnSummary::new();
+        let mut pnl_returns = PnLReturnSummary::default();
         pnl_returns.total.count = count;
         pnl_returns.total.mean = mean;
         pnl_returns
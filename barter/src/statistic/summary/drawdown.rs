@@ -8,6 +8,7 @@ use crate::{
         summary::{PositionSummariser, TableBuilder},
     },
 };
+use chrono::{DateTime, Utc};
 use prettytable::Row;
 use serde::{Deserialize, Serialize};
 
@@ -16,22 +17,29 @@ pub struct DrawdownSummary {
     pub current_drawdown: Drawdown,
     pub avg_drawdown: AvgDrawdown,
     pub max_drawdown: MaxDrawdown,
+    /// Mark-to-market [`EquityPoint`] snapshots have been received, so exited [`Position`] exit
+    /// balances are ignored in favour of the (superset) equity curve.
+    #[serde(default)]
+    pub marked_to_market: bool,
 }
 
 impl PositionSummariser for DrawdownSummary {
     fn update(&mut self, position: &Position) {
-        // Only update DrawdownSummary with closed Positions
-        let equity_point = match position.meta.exit_balance {
-            None => return,
-            Some(exit_balance) => EquityPoint::from(exit_balance),
-        };
+        // Mark-to-market equity curve already reflects every Position exit
+        if self.marked_to_market {
+            return;
+        }
 
-        // Updates
-        if let Some(ended_drawdown) = self.current_drawdown.update(equity_point) {
-            self.avg_drawdown.update(&ended_drawdown);
-            self.max_drawdown.update(&ended_drawdown);
+        // Only update DrawdownSummary with closed Positions
+        if let Some(exit_balance) = position.meta.exit_balance {
+            self.update_drawdowns(EquityPoint::from(exit_balance));
         }
     }
+
+    fn update_from_equity(&mut self, equity_point: EquityPoint) {
+        self.marked_to_market = true;
+        self.update_drawdowns(equity_point);
+    }
 }
 
 impl TableBuilder for DrawdownSummary {
@@ -55,11 +63,59 @@ impl TableBuilder for DrawdownSummary {
 }
 
 impl DrawdownSummary {
-    pub fn new(starting_equity: f64) -> Self {
+    pub fn new(starting_equity: f64, start_time: DateTime<Utc>) -> Self {
         Self {
-            current_drawdown: Drawdown::init(starting_equity),
+            current_drawdown: Drawdown::init(starting_equity, start_time),
             avg_drawdown: AvgDrawdown::init(),
             max_drawdown: MaxDrawdown::init(),
+            marked_to_market: false,
         }
     }
+
+    /// Updates the current, average & max drawdowns using the next [`EquityPoint`] of the
+    /// Portfolio.
+    fn update_drawdowns(&mut self, equity_point: EquityPoint) {
+        if let Some(ended_drawdown) = self.current_drawdown.update(equity_point) {
+            self.avg_drawdown.update(&ended_drawdown);
+            self.max_drawdown.update(&ended_drawdown);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{portfolio::Balance, test_util::position};
+    use barter_integration::amount;
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn drawdown_summary_marked_to_market_ignores_exited_position_balances() {
+        let base_time = Utc::now();
+        let mut summary = DrawdownSummary::new(100.0, base_time);
+
+        // Open Position drawdown is only visible via mark-to-market equity snapshots
+        summary.update_from_equity(EquityPoint {
+            time: base_time,
+            total: 80.0,
+        });
+        summary.update_from_equity(EquityPoint {
+            time: base_time + Duration::days(1),
+            total: 110.0,
+        });
+
+        // Exited Position balance already reflected in the equity curve, so it is ignored
+        let mut exited_position = position();
+        exited_position.meta.exit_balance = Some(Balance {
+            time: base_time + Duration::days(2),
+            total: amount!(50.0),
+            available: amount!(50.0),
+        });
+        summary.update(&exited_position);
+
+        assert!(summary.marked_to_market);
+        assert_eq!(summary.max_drawdown.drawdown.drawdown, -0.2);
+        assert_eq!(summary.current_drawdown.drawdown, 0.0);
+        assert_eq!(summary.current_drawdown.equity_range.high, 110.0);
+    }
 }
@@ -3,12 +3,17 @@ pub mod drawdown;
 pub mod pnl;
 pub mod trading;
 
-use crate::portfolio::position::Position;
+use crate::{portfolio::position::Position, statistic::metric::EquityPoint};
+use chrono::{DateTime, Utc};
 use prettytable::{Cell, Row, Table};
 
 pub trait Initialiser {
     type Config: Copy;
-    fn init(config: Self::Config) -> Self;
+
+    /// Initialises the statistics of a trading session starting at the provided time, which
+    /// should be sourced from the [`EngineClock`](crate::engine::clock::EngineClock) so
+    /// historical sessions are measured in historical time.
+    fn init(config: Self::Config, time: DateTime<Utc>) -> Self;
 }
 
 pub trait PositionSummariser: Copy {
@@ -18,6 +23,18 @@ pub trait PositionSummariser: Copy {
             self.update(position)
         }
     }
+
+    /// Updates using a mark-to-market [`EquityPoint`] snapshot of the Portfolio. Summarisers that
+    /// are only concerned with [`Position`]s can rely on the default no-op.
+    fn update_from_equity(&mut self, _equity_point: EquityPoint) {}
+
+    /// Updates using every mark-to-market [`EquityPoint`] of a Portfolio's equity curve, in
+    /// chronological order.
+    fn generate_equity_summary(&mut self, equity_curve: &[EquityPoint]) {
+        for equity_point in equity_curve.iter() {
+            self.update_from_equity(*equity_point)
+        }
+    }
 }
 
 pub trait TableBuilder {
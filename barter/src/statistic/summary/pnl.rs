@@ -1,16 +1,18 @@
 use crate::{
     portfolio::position::Position,
     statistic::{
-        de_duration_from_secs, se_duration_as_secs,
+        de_duration_from_secs,
+        metric::EquityPoint,
+        se_duration_as_secs,
         summary::{data::DataSummary, Initialiser, PositionSummariser, TableBuilder},
     },
 };
-use barter_integration::model::Side;
+use barter_integration::model::{amount::AmountExt, Side};
 use chrono::{DateTime, Duration, Utc};
 use prettytable::Row;
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct PnLReturnSummary {
     pub time: DateTime<Utc>,
     #[serde(
@@ -26,20 +28,8 @@ pub struct PnLReturnSummary {
 impl Initialiser for PnLReturnSummary {
     type Config = ();
 
-    fn init(_: Self::Config) -> Self {
-        Self::default()
-    }
-}
-
-impl Default for PnLReturnSummary {
-    fn default() -> Self {
-        Self {
-            time: Utc::now(),
-            duration: Duration::zero(),
-            trades_per_day: 0.0,
-            total: DataSummary::default(),
-            losses: DataSummary::default(),
-        }
+    fn init(_: Self::Config, time: DateTime<Utc>) -> Self {
+        Self::new(time)
     }
 }
 
@@ -103,9 +93,9 @@ impl TableBuilder for PnLReturnSummary {
 impl PnLReturnSummary {
     const SECONDS_IN_DAY: f64 = 86400.0;
 
-    pub fn new() -> Self {
+    pub fn new(time: DateTime<Utc>) -> Self {
         Self {
-            time: Utc::now(),
+            time,
             duration: Duration::zero(),
             trades_per_day: 0.0,
             total: Default::default(),
@@ -127,6 +117,87 @@ impl PnLReturnSummary {
         self.trades_per_day = self.total.count as f64
             / (self.duration.num_seconds() as f64 / PnLReturnSummary::SECONDS_IN_DAY)
     }
+
+    /// Proportion of trades with a non-negative PnL return. Returns `None` if there have been no
+    /// trades.
+    pub fn win_rate(&self) -> Option<f64> {
+        if self.total.count == 0 {
+            return None;
+        }
+        let wins = self.total.count - self.losses.count;
+        Some(wins as f64 / self.total.count as f64)
+    }
+
+    /// Ratio of the mean winning PnL return to the absolute mean losing PnL return. Returns
+    /// `None` if there have been no winning or no losing trades.
+    pub fn payoff_ratio(&self) -> Option<f64> {
+        let wins = self.total.count - self.losses.count;
+        if wins == 0 || self.losses.count == 0 || self.losses.mean == 0.0 {
+            return None;
+        }
+        let win_mean = (self.total.sum - self.losses.sum) / wins as f64;
+        Some(win_mean / self.losses.mean.abs())
+    }
+}
+
+/// Summary of the returns between consecutive mark-to-market [`EquityPoint`]s of a Portfolio's
+/// equity curve. Used to calculate risk-adjusted ratios of the Portfolio equity, rather than of
+/// the exited [`Position`]s.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct EquityReturnSummary {
+    pub start: Option<EquityPoint>,
+    pub last: Option<EquityPoint>,
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub duration: Duration,
+    pub returns_per_day: f64,
+    pub total: DataSummary,
+    pub losses: DataSummary,
+}
+
+impl PositionSummariser for EquityReturnSummary {
+    /// Exited [`Position`]s are already reflected in the equity curve.
+    fn update(&mut self, _: &Position) {}
+
+    fn update_from_equity(&mut self, equity_point: EquityPoint) {
+        // Set start EquityPoint if it's the first snapshot of the session
+        let Some(last) = self.last.replace(equity_point) else {
+            self.start = Some(equity_point);
+            return;
+        };
+
+        // Return is undefined if the previous equity was zero
+        if last.total == 0.0 {
+            return;
+        }
+
+        // Update duration of trading session & returns per day
+        if let Some(start) = self.start {
+            self.duration = equity_point.time.signed_duration_since(start.time);
+        }
+
+        // Calculate the equity return since the previous EquityPoint
+        let equity_return = (equity_point.total - last.total) / last.total;
+
+        // Update Total Equity Returns
+        self.total.update(equity_return);
+
+        // Update Loss Equity Returns if relevant
+        if equity_return.is_sign_negative() {
+            self.losses.update(equity_return);
+        }
+
+        self.returns_per_day = self.total.count as f64
+            / (self.duration.num_seconds() as f64 / PnLReturnSummary::SECONDS_IN_DAY);
+    }
+}
+
+impl EquityReturnSummary {
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
@@ -144,19 +215,19 @@ pub struct ProfitLossSummary {
 
 impl PositionSummariser for ProfitLossSummary {
     fn update(&mut self, position: &Position) {
-        self.total_contracts += position.quantity.abs();
-        self.total_pnl += position.realised_profit_loss;
+        self.total_contracts += position.quantity.abs().to_f64_lossy();
+        self.total_pnl += position.realised_profit_loss.to_f64_lossy();
         self.total_pnl_per_contract = self.total_pnl / self.total_contracts;
 
         match position.side {
             Side::Buy => {
-                self.long_contracts += position.quantity.abs();
-                self.long_pnl += position.realised_profit_loss;
+                self.long_contracts += position.quantity.abs().to_f64_lossy();
+                self.long_pnl += position.realised_profit_loss.to_f64_lossy();
                 self.long_pnl_per_contract = self.long_pnl / self.long_contracts;
             }
             Side::Sell => {
-                self.short_contracts += position.quantity.abs();
-                self.short_pnl += position.realised_profit_loss;
+                self.short_contracts += position.quantity.abs().to_f64_lossy();
+                self.short_pnl += position.realised_profit_loss.to_f64_lossy();
                 self.short_pnl_per_contract = self.short_pnl / self.short_contracts;
             }
         }
@@ -203,6 +274,7 @@ impl ProfitLossSummary {
 mod tests {
     use super::*;
     use crate::{portfolio::Balance, test_util::position};
+    use barter_integration::amount;
     use chrono::{Duration, Utc};
 
     #[test]
@@ -214,8 +286,7 @@ mod tests {
     fn update_trading_session_duration_with_non_exited_position() {
         let base_time = Utc::now();
 
-        let mut pnl_return_view = PnLReturnSummary::new();
-        pnl_return_view.time = base_time;
+        let mut pnl_return_view = PnLReturnSummary::new(base_time);
 
         let mut input_position = position();
         input_position.meta.exit_balance = None;
@@ -228,18 +299,43 @@ mod tests {
         assert_eq!(pnl_return_view.duration, expected);
     }
 
+    #[test]
+    fn update_equity_return_summary_from_equity_curve() {
+        let base_time = Utc::now();
+        let equity_point = |days: i64, total: f64| EquityPoint {
+            time: base_time + Duration::days(days),
+            total,
+        };
+
+        let mut summary = EquityReturnSummary::new();
+        summary.generate_equity_summary(&[
+            equity_point(0, 100.0),
+            equity_point(1, 110.0),
+            equity_point(2, 99.0),
+            equity_point(4, 108.9),
+        ]);
+
+        // Returns = [0.1, -0.1, 0.1]
+        assert_eq!(summary.start, Some(equity_point(0, 100.0)));
+        assert_eq!(summary.duration, Duration::days(4));
+        assert_eq!(summary.total.count, 3);
+        assert!((summary.total.mean - (0.1 / 3.0)).abs() < 1e-10);
+        assert_eq!(summary.losses.count, 1);
+        assert!((summary.losses.mean - -0.1).abs() < 1e-10);
+        assert!((summary.returns_per_day - 0.75).abs() < 1e-10);
+    }
+
     #[test]
     fn update_trading_session_duration_with_exited_position() {
         let base_time = Utc::now();
 
-        let mut pnl_return_view = PnLReturnSummary::new();
-        pnl_return_view.time = base_time;
+        let mut pnl_return_view = PnLReturnSummary::new(base_time);
 
         let mut input_position = position();
         input_position.meta.exit_balance = Some(Balance {
             time: base_time.checked_add_signed(Duration::days(15)).unwrap(),
-            total: 0.0,
-            available: 0.0,
+            total: amount!(0.0),
+            available: amount!(0.0),
         });
 
         pnl_return_view.update_trading_session_duration(&input_position);
@@ -1,10 +1,14 @@
 use crate::{
     portfolio::position::Position,
     statistic::{
-        metric::ratio::{CalmarRatio, Ratio, SharpeRatio, SortinoRatio},
+        metric::{
+            ratio::{CalmarRatio, Ratio, SharpeRatio, SortinoRatio},
+            EquityPoint,
+        },
         summary::{
-            drawdown::DrawdownSummary, pnl::PnLReturnSummary, Initialiser, PositionSummariser,
-            TableBuilder,
+            drawdown::DrawdownSummary,
+            pnl::{EquityReturnSummary, PnLReturnSummary},
+            Initialiser, PositionSummariser, TableBuilder,
         },
     },
 };
@@ -24,16 +28,21 @@ pub struct Config {
 pub struct TradingSummary {
     pub pnl_returns: PnLReturnSummary,
     pub drawdown: DrawdownSummary,
+    /// Returns between the mark-to-market [`EquityPoint`]s of the Portfolio equity curve, if any
+    /// snapshots were taken.
+    #[serde(default)]
+    pub equity_returns: EquityReturnSummary,
     pub tear_sheet: TearSheet,
 }
 
 impl Initialiser for TradingSummary {
     type Config = Config;
 
-    fn init(config: Self::Config) -> Self {
+    fn init(config: Self::Config, time: DateTime<Utc>) -> Self {
         Self {
-            pnl_returns: PnLReturnSummary::new(),
-            drawdown: DrawdownSummary::new(config.starting_equity),
+            pnl_returns: PnLReturnSummary::new(time),
+            drawdown: DrawdownSummary::new(config.starting_equity, time),
+            equity_returns: EquityReturnSummary::new(),
             tear_sheet: TearSheet::new(config.risk_free_return),
         }
     }
@@ -43,7 +52,15 @@ impl PositionSummariser for TradingSummary {
     fn update(&mut self, position: &Position) {
         self.pnl_returns.update(position);
         self.drawdown.update(position);
-        self.tear_sheet.update(&self.pnl_returns, &self.drawdown);
+        self.tear_sheet
+            .update(&self.pnl_returns, &self.equity_returns, &self.drawdown);
+    }
+
+    fn update_from_equity(&mut self, equity_point: EquityPoint) {
+        self.drawdown.update_from_equity(equity_point);
+        self.equity_returns.update_from_equity(equity_point);
+        self.tear_sheet
+            .update(&self.pnl_returns, &self.equity_returns, &self.drawdown);
     }
 }
 
@@ -101,9 +118,21 @@ impl TearSheet {
         }
     }
 
-    pub fn update(&mut self, pnl_returns: &PnLReturnSummary, drawdown: &DrawdownSummary) {
-        self.sharpe_ratio.update(pnl_returns);
-        self.sortino_ratio.update(pnl_returns);
+    /// Updates the ratios using the mark-to-market equity returns if any have been received,
+    /// otherwise using the PnL returns of the exited [`Position`]s.
+    pub fn update(
+        &mut self,
+        pnl_returns: &PnLReturnSummary,
+        equity_returns: &EquityReturnSummary,
+        drawdown: &DrawdownSummary,
+    ) {
+        if equity_returns.total.count > 0 {
+            self.sharpe_ratio.update_from_equity(equity_returns);
+            self.sortino_ratio.update_from_equity(equity_returns);
+        } else {
+            self.sharpe_ratio.update(pnl_returns);
+            self.sortino_ratio.update(pnl_returns);
+        }
         self.calmar_ratio
             .update(pnl_returns, drawdown.max_drawdown.drawdown.drawdown);
     }
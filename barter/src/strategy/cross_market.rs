@@ -0,0 +1,100 @@
+use super::{Signal, SignalGenerator};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::{instrument::Instrument, Market};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// May generate advisory [`Signal`]s for any number of markets as a result of analysing an input
+/// [`MarketEvent`] from any of the markets it trades (eg/ pairs trading, basket arbitrage, or
+/// lead-lag strategies).
+pub trait CrossMarketSignalGenerator {
+    /// Return the [`Signal`]s (possibly for other markets) generated by the input
+    /// [`MarketEvent`].
+    fn generate_signals(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Vec<Signal>;
+
+    /// Returns `true` once enough [`MarketEvent`]s have been analysed to generate meaningful
+    /// [`Signal`]s. See [`SignalGenerator::is_warmed_up`].
+    fn is_warmed_up(&self) -> bool {
+        true
+    }
+}
+
+/// [`SignalGenerator`] adapter that shares one [`CrossMarketSignalGenerator`] between the
+/// [`Trader`](crate::engine::trader::Trader)s of every market it trades, so it analyses the
+/// [`MarketEvent`]s of all of them.
+///
+/// [`Signal`]s generated for this [`Trader`](crate::engine::trader::Trader)'s [`Market`] are
+/// returned directly, while [`Signal`]s for other markets are returned via
+/// [`SignalGenerator::take_routed_signals`]. Once warmed up, the
+/// [`Trader`](crate::engine::trader::Trader) sends them to the
+/// [`Engine`](crate::engine::Engine) as a [`Command::RouteSignal`](crate::engine::Command), to be
+/// actioned by the [`Trader`](crate::engine::trader::Trader) (& hence Execution client) of their
+/// [`Market`].
+#[derive(Debug)]
+pub struct CrossMarketStrategy<Strategy>
+where
+    Strategy: CrossMarketSignalGenerator,
+{
+    market: Market,
+    strategy: Arc<Mutex<Strategy>>,
+    routed: Vec<Signal>,
+}
+
+impl<Strategy> SignalGenerator for CrossMarketStrategy<Strategy>
+where
+    Strategy: CrossMarketSignalGenerator,
+{
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        let signals = self.strategy.lock().generate_signals(market);
+
+        let mut local: Option<Signal> = None;
+        for signal in signals {
+            if signal.exchange == self.market.exchange
+                && signal.instrument == self.market.instrument
+            {
+                // Merge multiple Signals for this Trader's Market, latest Decisions & target
+                // exposure taking priority
+                match &mut local {
+                    Some(local) => {
+                        local.signals.extend(signal.signals);
+                        if signal.target_exposure.is_some() {
+                            local.target_exposure = signal.target_exposure;
+                        }
+                    }
+                    None => local = Some(signal),
+                }
+            } else {
+                self.routed.push(signal);
+            }
+        }
+
+        local
+    }
+
+    fn is_warmed_up(&self) -> bool {
+        self.strategy.lock().is_warmed_up()
+    }
+
+    fn take_routed_signals(&mut self) -> Vec<Signal> {
+        std::mem::take(&mut self.routed)
+    }
+}
+
+impl<Strategy> CrossMarketStrategy<Strategy>
+where
+    Strategy: CrossMarketSignalGenerator,
+{
+    /// Constructs a new [`CrossMarketStrategy`] for the [`Trader`](crate::engine::trader::Trader)
+    /// of the provided [`Market`], sharing the provided [`CrossMarketSignalGenerator`].
+    ///
+    /// The [`Trader`](crate::engine::trader::Trader) must be built with the
+    /// [`Engine`](crate::engine::Engine) command transmitter to route [`Signal`]s for other
+    /// markets.
+    pub fn new(market: Market, strategy: Arc<Mutex<Strategy>>) -> Self {
+        Self {
+            market,
+            strategy,
+            routed: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use super::{Decision, Signal, SignalGenerator, SignalStrength};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+};
+
+/// Rule used by an [`EnsembleStrategy`] to combine the [`Signal`]s generated by it's members
+/// into a single [`Signal`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum Aggregation {
+    /// A [`Decision`] is advised if more than half of the members advise it, with the mean
+    /// [`SignalStrength`] of the members that advise it.
+    MajorityVote,
+    /// A [`Decision`] is advised if the weighted mean [`SignalStrength`] across every member
+    /// (members that do not advise it contribute zero) is at least the threshold.
+    WeightedSum { threshold: f64 },
+    /// A [`Decision`] is advised only if every member advises it, with the minimum
+    /// [`SignalStrength`] of the members.
+    Unanimity,
+    /// The [`Signal`] of the highest priority member (ie/ the first added) that generates one.
+    Priority,
+}
+
+/// Member of an [`EnsembleStrategy`].
+struct Member {
+    strategy: Box<dyn SignalGenerator + Send>,
+    weight: f64,
+}
+
+/// Composite [`SignalGenerator`] that runs several [`SignalGenerator`]s on the same market, and
+/// combines their [`Signal`]s using an [`Aggregation`] rule. Every member analyses every
+/// [`MarketEvent`], regardless of the [`Aggregation`] rule.
+pub struct EnsembleStrategy {
+    aggregation: Aggregation,
+    members: Vec<Member>,
+}
+
+impl Debug for EnsembleStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EnsembleStrategy")
+            .field("aggregation", &self.aggregation)
+            .field(
+                "weights",
+                &self
+                    .members
+                    .iter()
+                    .map(|member| member.weight)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl SignalGenerator for EnsembleStrategy {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        // Drive every member with the MarketEvent, retaining the weight of each Signal generated
+        let signals = self
+            .members
+            .iter_mut()
+            .filter_map(|member| {
+                member
+                    .strategy
+                    .generate_signal(market)
+                    .map(|signal| (member.weight, signal))
+            })
+            .collect::<Vec<_>>();
+
+        let (_, template) = signals.first()?;
+
+        let aggregated = match self.aggregation {
+            Aggregation::Priority => return signals.into_iter().next().map(|(_, signal)| signal),
+            Aggregation::MajorityVote => self.majority_vote(&signals),
+            Aggregation::WeightedSum { threshold } => self.weighted_sum(&signals, threshold),
+            Aggregation::Unanimity => self.unanimity(&signals),
+        };
+
+        if aggregated.is_empty() {
+            return None;
+        }
+
+        Some(Signal {
+            signals: aggregated,
+            target_exposure: None,
+            ..template.clone()
+        })
+    }
+
+    fn is_warmed_up(&self) -> bool {
+        self.members
+            .iter()
+            .all(|member| member.strategy.is_warmed_up())
+    }
+}
+
+impl EnsembleStrategy {
+    /// Constructs a new [`EnsembleStrategy`] with no members, that combines their [`Signal`]s
+    /// using the provided [`Aggregation`] rule.
+    pub fn new(aggregation: Aggregation) -> Self {
+        Self {
+            aggregation,
+            members: Vec::new(),
+        }
+    }
+
+    /// Adds a member [`SignalGenerator`] with the provided weight. Weights are only used by
+    /// [`Aggregation::WeightedSum`], and members added first have the highest
+    /// [`Aggregation::Priority`].
+    pub fn member<Strategy>(mut self, strategy: Strategy, weight: f64) -> Self
+    where
+        Strategy: SignalGenerator + Send + 'static,
+    {
+        self.members.push(Member {
+            strategy: Box::new(strategy),
+            weight,
+        });
+        self
+    }
+
+    /// Groups the [`SignalStrength`]s (and weight of the member that generated them) advised
+    /// for each [`Decision`].
+    fn decision_strengths(signals: &[(f64, Signal)]) -> HashMap<Decision, Vec<(f64, f64)>> {
+        signals
+            .iter()
+            .flat_map(|(weight, signal)| {
+                signal
+                    .signals
+                    .iter()
+                    .map(move |(decision, strength)| (*decision, (*weight, strength.0)))
+            })
+            .fold(HashMap::new(), |mut decisions, (decision, strength)| {
+                decisions
+                    .entry(decision)
+                    .or_insert_with(Vec::new)
+                    .push(strength);
+                decisions
+            })
+    }
+
+    fn majority_vote(&self, signals: &[(f64, Signal)]) -> HashMap<Decision, SignalStrength> {
+        Self::decision_strengths(signals)
+            .into_iter()
+            .filter(|(_, strengths)| strengths.len() * 2 > self.members.len())
+            .map(|(decision, strengths)| {
+                let mean = strengths.iter().map(|(_, strength)| strength).sum::<f64>()
+                    / strengths.len() as f64;
+                (decision, SignalStrength(mean))
+            })
+            .collect()
+    }
+
+    fn weighted_sum(
+        &self,
+        signals: &[(f64, Signal)],
+        threshold: f64,
+    ) -> HashMap<Decision, SignalStrength> {
+        let total_weight = self.members.iter().map(|member| member.weight).sum::<f64>();
+        if total_weight <= 0.0 {
+            return HashMap::new();
+        }
+
+        Self::decision_strengths(signals)
+            .into_iter()
+            .map(|(decision, strengths)| {
+                let weighted_sum = strengths
+                    .iter()
+                    .map(|(weight, strength)| weight * strength)
+                    .sum::<f64>();
+                (decision, SignalStrength(weighted_sum / total_weight))
+            })
+            .filter(|(_, strength)| strength.0 >= threshold)
+            .collect()
+    }
+
+    fn unanimity(&self, signals: &[(f64, Signal)]) -> HashMap<Decision, SignalStrength> {
+        Self::decision_strengths(signals)
+            .into_iter()
+            .filter(|(_, strengths)| strengths.len() == self.members.len())
+            .map(|(decision, strengths)| {
+                let min = strengths
+                    .iter()
+                    .map(|(_, strength)| *strength)
+                    .fold(f64::INFINITY, f64::min);
+                (decision, SignalStrength(min))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{market_event_trade, signal};
+    use barter_integration::model::Side;
+
+    /// [`SignalGenerator`] that always advises the same decisions, if any.
+    struct FixedStrategy(Vec<(Decision, f64)>);
+
+    impl SignalGenerator for FixedStrategy {
+        fn generate_signal(&mut self, _: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+            if self.0.is_empty() {
+                return None;
+            }
+            Some(Signal {
+                signals: self
+                    .0
+                    .iter()
+                    .map(|(decision, strength)| (*decision, SignalStrength(*strength)))
+                    .collect(),
+                ..signal()
+            })
+        }
+    }
+
+    fn ensemble(
+        aggregation: Aggregation,
+        members: Vec<(Vec<(Decision, f64)>, f64)>,
+    ) -> EnsembleStrategy {
+        members.into_iter().fold(
+            EnsembleStrategy::new(aggregation),
+            |ensemble, (decisions, weight)| ensemble.member(FixedStrategy(decisions), weight),
+        )
+    }
+
+    #[test]
+    fn ensemble_strategy_aggregates_member_signals() {
+        struct TestCase {
+            aggregation: Aggregation,
+            members: Vec<(Vec<(Decision, f64)>, f64)>,
+            expected: Option<HashMap<Decision, SignalStrength>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Majority vote advises the Decision of more than half the members
+                aggregation: Aggregation::MajorityVote,
+                members: vec![
+                    (vec![(Decision::Long, 1.0)], 1.0),
+                    (
+                        vec![(Decision::Long, 0.5), (Decision::CloseShort, 1.0)],
+                        1.0,
+                    ),
+                    (vec![(Decision::Short, 1.0)], 1.0),
+                ],
+                expected: Some(HashMap::from([(Decision::Long, SignalStrength(0.75))])),
+            },
+            TestCase {
+                // TC1: Majority vote without a majority advises nothing
+                aggregation: Aggregation::MajorityVote,
+                members: vec![
+                    (vec![(Decision::Long, 1.0)], 1.0),
+                    (vec![(Decision::Short, 1.0)], 1.0),
+                ],
+                expected: None,
+            },
+            TestCase {
+                // TC2: Weighted sum advises Decisions with a weighted mean above the threshold
+                aggregation: Aggregation::WeightedSum { threshold: 0.5 },
+                members: vec![
+                    (vec![(Decision::Long, 1.0)], 3.0),
+                    (vec![(Decision::Short, 1.0)], 1.0),
+                ],
+                expected: Some(HashMap::from([(Decision::Long, SignalStrength(0.75))])),
+            },
+            TestCase {
+                // TC3: Unanimity advises Decisions every member advises, with the min strength
+                aggregation: Aggregation::Unanimity,
+                members: vec![
+                    (
+                        vec![(Decision::Long, 1.0), (Decision::CloseShort, 1.0)],
+                        1.0,
+                    ),
+                    (vec![(Decision::Long, 0.4)], 1.0),
+                ],
+                expected: Some(HashMap::from([(Decision::Long, SignalStrength(0.4))])),
+            },
+            TestCase {
+                // TC4: Unanimity is not reached if a member generates no Signal
+                aggregation: Aggregation::Unanimity,
+                members: vec![(vec![(Decision::Long, 1.0)], 1.0), (vec![], 1.0)],
+                expected: None,
+            },
+            TestCase {
+                // TC5: Priority advises the Signal of the first member that generates one
+                aggregation: Aggregation::Priority,
+                members: vec![
+                    (vec![], 1.0),
+                    (vec![(Decision::Short, 0.3)], 1.0),
+                    (vec![(Decision::Long, 1.0)], 1.0),
+                ],
+                expected: Some(HashMap::from([(Decision::Short, SignalStrength(0.3))])),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut strategy = ensemble(test.aggregation, test.members);
+            let actual = strategy
+                .generate_signal(&market_event_trade(Side::Buy))
+                .map(|signal| signal.signals);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
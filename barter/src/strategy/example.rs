@@ -1,8 +1,7 @@
-use super::{Decision, Signal, SignalGenerator, SignalStrength};
+use super::{Decision, Signal, SignalGenerator, SignalStrength, StrategySnapshot};
 use crate::data::MarketMeta;
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::instrument::Instrument;
-use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use ta::{indicators::RelativeStrengthIndex, Next};
@@ -13,10 +12,12 @@ pub struct Config {
     pub rsi_period: usize,
 }
 
-#[derive(Clone, Debug)]
-/// Example RSI based strategy that implements [`SignalGenerator`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Example RSI based strategy that implements [`SignalGenerator`] & [`StrategySnapshot`].
 pub struct RSIStrategy {
     rsi: RelativeStrengthIndex,
+    rsi_period: usize,
+    candles: usize,
 }
 
 impl SignalGenerator for RSIStrategy {
@@ -29,6 +30,7 @@ impl SignalGenerator for RSIStrategy {
 
         // Calculate the next RSI value using the new MarketEvent Candle data
         let rsi = self.rsi.next(candle_close);
+        self.candles = self.candles.saturating_add(1);
 
         // Generate advisory signals map
         let signals = RSIStrategy::generate_signals_map(rsi);
@@ -39,7 +41,7 @@ impl SignalGenerator for RSIStrategy {
         }
 
         Some(Signal {
-            time: Utc::now(),
+            time: market.exchange_time,
             exchange: market.exchange.clone(),
             instrument: market.instrument.clone(),
             market_meta: MarketMeta {
@@ -47,8 +49,28 @@ impl SignalGenerator for RSIStrategy {
                 time: market.exchange_time,
             },
             signals,
+            target_exposure: None,
         })
     }
+
+    fn is_warmed_up(&self) -> bool {
+        self.candles >= self.rsi_period
+    }
+
+    fn snapshotter(&mut self) -> Option<&mut dyn StrategySnapshot> {
+        Some(self)
+    }
+}
+
+impl StrategySnapshot for RSIStrategy {
+    fn snapshot(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+
+    fn restore(&mut self, snapshot: serde_json::Value) -> Result<(), serde_json::Error> {
+        *self = serde_json::from_value(snapshot)?;
+        Ok(())
+    }
 }
 
 impl RSIStrategy {
@@ -57,7 +79,11 @@ impl RSIStrategy {
         let rsi_indicator = RelativeStrengthIndex::new(config.rsi_period)
             .expect("Failed to construct RSI indicator");
 
-        Self { rsi: rsi_indicator }
+        Self {
+            rsi: rsi_indicator,
+            rsi_period: config.rsi_period,
+            candles: 0,
+        }
     }
 
     /// Given the latest RSI value for a symbol, generates a map containing the [`SignalStrength`] for
@@ -0,0 +1,126 @@
+use super::{bar_signal, BarAggregator, BarSource};
+use crate::strategy::{Decision, Signal, SignalGenerator, StrategySnapshot};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+use ta::{indicators::BollingerBands, Next};
+
+/// Configuration for constructing a [`BollingerMeanReversion`] via the new() constructor method.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Config {
+    pub period: usize,
+    /// Number of standard deviations between the middle band & the upper and lower bands.
+    pub multiplier: f64,
+    pub bars: BarSource,
+}
+
+/// Mean reversion strategy that goes long when a [`Bar`] closes below the lower Bollinger Band,
+/// and short when one closes above the upper band. Positions are exited once a [`Bar`] closes
+/// back across the middle band.
+///
+/// [`Bar`]: super::Bar
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BollingerMeanReversion {
+    bars: BarAggregator,
+    bands: BollingerBands,
+    warm_up: usize,
+}
+
+impl SignalGenerator for BollingerMeanReversion {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        let bar = self.bars.next(market)?;
+
+        let bands = self.bands.next(bar.close);
+
+        // Wait until the bands span a full period
+        if self.warm_up > 1 {
+            self.warm_up -= 1;
+            return None;
+        }
+
+        let decisions: &[Decision] = if bar.close < bands.lower {
+            &[Decision::Long, Decision::CloseShort]
+        } else if bar.close > bands.upper {
+            &[Decision::Short, Decision::CloseLong]
+        } else if bar.close > bands.average {
+            &[Decision::CloseLong]
+        } else if bar.close < bands.average {
+            &[Decision::CloseShort]
+        } else {
+            &[Decision::CloseLong, Decision::CloseShort]
+        };
+
+        bar_signal(market, &bar, decisions)
+    }
+
+    fn is_warmed_up(&self) -> bool {
+        self.warm_up <= 1
+    }
+
+    fn snapshotter(&mut self) -> Option<&mut dyn StrategySnapshot> {
+        Some(self)
+    }
+}
+
+impl StrategySnapshot for BollingerMeanReversion {
+    fn snapshot(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+
+    fn restore(&mut self, snapshot: serde_json::Value) -> Result<(), serde_json::Error> {
+        *self = serde_json::from_value(snapshot)?;
+        Ok(())
+    }
+}
+
+impl BollingerMeanReversion {
+    /// Constructs a new [`BollingerMeanReversion`] component using the provided configuration
+    /// struct.
+    pub fn new(config: Config) -> Self {
+        Self {
+            bars: BarAggregator::new(config.bars),
+            bands: BollingerBands::new(config.period, config.multiplier)
+                .expect("Failed to construct Bollinger Bands indicator"),
+            warm_up: config.period,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::indicator::tests::{closes, decisions};
+
+    #[test]
+    fn bollinger_mean_reversion_advises_on_band_crosses() {
+        let mut strategy = BollingerMeanReversion::new(Config {
+            period: 4,
+            multiplier: 1.0,
+            bars: BarSource::Candles,
+        });
+
+        let actual = decisions(
+            &mut strategy,
+            &closes(&[10.0, 10.0, 10.0, 10.0, 4.0, 8.0, 10.0, 16.0]),
+        );
+
+        let expected = vec![
+            // Warm up
+            vec![],
+            vec![],
+            vec![],
+            // Close equals the middle band
+            vec![Decision::CloseLong, Decision::CloseShort],
+            // Close below lower band
+            vec![Decision::Long, Decision::CloseShort],
+            // Close reverts to the middle band
+            vec![Decision::CloseLong, Decision::CloseShort],
+            // Close crosses above the middle band
+            vec![Decision::CloseLong],
+            // Close above upper band
+            vec![Decision::CloseLong, Decision::Short],
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
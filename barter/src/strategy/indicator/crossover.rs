@@ -0,0 +1,126 @@
+use super::{bar_signal, BarAggregator, BarSource};
+use crate::strategy::{Decision, Signal, SignalGenerator, StrategySnapshot};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+use ta::{indicators::SimpleMovingAverage, Next};
+
+/// Configuration for constructing a [`MovingAverageCrossover`] via the new() constructor method.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Config {
+    pub fast_period: usize,
+    pub slow_period: usize,
+    pub bars: BarSource,
+}
+
+/// Trend following strategy that goes long when the fast simple moving average of the [`Bar`]
+/// closes crosses above the slow one, and short when it crosses below.
+///
+/// [`Bar`]: super::Bar
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MovingAverageCrossover {
+    bars: BarAggregator,
+    fast: SimpleMovingAverage,
+    slow: SimpleMovingAverage,
+    warm_up: usize,
+    previous_spread: Option<f64>,
+}
+
+impl SignalGenerator for MovingAverageCrossover {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        let bar = self.bars.next(market)?;
+
+        let spread = self.fast.next(bar.close) - self.slow.next(bar.close);
+
+        // Wait until the slow moving average spans a full period
+        if self.warm_up > 1 {
+            self.warm_up -= 1;
+            return None;
+        }
+
+        let previous = self.previous_spread.replace(spread)?;
+        let decisions: &[Decision] = if previous <= 0.0 && spread > 0.0 {
+            &[Decision::Long, Decision::CloseShort]
+        } else if previous >= 0.0 && spread < 0.0 {
+            &[Decision::Short, Decision::CloseLong]
+        } else {
+            &[]
+        };
+
+        bar_signal(market, &bar, decisions)
+    }
+
+    fn is_warmed_up(&self) -> bool {
+        self.previous_spread.is_some()
+    }
+
+    fn snapshotter(&mut self) -> Option<&mut dyn StrategySnapshot> {
+        Some(self)
+    }
+}
+
+impl StrategySnapshot for MovingAverageCrossover {
+    fn snapshot(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+
+    fn restore(&mut self, snapshot: serde_json::Value) -> Result<(), serde_json::Error> {
+        *self = serde_json::from_value(snapshot)?;
+        Ok(())
+    }
+}
+
+impl MovingAverageCrossover {
+    /// Constructs a new [`MovingAverageCrossover`] component using the provided configuration
+    /// struct.
+    pub fn new(config: Config) -> Self {
+        Self {
+            bars: BarAggregator::new(config.bars),
+            fast: SimpleMovingAverage::new(config.fast_period)
+                .expect("Failed to construct fast SMA indicator"),
+            slow: SimpleMovingAverage::new(config.slow_period)
+                .expect("Failed to construct slow SMA indicator"),
+            warm_up: config.slow_period.max(config.fast_period),
+            previous_spread: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::indicator::tests::{closes, decisions};
+
+    #[test]
+    fn moving_average_crossover_advises_on_crossovers() {
+        let mut strategy = MovingAverageCrossover::new(Config {
+            fast_period: 2,
+            slow_period: 3,
+            bars: BarSource::Candles,
+        });
+
+        let actual = decisions(
+            &mut strategy,
+            &closes(&[10.0, 10.0, 10.0, 13.0, 14.0, 10.0, 6.0, 6.0]),
+        );
+
+        let expected = vec![
+            // Warm up
+            vec![],
+            vec![],
+            // Fast == slow
+            vec![],
+            // Fast crosses above slow
+            vec![Decision::Long, Decision::CloseShort],
+            // Fast remains above slow
+            vec![],
+            // Fast crosses below slow
+            vec![Decision::CloseLong, Decision::Short],
+            // Fast remains below slow
+            vec![],
+            vec![],
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
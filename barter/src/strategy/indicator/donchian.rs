@@ -0,0 +1,126 @@
+use super::{bar_signal, BarAggregator, BarSource};
+use crate::strategy::{Decision, Signal, SignalGenerator, StrategySnapshot};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+use ta::{
+    indicators::{Maximum, Minimum},
+    Next,
+};
+
+/// Configuration for constructing a [`DonchianBreakout`] via the new() constructor method.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Config {
+    pub period: usize,
+    pub bars: BarSource,
+}
+
+/// Breakout strategy that goes long when a [`Bar`] closes above the highest high of the
+/// preceding period of [`Bar`]s, and short when one closes below the lowest low.
+///
+/// [`Bar`]: super::Bar
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DonchianBreakout {
+    bars: BarAggregator,
+    highest: Maximum,
+    lowest: Minimum,
+    warm_up: usize,
+    channel: Option<(f64, f64)>,
+}
+
+impl SignalGenerator for DonchianBreakout {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        let bar = self.bars.next(market)?;
+
+        // Compare the Bar against the channel of the preceding Bars, before updating it
+        let decisions: &[Decision] = match self.channel {
+            Some((upper, _)) if bar.close > upper => &[Decision::Long, Decision::CloseShort],
+            Some((_, lower)) if bar.close < lower => &[Decision::Short, Decision::CloseLong],
+            _ => &[],
+        };
+
+        let channel = (self.highest.next(bar.high), self.lowest.next(bar.low));
+
+        // Wait until the channel spans a full period
+        if self.warm_up > 1 {
+            self.warm_up -= 1;
+        } else {
+            self.channel = Some(channel);
+        }
+
+        bar_signal(market, &bar, decisions)
+    }
+
+    fn is_warmed_up(&self) -> bool {
+        self.channel.is_some()
+    }
+
+    fn snapshotter(&mut self) -> Option<&mut dyn StrategySnapshot> {
+        Some(self)
+    }
+}
+
+impl StrategySnapshot for DonchianBreakout {
+    fn snapshot(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+
+    fn restore(&mut self, snapshot: serde_json::Value) -> Result<(), serde_json::Error> {
+        *self = serde_json::from_value(snapshot)?;
+        Ok(())
+    }
+}
+
+impl DonchianBreakout {
+    /// Constructs a new [`DonchianBreakout`] component using the provided configuration struct.
+    pub fn new(config: Config) -> Self {
+        Self {
+            bars: BarAggregator::new(config.bars),
+            highest: Maximum::new(config.period).expect("Failed to construct Maximum indicator"),
+            lowest: Minimum::new(config.period).expect("Failed to construct Minimum indicator"),
+            warm_up: config.period,
+            channel: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::indicator::tests::{candles, decisions};
+
+    #[test]
+    fn donchian_breakout_advises_on_channel_breakouts() {
+        let mut strategy = DonchianBreakout::new(Config {
+            period: 3,
+            bars: BarSource::Candles,
+        });
+
+        let actual = decisions(
+            &mut strategy,
+            &candles(&[
+                (12.0, 8.0, 10.0),
+                (13.0, 9.0, 11.0),
+                (11.0, 7.0, 9.0),
+                (12.5, 9.0, 12.5),
+                (15.0, 12.0, 14.0),
+                (14.0, 6.0, 6.5),
+            ]),
+        );
+
+        let expected = vec![
+            // Warm up
+            vec![],
+            vec![],
+            vec![],
+            // Close within the channel [7.0, 13.0]
+            vec![],
+            // Close above the channel [7.0, 13.0]
+            vec![Decision::Long, Decision::CloseShort],
+            // Close below the channel [7.0, 15.0]
+            vec![Decision::CloseLong, Decision::Short],
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,128 @@
+use super::{bar_signal, BarAggregator, BarSource};
+use crate::strategy::{Decision, Signal, SignalGenerator, StrategySnapshot};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+use ta::{indicators::MovingAverageConvergenceDivergence, Next};
+
+/// Configuration for constructing a [`MacdCrossover`] via the new() constructor method.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Config {
+    pub fast_period: usize,
+    pub slow_period: usize,
+    pub signal_period: usize,
+    pub bars: BarSource,
+}
+
+/// Momentum strategy that goes long when the MACD line of the [`Bar`] closes crosses above it's
+/// signal line, and short when it crosses below.
+///
+/// [`Bar`]: super::Bar
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MacdCrossover {
+    bars: BarAggregator,
+    macd: MovingAverageConvergenceDivergence,
+    warm_up: usize,
+    previous_histogram: Option<f64>,
+}
+
+impl SignalGenerator for MacdCrossover {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        let bar = self.bars.next(market)?;
+
+        let histogram = self.macd.next(bar.close).histogram;
+
+        // Wait until the slow moving average spans a full period
+        if self.warm_up > 1 {
+            self.warm_up -= 1;
+            return None;
+        }
+
+        let previous = self.previous_histogram.replace(histogram)?;
+        let decisions: &[Decision] = if previous <= 0.0 && histogram > 0.0 {
+            &[Decision::Long, Decision::CloseShort]
+        } else if previous >= 0.0 && histogram < 0.0 {
+            &[Decision::Short, Decision::CloseLong]
+        } else {
+            &[]
+        };
+
+        bar_signal(market, &bar, decisions)
+    }
+
+    fn is_warmed_up(&self) -> bool {
+        self.previous_histogram.is_some()
+    }
+
+    fn snapshotter(&mut self) -> Option<&mut dyn StrategySnapshot> {
+        Some(self)
+    }
+}
+
+impl StrategySnapshot for MacdCrossover {
+    fn snapshot(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+
+    fn restore(&mut self, snapshot: serde_json::Value) -> Result<(), serde_json::Error> {
+        *self = serde_json::from_value(snapshot)?;
+        Ok(())
+    }
+}
+
+impl MacdCrossover {
+    /// Constructs a new [`MacdCrossover`] component using the provided configuration struct.
+    pub fn new(config: Config) -> Self {
+        Self {
+            bars: BarAggregator::new(config.bars),
+            macd: MovingAverageConvergenceDivergence::new(
+                config.fast_period,
+                config.slow_period,
+                config.signal_period,
+            )
+            .expect("Failed to construct MACD indicator"),
+            warm_up: config.slow_period.max(config.fast_period),
+            previous_histogram: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::indicator::tests::{closes, decisions};
+
+    #[test]
+    fn macd_crossover_advises_on_signal_line_crossovers() {
+        let mut strategy = MacdCrossover::new(Config {
+            fast_period: 2,
+            slow_period: 3,
+            signal_period: 2,
+            bars: BarSource::Candles,
+        });
+
+        let actual = decisions(
+            &mut strategy,
+            &closes(&[10.0, 10.0, 10.0, 12.0, 14.0, 14.0, 14.0, 10.0]),
+        );
+
+        let expected = vec![
+            // Warm up
+            vec![],
+            vec![],
+            // MACD equals signal line
+            vec![],
+            // MACD crosses above signal line
+            vec![Decision::Long, Decision::CloseShort],
+            // MACD remains above signal line
+            vec![],
+            // MACD crosses below signal line as momentum fades
+            vec![Decision::CloseLong, Decision::Short],
+            // MACD remains below signal line
+            vec![],
+            vec![],
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,244 @@
+use super::{Decision, Signal, SignalStrength};
+use crate::data::MarketMeta;
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+
+/// Bollinger Bands mean reversion [`SignalGenerator`](super::SignalGenerator).
+pub mod bollinger;
+
+/// Moving average crossover trend following [`SignalGenerator`](super::SignalGenerator).
+pub mod crossover;
+
+/// Donchian channel breakout [`SignalGenerator`](super::SignalGenerator).
+pub mod donchian;
+
+/// MACD signal line crossover [`SignalGenerator`](super::SignalGenerator).
+pub mod macd;
+
+/// Source of the [`Bar`]s analysed by an indicator strategy.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum BarSource {
+    /// Every [`DataKind::Candle`] is a [`Bar`], and all other [`DataKind`]s are ignored.
+    Candles,
+    /// [`DataKind::Trade`]s are aggregated into [`Bar`]s spanning the interval (aligned to the
+    /// unix epoch), and all other [`DataKind`]s are ignored. A [`Bar`] is completed by the first
+    /// trade of the next interval.
+    Trades { interval: Duration },
+}
+
+/// OHLC bar analysed by an indicator strategy.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Bar {
+    pub close_time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+/// Yields the completed [`Bar`]s of a [`MarketEvent`] feed, as configured by a [`BarSource`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BarAggregator {
+    source: BarSource,
+    current: Option<Bar>,
+}
+
+impl BarAggregator {
+    /// Constructs a new [`BarAggregator`] yielding [`Bar`]s from the provided [`BarSource`].
+    pub fn new(source: BarSource) -> Self {
+        Self {
+            source,
+            current: None,
+        }
+    }
+
+    /// Returns the [`Bar`] completed by the input [`MarketEvent`], if any.
+    pub fn next(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Bar> {
+        match (self.source, &market.kind) {
+            (BarSource::Candles, DataKind::Candle(candle)) => Some(Bar {
+                close_time: candle.close_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+            }),
+            (BarSource::Trades { interval }, DataKind::Trade(trade)) => {
+                // Determine the close time of the interval the trade falls in
+                let interval_ms = interval.as_millis().clamp(1, i64::MAX as u128) as i64;
+                let trade_ms = market.exchange_time.timestamp_millis();
+                let close_ms = (trade_ms.div_euclid(interval_ms) + 1) * interval_ms;
+                let close_time = DateTime::<Utc>::from_timestamp_millis(close_ms)?;
+
+                match &mut self.current {
+                    Some(current) if close_time <= current.close_time => {
+                        current.high = current.high.max(trade.price);
+                        current.low = current.low.min(trade.price);
+                        current.close = trade.price;
+                        None
+                    }
+                    current => current.replace(Bar {
+                        close_time,
+                        open: trade.price,
+                        high: trade.price,
+                        low: trade.price,
+                        close: trade.price,
+                    }),
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Generates a [`Signal`] advising the provided [`Decision`]s with full [`SignalStrength`] in
+/// response to the [`Bar`] completed by the input [`MarketEvent`]. Returns `None` if no
+/// [`Decision`]s are advised.
+fn bar_signal(
+    market: &MarketEvent<Instrument, DataKind>,
+    bar: &Bar,
+    decisions: &[Decision],
+) -> Option<Signal> {
+    if decisions.is_empty() {
+        return None;
+    }
+
+    Some(Signal {
+        time: market.exchange_time,
+        exchange: market.exchange.clone(),
+        instrument: market.instrument.clone(),
+        signals: decisions
+            .iter()
+            .map(|decision| (*decision, SignalStrength(1.0)))
+            .collect::<HashMap<_, _>>(),
+        target_exposure: None,
+        market_meta: MarketMeta {
+            close: bar.close,
+            time: market.exchange_time,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{strategy::SignalGenerator, test_util::market_event_candle};
+    use barter_data::subscription::{candle::Candle, trade::PublicTrade};
+    use barter_integration::model::Side;
+
+    /// Build a [`DataKind::Candle`] [`MarketEvent`] for each (high, low, close) bar.
+    pub(super) fn candles(bars: &[(f64, f64, f64)]) -> Vec<MarketEvent<Instrument, DataKind>> {
+        let start = market_event_candle().exchange_time;
+        bars.iter()
+            .enumerate()
+            .map(|(index, (high, low, close))| {
+                let time = start + chrono::Duration::minutes(index as i64);
+                MarketEvent {
+                    exchange_time: time,
+                    kind: DataKind::Candle(Candle {
+                        close_time: time,
+                        open: *close,
+                        high: *high,
+                        low: *low,
+                        close: *close,
+                        volume: 1.0,
+                        trade_count: 1,
+                    }),
+                    ..market_event_candle()
+                }
+            })
+            .collect()
+    }
+
+    /// Build a [`DataKind::Candle`] [`MarketEvent`] for each close price.
+    pub(super) fn closes(closes: &[f64]) -> Vec<MarketEvent<Instrument, DataKind>> {
+        candles(
+            &closes
+                .iter()
+                .map(|close| (*close, *close, *close))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Drive the [`SignalGenerator`] with every [`MarketEvent`], returning the sorted
+    /// [`Decision`]s advised in response to each.
+    pub(super) fn decisions<Strategy>(
+        strategy: &mut Strategy,
+        markets: &[MarketEvent<Instrument, DataKind>],
+    ) -> Vec<Vec<Decision>>
+    where
+        Strategy: SignalGenerator,
+    {
+        markets
+            .iter()
+            .map(|market| {
+                let mut decisions = strategy
+                    .generate_signal(market)
+                    .map(|signal| signal.signals.into_keys().collect::<Vec<_>>())
+                    .unwrap_or_default();
+                decisions.sort();
+                decisions
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bar_aggregator_aggregates_trades_into_interval_bars() {
+        let start = DateTime::<Utc>::from_timestamp(1_699_999_980, 0).unwrap();
+        let trade = |seconds: i64, price: f64| MarketEvent {
+            exchange_time: start + chrono::Duration::seconds(seconds),
+            received_time: start + chrono::Duration::seconds(seconds),
+            kind: DataKind::Trade(PublicTrade {
+                id: "trade_id".to_string(),
+                price,
+                amount: 1.0,
+                side: Side::Buy,
+            }),
+            ..market_event_candle()
+        };
+
+        let mut aggregator = BarAggregator::new(BarSource::Trades {
+            interval: Duration::from_secs(60),
+        });
+
+        // Candles are ignored when aggregating trades
+        assert_eq!(aggregator.next(&market_event_candle()), None);
+
+        let actual = [
+            trade(0, 100.0),
+            trade(10, 105.0),
+            trade(20, 95.0),
+            trade(59, 101.0),
+            trade(60, 110.0),
+            trade(185, 120.0),
+        ]
+        .iter()
+        .map(|market| aggregator.next(market))
+        .collect::<Vec<_>>();
+
+        let expected = vec![
+            None,
+            None,
+            None,
+            None,
+            Some(Bar {
+                close_time: start + chrono::Duration::seconds(60),
+                open: 100.0,
+                high: 105.0,
+                low: 95.0,
+                close: 101.0,
+            }),
+            Some(Bar {
+                close_time: start + chrono::Duration::seconds(120),
+                open: 110.0,
+                high: 110.0,
+                low: 110.0,
+                close: 110.0,
+            }),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
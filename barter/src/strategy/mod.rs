@@ -1,31 +1,97 @@
 use crate::data::MarketMeta;
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{instrument::Instrument, Exchange, Market};
+use barter_integration::model::{instrument::Instrument, Exchange, Market, Side};
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
+
+/// Portfolio-level strategies that analyse the [`MarketEvent`]s of many markets, and may
+/// generate [`Signal`]s for any of them.
+pub mod cross_market;
+
+/// Composite [`SignalGenerator`] that combines the [`Signal`]s of several [`SignalGenerator`]s
+/// trading the same market.
+pub mod ensemble;
 
 /// Barter example RSI strategy [`SignalGenerator`] implementation.
 pub mod example;
 
+/// Library of configurable indicator based reference [`SignalGenerator`] implementations (eg/
+/// moving average crossover, MACD) that analyse candles, or trades aggregated into bars.
+pub mod indicator;
+
 /// May generate an advisory [`Signal`] as a result of analysing an input [`MarketEvent`].
 pub trait SignalGenerator {
     /// Optionally return a [`Signal`] given input [`MarketEvent`].
     fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal>;
+
+    /// Returns `true` once enough [`MarketEvent`]s have been analysed to generate meaningful
+    /// [`Signal`]s (eg/ an indicator spans it's full period). A
+    /// [`Trader`](crate::engine::trader::Trader) discards any [`Signal`]s generated before then.
+    fn is_warmed_up(&self) -> bool {
+        true
+    }
+
+    /// Takes the [`Signal`]s for other markets generated by the last call to
+    /// [`SignalGenerator::generate_signal`] (see
+    /// [`CrossMarketStrategy`](cross_market::CrossMarketStrategy)). A
+    /// [`Trader`](crate::engine::trader::Trader) routes them to the
+    /// [`Trader`](crate::engine::trader::Trader) of their market via the
+    /// [`Engine`](crate::engine::Engine), unless they were generated during warm up or before the
+    /// strategy is warmed up, in which case they are discarded.
+    fn take_routed_signals(&mut self) -> Vec<Signal> {
+        Vec::new()
+    }
+
+    /// Returns the [`StrategySnapshot`] implementation of this strategy, if it's state can be
+    /// snapshotted & restored.
+    fn snapshotter(&mut self) -> Option<&mut dyn StrategySnapshot> {
+        None
+    }
+}
+
+/// Strategy whose in-memory state (eg/ indicator values) can be serialised & restored, enabling
+/// it to survive an [`Engine`](crate::engine::Engine) restart. Exposed to a
+/// [`Trader`](crate::engine::trader::Trader) via [`SignalGenerator::snapshotter`].
+pub trait StrategySnapshot {
+    /// Serialise the current state of the strategy.
+    fn snapshot(&self) -> Result<serde_json::Value, serde_json::Error>;
+
+    /// Restore the strategy to the state of a snapshot previously taken.
+    fn restore(&mut self, snapshot: serde_json::Value) -> Result<(), serde_json::Error>;
 }
 
 /// Advisory [`Signal`] for a [`Market`] detailing the [`SignalStrength`] associated with each
-/// possible [`Decision`]. Interpreted by an [`OrderGenerator`](crate::portfolio::OrderGenerator).
+/// possible [`Decision`], or alternatively the [`TargetExposure`] to rebalance the market
+/// towards. Interpreted by an [`OrderGenerator`](crate::portfolio::OrderGenerator).
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
 pub struct Signal {
     pub time: DateTime<Utc>,
     pub exchange: Exchange,
     pub instrument: Instrument,
+    #[serde(serialize_with = "se_signals_ordered")]
     pub signals: HashMap<Decision, SignalStrength>,
+    /// Target exposure of the market. If present, the signals are ignored & the
+    /// [`OrderGenerator`](crate::portfolio::OrderGenerator) generates the delta order that moves
+    /// the current Position to the target.
+    #[serde(default)]
+    pub target_exposure: Option<TargetExposure>,
     /// Metadata propagated from the [`MarketEvent`] that yielded this [`Signal`].
     pub market_meta: MarketMeta,
 }
 
+/// Serialize a [`Signal`] signals map ordered by [`Decision`], ensuring identical [`Signal`]s
+/// always produce identical output.
+fn se_signals_ordered<S>(
+    signals: &HashMap<Decision, SignalStrength>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_map(signals.iter().collect::<BTreeMap<_, _>>())
+}
+
 /// Describes the type of advisory signal the strategy is endorsing.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub enum Decision {
@@ -61,12 +127,26 @@ impl Decision {
     pub fn is_exit(&self) -> bool {
         matches!(self, Decision::CloseLong | Decision::CloseShort)
     }
+
+    /// Determines the [`Side`] of an order that actions the [`Decision`].
+    pub fn order_side(&self) -> Side {
+        match self {
+            Decision::Long | Decision::CloseShort => Side::Buy,
+            Decision::Short | Decision::CloseLong => Side::Sell,
+        }
+    }
 }
 
 /// Strength of an advisory [`Signal`] decision produced by [`SignalGenerator`] strategy.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct SignalStrength(pub f64);
 
+/// Target exposure of a market advised by a [`SignalGenerator`] strategy, as a signed fraction of
+/// the allocation an [`OrderAllocator`](crate::portfolio::allocator::OrderAllocator) sizes a full
+/// strength entry with (eg/ 0.6 for 60% long, -0.3 for 30% short, 0.0 for flat).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct TargetExposure(pub f64);
+
 /// Force exit Signal produced after an [`Engine`](crate::engine::Engine) receives a
 /// [`Command::ExitPosition`](crate::engine::Command) from an external source.
 #[derive(Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
@@ -76,31 +156,30 @@ pub struct SignalForceExit {
     pub instrument: Instrument,
 }
 
-impl<M> From<M> for SignalForceExit
-where
-    M: Into<Market>,
-{
-    fn from(market: M) -> Self {
-        let market = market.into();
-        Self::new(market.exchange, market.instrument)
-    }
-}
-
 impl SignalForceExit {
     pub const FORCED_EXIT_SIGNAL: &'static str = "SignalForcedExit";
 
     /// Constructs a new [`Self`] using the configuration provided.
-    pub fn new<E, I>(exchange: E, instrument: I) -> Self
+    pub fn new<E, I>(time: DateTime<Utc>, exchange: E, instrument: I) -> Self
     where
         E: Into<Exchange>,
         I: Into<Instrument>,
     {
         Self {
-            time: Utc::now(),
+            time,
             exchange: exchange.into(),
             instrument: instrument.into(),
         }
     }
+
+    /// Constructs a new [`Self`] for the provided [`Market`] at the provided time.
+    pub fn from_market<M>(time: DateTime<Utc>, market: M) -> Self
+    where
+        M: Into<Market>,
+    {
+        let market = market.into();
+        Self::new(time, market.exchange, market.instrument)
+    }
 }
 
 #[cfg(test)]
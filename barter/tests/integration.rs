@@ -1,24 +1,58 @@
 use barter::{
-    data::historical,
-    engine::{trader::Trader, Engine},
-    event::EventTx,
+    data::{
+        historical,
+        warm_up::{JsonFileWarmUp, WarmUpSource, WarmUpWindow},
+        MarketMeta,
+    },
+    engine::{
+        clock::{EngineClock, HistoricalClock, LiveClock},
+        policy::{ErrorAction, ErrorClass, ErrorPolicy, ErrorResponse},
+        trader::{Trader, TraderBuilder},
+        AddTrader, Command, Engine, EngineBuilder,
+    },
+    event::{
+        journal::{replay, JournalFormat, JournalReader, JournalTx},
+        Event, EventTx, MessageTransmitter,
+    },
     execution::{
+        error::ExecutionError,
         simulated::{Config as ExecutionConfig, SimulatedExecution},
-        Fees,
+        ExecutionClient, Fees, FillEvent, OrderStatus,
     },
     portfolio::{
-        allocator::DefaultAllocator, portfolio::MetaPortfolio,
-        repository::in_memory::InMemoryRepository, risk::DefaultRisk,
+        allocator::DefaultAllocator,
+        portfolio::{MetaPortfolio, MetaPortfolioBuilder},
+        position::determine_position_id,
+        protection::{PriceOffset, ProtectiveExitConfig},
+        repository::{
+            in_memory::InMemoryRepository, BalanceHandler, EquityHandler, PositionHandler,
+            StatisticHandler, StrategySnapshotHandler,
+        },
+        risk::DefaultRisk,
+        FillUpdater, MarketUpdater, OrderEvent, OrderGenerator,
     },
     statistic::summary::{
         trading::{Config as StatisticConfig, TradingSummary},
         Initialiser,
     },
-    strategy::example::{Config as StrategyConfig, RSIStrategy},
+    strategy::{
+        cross_market::{CrossMarketSignalGenerator, CrossMarketStrategy},
+        example::{Config as StrategyConfig, RSIStrategy},
+        Decision, Signal, SignalGenerator, SignalStrength, StrategySnapshot,
+    },
     test_util::market_event_trade,
 };
-use barter_integration::model::{instrument::kind::InstrumentKind, Market, Side};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::{
+    amount,
+    model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Market, MarketId, Side,
+    },
+};
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, sync::Arc, time::Duration};
 use tokio::sync::mpsc;
 use uuid::Uuid;
@@ -43,10 +77,11 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
         MetaPortfolio::builder()
             .engine_id(engine_id)
             .markets(vec![market.clone()])
-            .starting_cash(10_000.0)
+            .starting_cash(amount!(10_000.0))
             .repository(InMemoryRepository::new())
             .allocation_manager(DefaultAllocator {
                 default_order_value: 100.0,
+                ..Default::default()
             })
             .risk_manager(DefaultRisk {})
             .statistic_config(StatisticConfig {
@@ -54,6 +89,7 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
                 trading_days_per_year: 365,
                 risk_free_return: 0.0,
             })
+            .clock(LiveClock)
             .build_and_init()
             .expect("failed to build & initialise MetaPortfolio"),
     ));
@@ -75,13 +111,17 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
                 [market_event_trade(Side::Buy)].into_iter(),
             ))
             .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
-            .execution(SimulatedExecution::new(ExecutionConfig {
-                simulated_fees_pct: Fees {
-                    exchange: 0.1,
-                    slippage: 0.05,
-                    network: 0.0,
+            .execution(SimulatedExecution::new(
+                ExecutionConfig {
+                    simulated_fees_pct: Fees {
+                        exchange: amount!(0.1),
+                        slippage: amount!(0.05),
+                        network: amount!(0.0),
+                    },
                 },
-            }))
+                LiveClock,
+            ))
+            .clock(LiveClock)
             .build()
             .expect("failed to build trader"),
     );
@@ -96,11 +136,14 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
         .portfolio(portfolio)
         .traders(traders)
         .trader_command_txs(trader_command_txs)
-        .statistics_summary(TradingSummary::init(StatisticConfig {
-            starting_equity: 1000.0,
-            trading_days_per_year: 365,
-            risk_free_return: 0.0,
-        }))
+        .statistics_summary(TradingSummary::init(
+            StatisticConfig {
+                starting_equity: 1000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            },
+            Utc::now(),
+        ))
         .build()
         .expect("failed to build engine");
 
@@ -116,3 +159,1206 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
         "failed because Engine's command_rx.await is blocking the Engine from stopping"
     )
 }
+
+/// Statistic configuration shared by the Portfolio & Engine of every test.
+const STATISTIC_CONFIG: StatisticConfig = StatisticConfig {
+    starting_equity: 10_000.0,
+    trading_days_per_year: 365,
+    risk_free_return: 0.0,
+};
+
+/// [`MetaPortfolio`] shared by the [`Trader`]s of every test.
+type TestPortfolio<Clock> = MetaPortfolio<
+    InMemoryRepository<TradingSummary>,
+    DefaultAllocator,
+    DefaultRisk,
+    TradingSummary,
+    Clock,
+>;
+
+/// Historical [`MarketEvent`] feed traded by the [`Trader`]s of every test.
+type TestFeed = historical::MarketFeed<
+    std::vec::IntoIter<MarketEvent<Instrument, DataKind>>,
+    MarketEvent<Instrument, DataKind>,
+>;
+
+/// Build a [`MetaPortfolioBuilder`] tracking the provided [`Market`]s with $10,000 starting cash,
+/// an [`InMemoryRepository`], a $100 [`DefaultAllocator`] & [`DefaultRisk`]. Tests override any
+/// other component before building.
+fn portfolio_builder<Clock>(
+    engine_id: Uuid,
+    markets: Vec<Market>,
+    clock: Clock,
+) -> MetaPortfolioBuilder<
+    InMemoryRepository<TradingSummary>,
+    DefaultAllocator,
+    DefaultRisk,
+    TradingSummary,
+    Clock,
+>
+where
+    Clock: EngineClock,
+{
+    MetaPortfolio::builder()
+        .engine_id(engine_id)
+        .markets(markets)
+        .starting_cash(amount!(10_000.0))
+        .repository(InMemoryRepository::new())
+        .allocation_manager(DefaultAllocator {
+            default_order_value: 100.0,
+            ..Default::default()
+        })
+        .risk_manager(DefaultRisk {})
+        .statistic_config(STATISTIC_CONFIG)
+        .clock(clock)
+}
+
+/// Build & initialise the global shared-state [`MetaPortfolio`] of the default
+/// [`portfolio_builder`].
+fn portfolio<Clock>(
+    engine_id: Uuid,
+    markets: Vec<Market>,
+    clock: Clock,
+) -> Arc<Mutex<TestPortfolio<Clock>>>
+where
+    Clock: EngineClock,
+{
+    Arc::new(Mutex::new(
+        portfolio_builder(engine_id, markets, clock)
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ))
+}
+
+/// Build a trade [`MarketEvent`] of the provided [`Market`] at each of the seconds after the
+/// start time.
+fn market_events(
+    market: &Market,
+    start_time: DateTime<Utc>,
+    seconds: &[i64],
+) -> Vec<MarketEvent<Instrument, DataKind>> {
+    seconds
+        .iter()
+        .map(|seconds| {
+            let mut event = market_event_trade(Side::Buy);
+            event.exchange_time = start_time + chrono::Duration::seconds(*seconds);
+            event.exchange = market.exchange.clone();
+            event.instrument = market.instrument.clone();
+            event
+        })
+        .collect()
+}
+
+/// Build a [`TraderBuilder`] for the provided [`Market`] that trades the historical
+/// [`MarketEvent`]s via a fee-less [`SimulatedExecution`], along with the [`Command`]
+/// transmitter the [`Engine`] routes [`Command`]s to it with. Tests provide the `event_tx` &
+/// `strategy`, and override any other component before building.
+#[allow(clippy::type_complexity)]
+fn trader_builder<EventTx, Portfolio, Strategy, Clock>(
+    engine_id: Uuid,
+    market: &Market,
+    portfolio: &Arc<Mutex<Portfolio>>,
+    market_events: Vec<MarketEvent<Instrument, DataKind>>,
+    clock: Clock,
+) -> (
+    TraderBuilder<
+        EventTx,
+        TradingSummary,
+        Portfolio,
+        TestFeed,
+        Strategy,
+        SimulatedExecution<Clock>,
+        Clock,
+    >,
+    mpsc::Sender<Command>,
+)
+where
+    EventTx: MessageTransmitter<Event>,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + StrategySnapshotHandler,
+    Strategy: SignalGenerator + Send,
+    Clock: EngineClock + Clone + Send,
+{
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    let builder = Trader::builder()
+        .engine_id(engine_id)
+        .market(market.clone())
+        .command_rx(trader_command_rx)
+        .portfolio(Arc::clone(portfolio))
+        .data(historical::MarketFeed::new(market_events))
+        .execution(SimulatedExecution::new(
+            ExecutionConfig {
+                simulated_fees_pct: Fees::default(),
+            },
+            clock.clone(),
+        ))
+        .clock(clock);
+
+    (builder, trader_command_tx)
+}
+
+/// Build an [`EngineBuilder`] for the provided [`Trader`]s, routing [`Command`]s to each via the
+/// transmitter it was built with. Tests override any other component before building.
+#[allow(clippy::type_complexity)]
+fn engine_builder<EventTx, Portfolio, Strategy, Execution, Clock>(
+    engine_id: Uuid,
+    command_rx: mpsc::Receiver<Command>,
+    portfolio: &Arc<Mutex<Portfolio>>,
+    traders: Vec<(
+        Trader<EventTx, TradingSummary, Portfolio, TestFeed, Strategy, Execution, Clock>,
+        mpsc::Sender<Command>,
+    )>,
+) -> EngineBuilder<EventTx, TradingSummary, Portfolio, TestFeed, Strategy, Execution, Clock>
+where
+    EventTx: MessageTransmitter<Event> + Send + 'static,
+    Portfolio: PositionHandler
+        + BalanceHandler
+        + StatisticHandler<TradingSummary>
+        + StrategySnapshotHandler
+        + EquityHandler
+        + MarketUpdater
+        + OrderGenerator
+        + FillUpdater
+        + Send
+        + 'static,
+    Strategy: SignalGenerator + Send + 'static,
+    Execution: ExecutionClient + Send + 'static,
+    Clock: EngineClock + Send + 'static,
+{
+    let (trader_command_txs, traders): (HashMap<_, _>, Vec<_>) = traders
+        .into_iter()
+        .map(|(trader, command_tx)| ((trader.market().clone(), command_tx), trader))
+        .unzip();
+
+    Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(Arc::clone(portfolio))
+        .traders(traders)
+        .trader_command_txs(trader_command_txs)
+        .statistics_summary(TradingSummary::init(STATISTIC_CONFIG, Utc::now()))
+}
+
+/// Collect every [`Event`] sent so far, in the order they were sent.
+fn drain_events(event_rx: &mut mpsc::UnboundedReceiver<Event>) -> Vec<Event> {
+    let mut events = Vec::new();
+    while let Ok(event) = event_rx.try_recv() {
+        events.push(event);
+    }
+    events
+}
+
+#[test]
+fn engine_run_backtest_processes_markets_in_exchange_time_order() {
+    let (_command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+    let engine_id = Uuid::new_v4();
+    let start_time = Utc::now();
+    let clock = HistoricalClock::new(start_time);
+
+    // Create the Markets to be traded on, each with interleaved historical MarketEvent times
+    let btc = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let eth = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+    let portfolio = portfolio(engine_id, vec![btc.clone(), eth.clone()], clock.clone());
+
+    let traders = [(&btc, vec![0, 2, 4]), (&eth, vec![1, 3])]
+        .into_iter()
+        .map(|(market, seconds)| {
+            let (trader, trader_command_tx) = trader_builder(
+                engine_id,
+                market,
+                &portfolio,
+                market_events(market, start_time, &seconds),
+                clock.clone(),
+            );
+            let trader = trader
+                .event_tx(event_tx.clone())
+                .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+                .build()
+                .expect("failed to build trader");
+            (trader, trader_command_tx)
+        })
+        .collect();
+
+    engine_builder(engine_id, command_rx, &portfolio, traders)
+        .build()
+        .expect("failed to build engine")
+        .run_backtest();
+
+    // Collect the (exchange_time, base) of every MarketEvent the Engine processed
+    let actual = drain_events(&mut event_rx)
+        .into_iter()
+        .filter_map(|event| match event {
+            Event::Market(market) => {
+                Some((market.exchange_time, market.instrument.base.to_string()))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let expected = [(0, "btc"), (1, "eth"), (2, "btc"), (3, "eth"), (4, "btc")]
+        .into_iter()
+        .map(|(seconds, base)| {
+            (
+                start_time + chrono::Duration::seconds(seconds),
+                base.to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(actual, expected);
+    assert_eq!(clock.time(), start_time + chrono::Duration::seconds(4));
+}
+
+/// [`SignalGenerator`] that advises to go long on every [`MarketEvent`].
+struct AlwaysLongStrategy;
+
+impl SignalGenerator for AlwaysLongStrategy {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        Some(Signal {
+            time: market.exchange_time,
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            signals: HashMap::from([(Decision::Long, SignalStrength(1.0))]),
+            target_exposure: None,
+            market_meta: MarketMeta {
+                close: 100.0,
+                time: market.exchange_time,
+            },
+        })
+    }
+}
+
+#[test]
+fn trader_sends_order_update_keyed_by_cid_for_every_order() {
+    let (_command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let engine_id = Uuid::new_v4();
+    let market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let portfolio = portfolio(engine_id, vec![market.clone()], LiveClock);
+
+    let (trader, trader_command_tx) = trader_builder(
+        engine_id,
+        &market,
+        &portfolio,
+        market_events(&market, Utc::now(), &[0]),
+        LiveClock,
+    );
+    let trader = trader
+        .event_tx(EventTx::new(event_tx))
+        .strategy(AlwaysLongStrategy)
+        .build()
+        .expect("failed to build trader");
+
+    engine_builder(
+        engine_id,
+        command_rx,
+        &portfolio,
+        vec![(trader, trader_command_tx)],
+    )
+    .build()
+    .expect("failed to build engine")
+    .run_backtest();
+
+    // Collect the OrderNew, OrderUpdate & Fill Events in the order they were sent
+    let events = drain_events(&mut event_rx)
+        .into_iter()
+        .filter(|event| {
+            matches!(
+                event,
+                Event::OrderNew(_) | Event::OrderUpdate(_) | Event::Fill(_)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(events.len(), 3);
+    let Event::OrderNew(order) = &events[0] else {
+        panic!("expected Event::OrderNew, found: {:?}", events[0]);
+    };
+    let Event::OrderUpdate(update) = &events[1] else {
+        panic!("expected Event::OrderUpdate, found: {:?}", events[1]);
+    };
+    assert_eq!(update.cid, order.cid);
+    assert_eq!(update.status, OrderStatus::Filled);
+    assert!(matches!(events[2], Event::Fill(_)));
+}
+
+#[test]
+fn engine_run_backtest_actions_pause_resume_remove_and_add_trader_commands() {
+    let (command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+    let engine_id = Uuid::new_v4();
+    let start_time = Utc::now();
+    let clock = HistoricalClock::new(start_time);
+
+    // Create channel to hot-add Traders to the Engine
+    let (trader_tx, trader_rx) = mpsc::channel(5);
+
+    let btc = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let eth = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+    let ada = Market::new("binance", ("ada", "usdt", InstrumentKind::Spot));
+    let sol = Market::new("binance", ("sol", "usdt", InstrumentKind::Spot));
+    let portfolio = portfolio(
+        engine_id,
+        vec![btc.clone(), eth.clone(), ada.clone()],
+        clock.clone(),
+    );
+
+    // Build a Trader for a Market that always goes long, yielding MarketEvents at the seconds
+    let build_trader = |market: &Market, seconds: &[i64]| {
+        let (trader, trader_command_tx) = trader_builder(
+            engine_id,
+            market,
+            &portfolio,
+            market_events(market, start_time, seconds),
+            clock.clone(),
+        );
+        let trader = trader
+            .event_tx(event_tx.clone())
+            .strategy(AlwaysLongStrategy)
+            .build()
+            .expect("failed to build trader");
+        (trader, trader_command_tx)
+    };
+
+    let traders = vec![
+        build_trader(&btc, &[0, 3]),
+        build_trader(&eth, &[1, 4]),
+        build_trader(&ada, &[2, 5]),
+    ];
+
+    // Hot-add a Trader for a Market the Portfolio starts tracking after it was initialised
+    portfolio
+        .lock()
+        .add_market(sol.clone(), STATISTIC_CONFIG)
+        .expect("failed to add Market to MetaPortfolio");
+    let (trader, command_tx_sol) = build_trader(&sol, &[6]);
+    trader_tx
+        .try_send(AddTrader {
+            trader,
+            command_tx: command_tx_sol,
+        })
+        .expect("failed to send AddTrader");
+
+    // Pause btc, pause then resume ada, & remove eth
+    for command in [
+        Command::PauseTrader(btc),
+        Command::PauseTrader(ada.clone()),
+        Command::ResumeTrader(ada),
+        Command::RemoveTrader(eth),
+    ] {
+        command_tx
+            .try_send(command)
+            .expect("failed to send Command");
+    }
+
+    engine_builder(engine_id, command_rx, &portfolio, traders)
+        .trader_rx(trader_rx)
+        .build()
+        .expect("failed to build engine")
+        .run_backtest();
+
+    // Collect the (exchange_time, base) of every MarketEvent, & the base of every OrderNew
+    let mut markets = Vec::new();
+    let mut orders = Vec::new();
+    for event in drain_events(&mut event_rx) {
+        match event {
+            Event::Market(market) => {
+                markets.push((market.exchange_time, market.instrument.base.to_string()))
+            }
+            Event::OrderNew(order) => orders.push(order.instrument.base.to_string()),
+            _ => {}
+        }
+    }
+
+    // Removed eth Trader processes no MarketEvents
+    let expected_markets = [(0, "btc"), (2, "ada"), (3, "btc"), (5, "ada"), (6, "sol")]
+        .into_iter()
+        .map(|(seconds, base)| {
+            (
+                start_time + chrono::Duration::seconds(seconds),
+                base.to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(markets, expected_markets);
+
+    // Paused btc Trader enters no Positions, but resumed ada & hot-added sol Traders do
+    assert_eq!(orders, vec!["ada".to_string(), "sol".to_string()]);
+}
+
+#[test]
+fn engine_run_backtest_actions_fetch_commands() {
+    let (command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, _event_rx) = mpsc::unbounded_channel();
+    let engine_id = Uuid::new_v4();
+    let clock = HistoricalClock::new(Utc::now());
+    let market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let portfolio = portfolio(engine_id, vec![market.clone()], clock.clone());
+
+    let (trader, trader_command_tx) = trader_builder(
+        engine_id,
+        &market,
+        &portfolio,
+        vec![market_event_trade(Side::Buy)],
+        clock,
+    );
+    let trader = trader
+        .event_tx(EventTx::new(event_tx))
+        .strategy(AlwaysLongStrategy)
+        .build()
+        .expect("failed to build trader");
+
+    // Queue the fetch Commands so they are actioned before any MarketEvent is processed
+    let (balance_tx, mut balance_rx) = tokio::sync::oneshot::channel();
+    let (statistics_tx, mut statistics_rx) = tokio::sync::oneshot::channel();
+    let (positions_tx, mut positions_rx) = tokio::sync::oneshot::channel();
+    let (market_times_tx, mut market_times_rx) = tokio::sync::oneshot::channel();
+    let (equity_curve_tx, mut equity_curve_rx) = tokio::sync::oneshot::channel();
+    for command in [
+        Command::FetchBalance(balance_tx),
+        Command::FetchStatistics(statistics_tx),
+        Command::FetchExitedPositions(positions_tx),
+        Command::FetchMarketTimes(market_times_tx),
+        Command::FetchEquityCurve(equity_curve_tx),
+    ] {
+        command_tx
+            .try_send(command)
+            .expect("failed to send Command");
+    }
+
+    engine_builder(
+        engine_id,
+        command_rx,
+        &portfolio,
+        vec![(trader, trader_command_tx)],
+    )
+    .build()
+    .expect("failed to build engine")
+    .run_backtest();
+
+    let balance = balance_rx
+        .try_recv()
+        .expect("failed to receive Balance")
+        .expect("failed to fetch Balance");
+    assert_eq!(balance.total, amount!(10_000.0));
+    assert_eq!(balance.available, amount!(10_000.0));
+
+    let statistics = statistics_rx
+        .try_recv()
+        .expect("failed to receive statistics")
+        .expect("failed to fetch statistics");
+    assert_eq!(statistics.len(), 1);
+    assert!(statistics.contains_key(&market));
+
+    let exited_positions = positions_rx
+        .try_recv()
+        .expect("failed to receive exited Positions")
+        .expect("failed to fetch exited Positions");
+    assert!(exited_positions.is_empty());
+
+    let market_times = market_times_rx
+        .try_recv()
+        .expect("failed to receive MarketEvent times");
+    assert_eq!(market_times, HashMap::from([(market, None)]));
+
+    let equity_curve = equity_curve_rx
+        .try_recv()
+        .expect("failed to receive equity curve")
+        .expect("failed to fetch equity curve");
+    assert!(equity_curve.is_empty());
+}
+
+/// Execution handler that fails to poll in-flight Fills the first `failures` times.
+struct FailingExecution {
+    failures: usize,
+}
+
+impl ExecutionClient for FailingExecution {
+    fn generate_fill(&mut self, _: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        Ok(None)
+    }
+
+    fn poll_fills(&mut self) -> Result<Vec<FillEvent>, ExecutionError> {
+        if self.failures == 0 {
+            return Ok(Vec::new());
+        }
+        self.failures -= 1;
+        Err(ExecutionError::Client(
+            barter_execution::error::ExecutionError::Simulated("exchange offline".to_owned()),
+        ))
+    }
+}
+
+#[test]
+fn trader_responds_to_errors_as_configured_by_error_policy() {
+    struct TestCase {
+        failures: usize,
+        response: ErrorResponse,
+        expected_markets: usize,
+        expected_errors: usize,
+        expected_halt: Option<ErrorAction>,
+    }
+
+    let retry_then_halt = ErrorResponse {
+        max_retries: 2,
+        backoff: Duration::ZERO,
+        action: ErrorAction::HaltTrader,
+    };
+    let skip = ErrorResponse {
+        action: ErrorAction::Skip,
+        ..Default::default()
+    };
+
+    let cases = vec![
+        TestCase {
+            // TC0: Error recovered by retrying is not sent as an Event::Error
+            failures: 2,
+            response: retry_then_halt,
+            expected_markets: 2,
+            expected_errors: 0,
+            expected_halt: None,
+        },
+        TestCase {
+            // TC1: Error persisting after retries halts the Trader before any MarketEvent is sent
+            failures: 3,
+            response: retry_then_halt,
+            expected_markets: 0,
+            expected_errors: 1,
+            expected_halt: Some(ErrorAction::HaltTrader),
+        },
+        TestCase {
+            // TC2: Skipped errors are sent as an Event::Error & the Trader continues trading
+            failures: usize::MAX,
+            response: skip,
+            expected_markets: 2,
+            expected_errors: 2,
+            expected_halt: None,
+        },
+    ];
+
+    let market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let engine_id = Uuid::new_v4();
+
+    for (index, test) in cases.into_iter().enumerate() {
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let (_trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+        let trader: Trader<_, TradingSummary, _, _, _, _, _> = Trader::builder()
+            .engine_id(engine_id)
+            .market(market.clone())
+            .command_rx(trader_command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(portfolio(engine_id, vec![market.clone()], LiveClock))
+            .data(historical::MarketFeed::new(market_events(
+                &market,
+                Utc::now(),
+                &[0, 1],
+            )))
+            .strategy(AlwaysLongStrategy)
+            .execution(FailingExecution {
+                failures: test.failures,
+            })
+            .clock(LiveClock)
+            .error_policy(ErrorPolicy {
+                execution_poll: test.response,
+                ..Default::default()
+            })
+            .build()
+            .expect("failed to build trader");
+
+        let halt = trader.run();
+
+        let mut markets = 0;
+        let mut errors = 0;
+        for event in drain_events(&mut event_rx) {
+            match event {
+                Event::Market(_) => markets += 1,
+                Event::Error(error) => {
+                    assert_eq!(error.class, ErrorClass::ExecutionPoll, "TC{} failed", index);
+                    errors += 1;
+                }
+                _ => {}
+            }
+        }
+
+        assert_eq!(markets, test.expected_markets, "TC{} failed", index);
+        assert_eq!(errors, test.expected_errors, "TC{} failed", index);
+        assert_eq!(
+            halt.map(|error| error.action),
+            test.expected_halt,
+            "TC{} failed",
+            index
+        );
+    }
+}
+
+/// Execution handler that fails to execute every OrderEvent.
+struct RejectingExecution;
+
+impl ExecutionClient for RejectingExecution {
+    fn generate_fill(&mut self, _: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        Err(ExecutionError::Client(
+            barter_execution::error::ExecutionError::Simulated("insufficient margin".to_owned()),
+        ))
+    }
+}
+
+#[test]
+fn trader_applies_error_policy_to_order_execution_errors() {
+    let market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let engine_id = Uuid::new_v4();
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let (_trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    let trader: Trader<_, TradingSummary, _, _, _, _, _> = Trader::builder()
+        .engine_id(engine_id)
+        .market(market.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .portfolio(portfolio(engine_id, vec![market.clone()], LiveClock))
+        .data(historical::MarketFeed::new(market_events(
+            &market,
+            Utc::now(),
+            &[0, 1],
+        )))
+        .strategy(AlwaysLongStrategy)
+        .execution(RejectingExecution)
+        .clock(LiveClock)
+        .error_policy(ErrorPolicy {
+            order_execution: ErrorResponse {
+                action: ErrorAction::Skip,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .build()
+        .expect("failed to build trader");
+
+    assert_eq!(trader.run(), None);
+
+    let mut errors = 0;
+    let mut rejections = 0;
+    for event in drain_events(&mut event_rx) {
+        match event {
+            Event::Error(error) => {
+                assert_eq!(error.class, ErrorClass::OrderExecution);
+                assert_eq!(error.kind, "Client");
+                errors += 1;
+            }
+            Event::OrderUpdate(update) => {
+                assert!(matches!(update.status, OrderStatus::Rejected { .. }));
+                rejections += 1;
+            }
+            _ => {}
+        }
+    }
+
+    // Every OrderEvent is rejected & reported as an Event::Error, and the Trader continues
+    assert_eq!(errors, 2);
+    assert_eq!(rejections, 2);
+}
+
+/// Execution handler that rejects the first exit OrderEvent, and simulates the Fill of every
+/// other OrderEvent.
+struct RejectFirstExitExecution {
+    simulated: SimulatedExecution<LiveClock>,
+    rejected: bool,
+}
+
+impl ExecutionClient for RejectFirstExitExecution {
+    fn generate_fill(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        if order.decision.is_exit() && !self.rejected {
+            self.rejected = true;
+            return Err(ExecutionError::Client(
+                barter_execution::error::ExecutionError::Simulated("exchange busy".to_owned()),
+            ));
+        }
+        self.simulated.generate_fill(order)
+    }
+}
+
+#[test]
+fn trader_retries_protective_exit_after_first_exit_order_fails() {
+    let market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let engine_id = Uuid::new_v4();
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let (_trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    // Enter at 100, then breach the 90 stop loss on both subsequent MarketEvents
+    let mut market_events = market_events(&market, Utc::now(), &[0, 1, 2]);
+    for (event, price) in market_events.iter_mut().zip([100.0, 80.0, 80.0]) {
+        if let DataKind::Trade(trade) = &mut event.kind {
+            trade.price = price;
+        }
+    }
+
+    let portfolio = Arc::new(Mutex::new(
+        portfolio_builder(engine_id, vec![market.clone()], LiveClock)
+            .protective_exits(ProtectiveExitConfig {
+                stop_loss: Some(PriceOffset::Price(10.0)),
+                ..Default::default()
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let trader: Trader<_, TradingSummary, _, _, _, _, _> = Trader::builder()
+        .engine_id(engine_id)
+        .market(market.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new(market_events))
+        .strategy(AlwaysLongStrategy)
+        .execution(RejectFirstExitExecution {
+            simulated: SimulatedExecution::new(
+                ExecutionConfig {
+                    simulated_fees_pct: Fees::default(),
+                },
+                LiveClock,
+            ),
+            rejected: false,
+        })
+        .clock(LiveClock)
+        .error_policy(ErrorPolicy {
+            order_execution: ErrorResponse {
+                action: ErrorAction::Skip,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .build()
+        .expect("failed to build trader");
+
+    assert_eq!(trader.run(), None);
+
+    let events = drain_events(&mut event_rx);
+    let exit_orders = events
+        .iter()
+        .filter(|event| matches!(event, Event::OrderNew(order) if order.decision.is_exit()))
+        .count();
+    let exits = events
+        .iter()
+        .filter(|event| matches!(event, Event::PositionExit(_)))
+        .count();
+
+    // The stop loss is re-armed after the first exit OrderEvent is rejected, so the next
+    // MarketEvent beyond it triggers a second exit OrderEvent that closes the Position
+    assert_eq!(exit_orders, 2);
+    assert_eq!(exits, 1);
+    assert!(portfolio
+        .lock()
+        .get_open_position(&determine_position_id(
+            engine_id,
+            &market.exchange,
+            &market.instrument
+        ))
+        .expect("failed to get open Position")
+        .is_none());
+}
+
+#[test]
+fn engine_journal_replays_into_identical_portfolio_state() {
+    let engine_id = Uuid::new_v4();
+    let start_time = Utc::now();
+    let market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+
+    let mut market_events = market_events(&market, start_time, &[0, 1, 2]);
+    for (event, price) in market_events.iter_mut().zip([1000.0, 1100.0, 900.0]) {
+        if let DataKind::Trade(trade) = &mut event.kind {
+            trade.price = price;
+        }
+    }
+
+    // Run a backtest that appends every Event to a binary journal
+    let path = std::env::temp_dir().join(format!("barter_journal_{}", Uuid::new_v4()));
+    let clock = HistoricalClock::new(start_time);
+    let portfolio = portfolio(engine_id, vec![market.clone()], clock.clone());
+    let (_command_tx, command_rx) = mpsc::channel(20);
+
+    let (trader, trader_command_tx) =
+        trader_builder(engine_id, &market, &portfolio, market_events, clock);
+    let trader = trader
+        .event_tx(JournalTx::new(&path, JournalFormat::Binary).expect("failed to open journal"))
+        .strategy(AlwaysLongStrategy)
+        .build()
+        .expect("failed to build trader");
+
+    engine_builder(
+        engine_id,
+        command_rx,
+        &portfolio,
+        vec![(trader, trader_command_tx)],
+    )
+    .build()
+    .expect("failed to build engine")
+    .run_backtest();
+
+    // Replay the journal into a fresh, identically configured MetaPortfolio
+    let clock = HistoricalClock::new(start_time);
+    let mut replayed = portfolio_builder(engine_id, vec![market.clone()], clock.clone())
+        .build_and_init()
+        .expect("failed to build & initialise MetaPortfolio");
+    let journal =
+        JournalReader::open(&path, JournalFormat::Binary).expect("failed to open journal");
+    let outcome = replay(journal, &mut replayed, &clock).expect("failed to replay journal");
+    std::fs::remove_file(&path).expect("failed to remove journal");
+
+    let mut portfolio = portfolio.lock();
+    let balance = portfolio.get_balance(engine_id).unwrap();
+    assert_eq!(replayed.get_balance(engine_id).unwrap(), balance);
+    assert_eq!(outcome.journal_balance, Some(balance));
+    assert!(outcome.events > 0);
+
+    let open_positions = portfolio
+        .get_open_positions(engine_id, [&market].into_iter())
+        .unwrap();
+    assert_eq!(open_positions.len(), 1);
+    assert_eq!(
+        replayed
+            .get_open_positions(engine_id, [&market].into_iter())
+            .unwrap(),
+        open_positions
+    );
+}
+
+/// [`CrossMarketSignalGenerator`] that advises to go long on the lagging [`Market`] whenever the
+/// leading [`Market`] trades.
+struct LeadLagStrategy {
+    leader: Market,
+    lagger: Market,
+}
+
+impl CrossMarketSignalGenerator for LeadLagStrategy {
+    fn generate_signals(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Vec<Signal> {
+        if market.exchange != self.leader.exchange || market.instrument != self.leader.instrument {
+            return Vec::new();
+        }
+
+        vec![Signal {
+            time: market.exchange_time,
+            exchange: self.lagger.exchange.clone(),
+            instrument: self.lagger.instrument.clone(),
+            signals: HashMap::from([(Decision::Long, SignalStrength(1.0))]),
+            target_exposure: None,
+            market_meta: MarketMeta {
+                close: 100.0,
+                time: market.exchange_time,
+            },
+        }]
+    }
+}
+
+#[test]
+fn engine_run_backtest_routes_cross_market_signals_to_trader_of_their_market() {
+    let (command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+    let engine_id = Uuid::new_v4();
+    let start_time = Utc::now();
+    let clock = HistoricalClock::new(start_time);
+
+    let spot = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let perpetual = Market::new("bybit", ("btc", "usdt", InstrumentKind::Perpetual));
+    let portfolio = portfolio(
+        engine_id,
+        vec![spot.clone(), perpetual.clone()],
+        clock.clone(),
+    );
+
+    // Share one cross-market strategy between the Traders of both Markets
+    let strategy = Arc::new(Mutex::new(LeadLagStrategy {
+        leader: spot.clone(),
+        lagger: perpetual.clone(),
+    }));
+
+    let traders = [(&spot, vec![0, 2]), (&perpetual, vec![1, 3])]
+        .into_iter()
+        .map(|(market, seconds)| {
+            let (trader, trader_command_tx) = trader_builder(
+                engine_id,
+                market,
+                &portfolio,
+                market_events(market, start_time, &seconds),
+                clock.clone(),
+            );
+            let trader = trader
+                .event_tx(event_tx.clone())
+                .strategy(CrossMarketStrategy::new(
+                    market.clone(),
+                    Arc::clone(&strategy),
+                ))
+                .engine_command_tx(command_tx.clone())
+                .build()
+                .expect("failed to build trader");
+            (trader, trader_command_tx)
+        })
+        .collect();
+
+    engine_builder(engine_id, command_rx, &portfolio, traders)
+        .build()
+        .expect("failed to build engine")
+        .run_backtest();
+
+    // Collect the exchange of every Signal, & the exchange & time of every OrderNew
+    let mut signals = Vec::new();
+    let mut orders = Vec::new();
+    for event in drain_events(&mut event_rx) {
+        match event {
+            Event::Signal(signal) => signals.push(signal.exchange.to_string()),
+            Event::OrderNew(order) => orders.push((order.exchange.to_string(), order.time)),
+            _ => {}
+        }
+    }
+
+    // Signals generated by the spot Trader are routed to, & actioned by, the perpetual Trader
+    // when it processes it's next MarketEvent, entering a single Position
+    assert_eq!(signals, vec!["bybit".to_string(), "bybit".to_string()]);
+    assert_eq!(
+        orders,
+        vec![(
+            "bybit".to_string(),
+            start_time + chrono::Duration::seconds(1)
+        )]
+    );
+}
+
+#[test]
+fn trader_does_not_route_cross_market_signals_generated_during_warm_up() {
+    let (command_tx, mut command_rx) = mpsc::channel(20);
+    let engine_id = Uuid::new_v4();
+    let start_time = Utc::now();
+    let clock = HistoricalClock::new(start_time);
+
+    let spot = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let perpetual = Market::new("bybit", ("btc", "usdt", InstrumentKind::Perpetual));
+    let portfolio = portfolio(
+        engine_id,
+        vec![spot.clone(), perpetual.clone()],
+        clock.clone(),
+    );
+
+    let strategy = Arc::new(Mutex::new(LeadLagStrategy {
+        leader: spot.clone(),
+        lagger: perpetual.clone(),
+    }));
+
+    // Warm up the spot Trader with historical leader MarketEvents that generate lagger Signals
+    let (trader, _trader_command_tx) =
+        trader_builder(engine_id, &spot, &portfolio, Vec::new(), clock);
+    let trader: Trader<_, TradingSummary, _, _, _, _, _> = trader
+        .event_tx(EventTx::new(mpsc::unbounded_channel().0))
+        .strategy(CrossMarketStrategy::new(spot.clone(), strategy))
+        .engine_command_tx(command_tx)
+        .warm_up(market_events(&spot, start_time, &[-300, -200, -100]))
+        .build()
+        .expect("failed to build trader");
+
+    // Stale historical Signals are not routed to the Engine as live Signals
+    assert_eq!(trader.run(), None);
+    assert!(matches!(
+        command_rx.try_recv(),
+        Err(mpsc::error::TryRecvError::Empty | mpsc::error::TryRecvError::Disconnected)
+    ));
+}
+
+/// [`SignalGenerator`] that advises to go long on every [`MarketEvent`], but is only warmed up
+/// once it has analysed a full period of [`MarketEvent`]s.
+struct WarmingLongStrategy {
+    period: usize,
+    analysed: usize,
+}
+
+impl SignalGenerator for WarmingLongStrategy {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        self.analysed += 1;
+        AlwaysLongStrategy.generate_signal(market)
+    }
+
+    fn is_warmed_up(&self) -> bool {
+        self.analysed >= self.period
+    }
+}
+
+#[test]
+fn trader_suppresses_signals_until_strategy_warmed_up() {
+    let (_command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+    let engine_id = Uuid::new_v4();
+    let start_time = Utc::now();
+    let clock = HistoricalClock::new(start_time);
+
+    let btc = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let eth = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+    // Record historical btc MarketEvents to warm up the btc Strategy with
+    let path = std::env::temp_dir().join(format!("barter_warm_up_{}.json", Uuid::new_v4()));
+    let history = market_events(&btc, start_time, &[-300, -200, -100])
+        .into_iter()
+        .chain(market_events(&eth, start_time, &[-50]))
+        .collect::<Vec<_>>();
+    std::fs::write(&path, serde_json::to_string(&history).unwrap())
+        .expect("failed to write warm up file");
+
+    // Warm up window ends at the backtest start, excluding MarketEvents recorded after it
+    let mut source = JsonFileWarmUp::new(&path);
+    let warm_up = source
+        .fetch(
+            &btc,
+            WarmUpWindow::Events(2),
+            start_time - chrono::Duration::seconds(250),
+        )
+        .expect("failed to fetch warm up MarketEvents");
+    assert_eq!(warm_up.len(), 1);
+
+    let warm_up = source
+        .fetch(&btc, WarmUpWindow::Events(2), clock.time())
+        .expect("failed to fetch warm up MarketEvents");
+    std::fs::remove_file(&path).expect("failed to remove warm up file");
+    assert_eq!(warm_up.len(), 2);
+
+    let portfolio = portfolio(engine_id, vec![btc.clone(), eth.clone()], clock.clone());
+
+    // Only the btc Strategy is warmed up before trading begins
+    let traders = [(&btc, vec![0, 2], warm_up), (&eth, vec![1, 3], vec![])]
+        .into_iter()
+        .map(|(market, seconds, warm_up)| {
+            let (trader, trader_command_tx) = trader_builder(
+                engine_id,
+                market,
+                &portfolio,
+                market_events(market, start_time, &seconds),
+                clock.clone(),
+            );
+            let trader = trader
+                .event_tx(event_tx.clone())
+                .strategy(WarmingLongStrategy {
+                    period: 3,
+                    analysed: 0,
+                })
+                .warm_up(warm_up)
+                .build()
+                .expect("failed to build trader");
+            (trader, trader_command_tx)
+        })
+        .collect();
+
+    engine_builder(engine_id, command_rx, &portfolio, traders)
+        .build()
+        .expect("failed to build engine")
+        .run_backtest();
+
+    // Collect the base of every Signal, & the MarketEvents analysed by the Portfolio
+    let mut signals = Vec::new();
+    let mut markets = 0;
+    for event in drain_events(&mut event_rx) {
+        match event {
+            Event::Signal(signal) => signals.push(signal.instrument.base.to_string()),
+            Event::Market(_) => markets += 1,
+            _ => {}
+        }
+    }
+
+    // Warm up MarketEvents are not traded, & only the warmed up btc Strategy generates Signals
+    assert_eq!(markets, 4);
+    assert_eq!(signals, vec!["btc".to_string(), "btc".to_string()]);
+}
+
+/// [`SignalGenerator`] that counts the [`MarketEvent`]s it has analysed, advising to go long
+/// once it has analysed a full period of them. Supports [`StrategySnapshot`]s of it's count.
+#[derive(Deserialize, Serialize)]
+struct CountingStrategy {
+    period: usize,
+    analysed: usize,
+}
+
+impl SignalGenerator for CountingStrategy {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        self.analysed += 1;
+        if self.analysed < self.period {
+            return None;
+        }
+        AlwaysLongStrategy.generate_signal(market)
+    }
+
+    fn snapshotter(&mut self) -> Option<&mut dyn StrategySnapshot> {
+        Some(self)
+    }
+}
+
+impl StrategySnapshot for CountingStrategy {
+    fn snapshot(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+
+    fn restore(&mut self, snapshot: serde_json::Value) -> Result<(), serde_json::Error> {
+        *self = serde_json::from_value(snapshot)?;
+        Ok(())
+    }
+}
+
+#[test]
+fn engine_restores_strategy_snapshots_persisted_by_previous_run() {
+    let engine_id = Uuid::new_v4();
+    let start_time = Utc::now();
+    let clock = HistoricalClock::new(start_time);
+    let btc = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+
+    // Build global shared-state MetaPortfolio, whose repository persists across both runs
+    let portfolio = portfolio(engine_id, vec![btc.clone()], clock.clone());
+
+    // Run a backtest of the btc MarketEvents at the seconds provided, returning the Signals
+    let run = |seconds: &[i64]| {
+        let (_command_tx, command_rx) = mpsc::channel(20);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+
+        let (trader, trader_command_tx) = trader_builder(
+            engine_id,
+            &btc,
+            &portfolio,
+            market_events(&btc, start_time, seconds),
+            clock.clone(),
+        );
+        let trader = trader
+            .event_tx(EventTx::new(event_tx))
+            .strategy(CountingStrategy {
+                period: 3,
+                analysed: 0,
+            })
+            .build()
+            .expect("failed to build trader");
+
+        engine_builder(
+            engine_id,
+            command_rx,
+            &portfolio,
+            vec![(trader, trader_command_tx)],
+        )
+        .build()
+        .expect("failed to build engine")
+        .run_backtest();
+
+        drain_events(&mut event_rx)
+            .into_iter()
+            .filter(|event| matches!(event, Event::Signal(_)))
+            .count()
+    };
+
+    // First run analyses too few MarketEvents to advise, but snapshots it's Strategy on stopping
+    assert_eq!(run(&[0, 1]), 0);
+    let snapshot = portfolio
+        .lock()
+        .get_strategy_snapshot(engine_id, &MarketId::from(&btc))
+        .expect("failed to get Strategy snapshot");
+    assert_eq!(
+        snapshot,
+        Some(serde_json::json!({ "period": 3, "analysed": 2 }))
+    );
+
+    // Second run restores the Strategy, so it advises on it's first MarketEvent
+    assert_eq!(run(&[2]), 1);
+}